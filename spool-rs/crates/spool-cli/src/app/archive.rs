@@ -1,9 +1,9 @@
 use crate::cli::ArchiveArgs;
 use crate::cli_error::{CliError, CliResult, fail, to_cli_error};
 use crate::runtime::Runtime;
+use spool_core::config::types::CoverageDefaults;
 use spool_core::paths as core_paths;
-use spool_domain::changes::ChangeRepository;
-use spool_domain::tasks::TaskRepository;
+use spool_domain::changes::ChangeWorkStatus;
 
 pub(crate) fn handle_archive(rt: &Runtime, args: &[String]) -> CliResult<()> {
     use spool_core::archive;
@@ -27,6 +27,31 @@ pub(crate) fn handle_archive(rt: &Runtime, args: &[String]) -> CliResult<()> {
     let skip_validation = args.iter().any(|a| a == "--no-validate");
     let skip_specs = args.iter().any(|a| a == "--skip-specs");
     let auto_confirm = args.iter().any(|a| a == "--yes" || a == "-y");
+    let coverage = if args.iter().any(|a| a == "--require-coverage") {
+        let project_root = spool_path.parent().unwrap_or(spool_path);
+        Some(
+            spool_core::coverage::load_coverage_defaults(project_root, spool_path, rt.ctx())
+                .map_err(to_cli_error)?,
+        )
+    } else {
+        None
+    };
+
+    if args.iter().any(|a| a == "--all-complete") {
+        let module = args
+            .iter()
+            .position(|a| a == "--module")
+            .and_then(|i| args.get(i + 1))
+            .map(|s| s.as_str());
+        return handle_archive_all_complete(
+            rt,
+            module,
+            skip_validation,
+            skip_specs,
+            auto_confirm,
+            coverage.as_ref(),
+        );
+    }
 
     // Get change name (first positional arg)
     let change_name = args
@@ -35,7 +60,7 @@ pub(crate) fn handle_archive(rt: &Runtime, args: &[String]) -> CliResult<()> {
         .map(|s| s.as_str());
 
     // If no change specified, list available changes and prompt for selection
-    let change_repo = ChangeRepository::new(spool_path);
+    let change_repo = spool_core::workflow::change_repository(spool_path, rt.ctx());
     let change_name = if let Some(name) = change_name {
         name.to_string()
     } else {
@@ -43,16 +68,17 @@ pub(crate) fn handle_archive(rt: &Runtime, args: &[String]) -> CliResult<()> {
         if available.is_empty() {
             return fail("No changes found to archive.");
         }
+        if super::picker::ui_interactive(args) {
+            super::picker::pick_change(rt, None)?
+        } else {
+            println!("Available changes:");
+            for (idx, change) in available.iter().enumerate() {
+                println!("  {}. {}", idx + 1, change.id);
+            }
+            println!();
 
-        println!("Available changes:");
-        for (idx, change) in available.iter().enumerate() {
-            println!("  {}. {}", idx + 1, change.id);
+            return fail("Please specify a change name: spool archive <change-name>");
         }
-        println!();
-
-        // Simple selection (in a real implementation, would use interactive prompt)
-        // For now, just fail with message
-        return fail("Please specify a change name: spool archive <change-name>");
     };
 
     // Verify change exists
@@ -62,7 +88,7 @@ pub(crate) fn handle_archive(rt: &Runtime, args: &[String]) -> CliResult<()> {
 
     // Check task completion unless skipping validation
     if !skip_validation {
-        let task_repo = TaskRepository::new(spool_path);
+        let task_repo = spool_core::workflow::task_repository(spool_path, rt.ctx());
         let (completed, total) = task_repo.get_task_counts(&change_name).unwrap_or((0, 0));
         if total > 0 {
             if completed < total {
@@ -89,6 +115,15 @@ pub(crate) fn handle_archive(rt: &Runtime, args: &[String]) -> CliResult<()> {
         }
     }
 
+    if let Some(coverage) = &coverage {
+        if let Err(reason) = check_coverage_gate(rt, &change_name, coverage) {
+            return fail(format!(
+                "Coverage gate failed for '{change_name}':\n{reason}"
+            ));
+        }
+        eprintln!("✔ Coverage meets the {}% target", coverage.target_percent);
+    }
+
     // Generate archive name
     let archive_name = archive::generate_archive_name(&change_name);
 
@@ -97,41 +132,47 @@ pub(crate) fn handle_archive(rt: &Runtime, args: &[String]) -> CliResult<()> {
         return fail(format!("Archive '{}' already exists", archive_name));
     }
 
-    let mut specs_updated: Vec<String> = Vec::new();
+    let mut spec_names: Vec<String> = Vec::new();
+    let mut new_specs: Vec<String> = Vec::new();
+    let mut existing_specs: Vec<String> = Vec::new();
+    let mut specs_declined = false;
 
     // Handle spec updates unless skipped
     if !skip_specs {
-        let spec_names =
+        let discovered =
             archive::discover_change_specs(spool_path, &change_name).map_err(to_cli_error)?;
 
-        if !spec_names.is_empty() {
-            let (new_specs, existing_specs) = archive::categorize_specs(spool_path, &spec_names);
+        if !discovered.is_empty() {
+            let (discovered_new, discovered_existing) =
+                archive::categorize_specs(spool_path, &discovered);
 
             // Show confirmation
-            if !new_specs.is_empty() || !existing_specs.is_empty() {
+            if !discovered_new.is_empty() || !discovered_existing.is_empty() {
                 println!("The following specs will be updated:");
                 println!();
 
-                if !new_specs.is_empty() {
+                if !discovered_new.is_empty() {
                     println!("NEW specs to be created:");
-                    for spec in &new_specs {
+                    for spec in &discovered_new {
                         println!("  - {}", spec);
                     }
                     println!();
                 }
 
-                if !existing_specs.is_empty() {
+                if !discovered_existing.is_empty() {
                     println!("EXISTING specs to be updated:");
-                    for spec in &existing_specs {
+                    for spec in &discovered_existing {
                         println!("  - {}", spec);
                     }
                     println!();
                 }
 
-                if !auto_confirm {
+                let apply = if auto_confirm {
+                    true
+                } else {
                     println!(
                         "Update {} specs and archive '{}'? [y/N]: ",
-                        spec_names.len(),
+                        discovered.len(),
                         change_name
                     );
                     let mut input = String::new();
@@ -139,30 +180,35 @@ pub(crate) fn handle_archive(rt: &Runtime, args: &[String]) -> CliResult<()> {
                         .read_line(&mut input)
                         .map_err(|_| CliError::msg("Failed to read input"))?;
                     let input = input.trim().to_lowercase();
-                    if input != "y" && input != "yes" {
+                    let confirmed = input == "y" || input == "yes";
+                    if !confirmed {
                         println!("Skipping spec updates, continuing with archive...");
-                    } else {
-                        // Copy specs to main
-                        specs_updated =
-                            archive::copy_specs_to_main(spool_path, &change_name, &spec_names)
-                                .map_err(to_cli_error)?;
-                        eprintln!("✔ Updated {} specs", specs_updated.len());
+                        specs_declined = true;
                     }
-                } else {
-                    // Copy specs to main
-                    specs_updated =
-                        archive::copy_specs_to_main(spool_path, &change_name, &spec_names)
-                            .map_err(to_cli_error)?;
-                    eprintln!("✔ Updated {} specs", specs_updated.len());
+                    confirmed
+                };
+
+                if apply {
+                    spec_names = discovered;
+                    new_specs = discovered_new;
+                    existing_specs = discovered_existing;
                 }
             }
         }
     }
 
-    // Move to archive
-    archive::move_to_archive(spool_path, &change_name, &archive_name).map_err(to_cli_error)?;
-
-    eprintln!("✔ Archived '{}' as '{}'", change_name, archive_name);
+    let specs_updated = apply_archive(
+        spool_path,
+        &ArchiveRequest {
+            change_name: &change_name,
+            archive_name: &archive_name,
+            spec_names: &spec_names,
+            new_specs: &new_specs,
+            existing_specs: &existing_specs,
+            validation_skipped: skip_validation,
+            specs_skipped: skip_specs || specs_declined,
+        },
+    )?;
     if !specs_updated.is_empty() {
         eprintln!("  Updated specs: {}", specs_updated.join(", "));
     }
@@ -170,6 +216,259 @@ pub(crate) fn handle_archive(rt: &Runtime, args: &[String]) -> CliResult<()> {
     Ok(())
 }
 
+/// Check the change against the coverage target (`--require-coverage`).
+fn check_coverage_gate(
+    rt: &Runtime,
+    change_id: &str,
+    coverage: &CoverageDefaults,
+) -> Result<(), String> {
+    use spool_core::coverage;
+
+    let spool_path = rt.spool_path();
+    let project_root = spool_path.parent().unwrap_or(spool_path);
+    let check = coverage::find_coverage_report(project_root, coverage.report.as_deref())
+        .and_then(|report| {
+            coverage::check_change_coverage(
+                spool_path,
+                change_id,
+                &report,
+                coverage.target_percent,
+                rt.ctx(),
+            )
+        })
+        .map_err(|e| e.to_string())?;
+    if check.passed {
+        Ok(())
+    } else {
+        Err(check.failures().join("\n"))
+    }
+}
+
+struct ArchiveRequest<'a> {
+    change_name: &'a str,
+    archive_name: &'a str,
+    /// Specs to merge into the main specs (empty when spec updates are skipped).
+    spec_names: &'a [String],
+    new_specs: &'a [String],
+    existing_specs: &'a [String],
+    validation_skipped: bool,
+    specs_skipped: bool,
+}
+
+/// Merge specs, move the change into the archive and write its `archive.json`.
+///
+/// Returns the specs that were written to the main specs directory.
+fn apply_archive(spool_path: &std::path::Path, req: &ArchiveRequest<'_>) -> CliResult<Vec<String>> {
+    use spool_core::archive;
+
+    let mut specs_updated: Vec<String> = Vec::new();
+    let mut hashes_before = std::collections::BTreeMap::new();
+    if !req.spec_names.is_empty() {
+        hashes_before =
+            archive::snapshot_spec_hashes(spool_path, req.spec_names).map_err(to_cli_error)?;
+        // Copy specs to main
+        specs_updated = archive::copy_specs_to_main(spool_path, req.change_name, req.spec_names)
+            .map_err(to_cli_error)?;
+        eprintln!("✔ Updated {} specs", specs_updated.len());
+    }
+
+    let manifest = archive::build_archive_manifest(
+        spool_path,
+        archive::ArchiveManifestInput {
+            change_id: req.change_name,
+            archive_name: req.archive_name,
+            spool_version: option_env!("SPOOL_WORKSPACE_VERSION")
+                .unwrap_or(env!("CARGO_PKG_VERSION")),
+            validation_skipped: req.validation_skipped,
+            specs_skipped: req.specs_skipped,
+            new_specs: req.new_specs,
+            updated_specs: req.existing_specs,
+            hashes_before: &hashes_before,
+        },
+    )
+    .map_err(to_cli_error)?;
+
+    // Move to archive
+    archive::move_to_archive(spool_path, req.change_name, req.archive_name)
+        .map_err(to_cli_error)?;
+    archive::write_archive_manifest(spool_path, req.archive_name, &manifest)
+        .map_err(to_cli_error)?;
+
+    eprintln!("✔ Archived '{}' as '{}'", req.change_name, req.archive_name);
+    Ok(specs_updated)
+}
+
+struct BulkArchiveRow {
+    change_id: String,
+    archived: bool,
+    detail: String,
+}
+
+/// Archive every change whose work status is complete, skipping (not aborting on)
+/// changes that fail validation or would merge into a spec already updated in this batch.
+fn handle_archive_all_complete(
+    rt: &Runtime,
+    module: Option<&str>,
+    skip_validation: bool,
+    skip_specs: bool,
+    auto_confirm: bool,
+    coverage: Option<&CoverageDefaults>,
+) -> CliResult<()> {
+    use spool_core::archive;
+
+    let spool_path = rt.spool_path();
+    let change_repo = spool_core::workflow::change_repository(spool_path, rt.ctx());
+    let summaries = match module {
+        Some(m) => change_repo.list_by_module(m),
+        None => change_repo.list(),
+    }
+    .map_err(to_cli_error)?;
+
+    let complete: Vec<String> = summaries
+        .into_iter()
+        .filter(|s| s.work_status() == ChangeWorkStatus::Complete)
+        .map(|s| s.id)
+        .collect();
+    if complete.is_empty() {
+        println!("No completed changes found to archive.");
+        return Ok(());
+    }
+
+    let ordered = archive::order_changes_for_archive(spool_path, &complete);
+    println!("Completed changes to archive:");
+    for id in &ordered {
+        println!("  - {id}");
+    }
+    println!();
+
+    if !auto_confirm {
+        println!("Archive {} changes? [y/N]: ", ordered.len());
+        let mut input = String::new();
+        std::io::stdin()
+            .read_line(&mut input)
+            .map_err(|_| CliError::msg("Failed to read input"))?;
+        let input = input.trim().to_lowercase();
+        if input != "y" && input != "yes" {
+            println!("Archive cancelled.");
+            return Ok(());
+        }
+    }
+
+    let mut rows: Vec<BulkArchiveRow> = Vec::new();
+    let mut touched_specs: std::collections::BTreeMap<String, String> =
+        std::collections::BTreeMap::new();
+    for change_id in ordered {
+        let skip = |detail: String| BulkArchiveRow {
+            change_id: change_id.clone(),
+            archived: false,
+            detail,
+        };
+
+        if !skip_validation {
+            match spool_core::validate::validate_change(spool_path, &change_id, false) {
+                Ok(report) if !report.valid => {
+                    rows.push(skip(format!(
+                        "validation failed ({} errors)",
+                        report.summary.errors
+                    )));
+                    continue;
+                }
+                Ok(_) => {}
+                Err(e) => {
+                    rows.push(skip(format!("validation failed: {e}")));
+                    continue;
+                }
+            }
+        }
+
+        if let Some(coverage) = coverage
+            && let Err(reason) = check_coverage_gate(rt, &change_id, coverage)
+        {
+            let first = reason.lines().next().unwrap_or_default().to_string();
+            rows.push(skip(format!("coverage gate failed: {first}")));
+            continue;
+        }
+
+        let archive_name = archive::generate_archive_name(&change_id);
+        if archive::archive_exists(spool_path, &archive_name) {
+            rows.push(skip(format!("archive '{archive_name}' already exists")));
+            continue;
+        }
+
+        let spec_names = if skip_specs {
+            Vec::new()
+        } else {
+            match archive::discover_change_specs(spool_path, &change_id) {
+                Ok(v) => v,
+                Err(e) => {
+                    rows.push(skip(e.to_string()));
+                    continue;
+                }
+            }
+        };
+        let conflict = spec_names
+            .iter()
+            .find_map(|spec| touched_specs.get(spec).map(|by| (spec, by)));
+        if let Some((spec, by)) = conflict {
+            rows.push(skip(format!(
+                "spec conflict: '{spec}' already updated by {by} in this batch"
+            )));
+            continue;
+        }
+
+        let (new_specs, existing_specs) = archive::categorize_specs(spool_path, &spec_names);
+        let result = apply_archive(
+            spool_path,
+            &ArchiveRequest {
+                change_name: &change_id,
+                archive_name: &archive_name,
+                spec_names: &spec_names,
+                new_specs: &new_specs,
+                existing_specs: &existing_specs,
+                validation_skipped: skip_validation,
+                specs_skipped: skip_specs,
+            },
+        );
+        match result {
+            Ok(updated) => {
+                for spec in &updated {
+                    touched_specs.insert(spec.clone(), change_id.clone());
+                }
+                rows.push(BulkArchiveRow {
+                    change_id: change_id.clone(),
+                    archived: true,
+                    detail: format!("{archive_name} ({} specs updated)", updated.len()),
+                });
+            }
+            Err(e) => rows.push(skip(e.to_string())),
+        }
+    }
+
+    print_bulk_summary(&rows);
+    Ok(())
+}
+
+fn print_bulk_summary(rows: &[BulkArchiveRow]) {
+    let archived = rows.iter().filter(|r| r.archived).count();
+    let skipped = rows.len() - archived;
+    let width = rows
+        .iter()
+        .map(|r| r.change_id.len())
+        .max()
+        .unwrap_or(0)
+        .max("Change".len());
+
+    println!();
+    println!("Archive summary:");
+    println!("  {: <width$}  {: <8}  Detail", "Change", "Result");
+    for r in rows {
+        let result = if r.archived { "archived" } else { "skipped" };
+        println!("  {: <width$}  {: <8}  {}", r.change_id, result, r.detail);
+    }
+    println!();
+    println!("{archived} archived, {skipped} skipped");
+}
+
 pub(crate) fn handle_archive_clap(rt: &Runtime, args: &ArchiveArgs) -> CliResult<()> {
     let mut argv: Vec<String> = Vec::new();
     if let Some(change) = &args.change {
@@ -184,5 +483,15 @@ pub(crate) fn handle_archive_clap(rt: &Runtime, args: &ArchiveArgs) -> CliResult
     if args.no_validate {
         argv.push("--no-validate".to_string());
     }
+    if args.all_complete {
+        argv.push("--all-complete".to_string());
+    }
+    if args.require_coverage {
+        argv.push("--require-coverage".to_string());
+    }
+    if let Some(module) = &args.module {
+        argv.push("--module".to_string());
+        argv.push(module.clone());
+    }
     handle_archive(rt, &argv)
 }
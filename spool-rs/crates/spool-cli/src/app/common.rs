@@ -5,11 +5,14 @@ use clap::CommandFactory;
 use spool_core::config::ConfigContext;
 use spool_core::paths as core_paths;
 use spool_core::workflow as core_workflow;
-use spool_domain::changes::ChangeRepository;
 use std::path::Path;
 
-pub(crate) fn schema_not_found_message(ctx: &ConfigContext, name: &str) -> String {
-    let schemas = core_workflow::list_available_schemas(ctx);
+pub(crate) fn schema_not_found_message(
+    spool_path: &Path,
+    ctx: &ConfigContext,
+    name: &str,
+) -> String {
+    let schemas = core_workflow::list_available_schemas(spool_path, ctx);
     let mut msg = format!("Schema '{name}' not found");
     if !schemas.is_empty() {
         msg.push_str(&format!(". Available schemas:\n  {}", schemas.join("\n  ")));
@@ -54,7 +57,7 @@ pub(crate) fn detect_item_type(rt: &Runtime, item: &str) -> String {
     let spool_path = rt.spool_path();
     let idx = rt.repo_index();
 
-    let change_repo = ChangeRepository::new(spool_path);
+    let change_repo = core_workflow::change_repository(spool_path, rt.ctx());
     let is_change = change_repo.exists(item);
     let is_spec = idx.spec_dir_names.iter().any(|n| n == item)
         && core_paths::spec_markdown_path(spool_path, item).exists();
@@ -71,7 +74,7 @@ pub(crate) fn list_spec_ids(rt: &Runtime) -> Vec<String> {
 }
 
 pub(crate) fn list_change_ids(rt: &Runtime) -> Vec<String> {
-    let change_repo = ChangeRepository::new(rt.spool_path());
+    let change_repo = core_workflow::change_repository(rt.spool_path(), rt.ctx());
     change_repo
         .list()
         .map(|changes| changes.into_iter().map(|c| c.id).collect())
@@ -4,7 +4,6 @@ use crate::runtime::Runtime;
 use crate::util::parse_string_flag;
 use spool_core::config::load_cascading_project_config;
 use spool_core::workflow as core_workflow;
-use spool_domain::changes::ChangeRepository;
 use spool_domain::modules::ModuleRepository;
 use std::collections::BTreeMap;
 use std::path::Path;
@@ -106,7 +105,7 @@ pub(crate) fn handle_agent_instruction(rt: &Runtime, args: &[String]) -> CliResu
             return handle_new_proposal_guide(rt, want_json);
         }
 
-        let change_repo = ChangeRepository::new(rt.spool_path());
+        let change_repo = core_workflow::change_repository(rt.spool_path(), rt.ctx());
         let changes = change_repo.list().unwrap_or_default();
         let mut msg = "Missing required option --change".to_string();
         if !changes.is_empty() {
@@ -154,7 +153,9 @@ pub(crate) fn handle_agent_instruction(rt: &Runtime, args: &[String]) -> CliResu
                 return fail(format!("Change '{name}' not found"));
             }
             Err(core_workflow::WorkflowError::SchemaNotFound(name)) => {
-                return fail(super::common::schema_not_found_message(ctx, &name));
+                return fail(super::common::schema_not_found_message(
+                    spool_path, ctx, &name,
+                ));
             }
             Err(e) => return Err(to_cli_error(e)),
         };
@@ -3,7 +3,7 @@ use crate::cli_error::{CliResult, fail, to_cli_error};
 use crate::runtime::Runtime;
 use chrono::{DateTime, Utc};
 use spool_core::paths as core_paths;
-use spool_domain::changes::{ChangeRepository, ChangeStatus};
+use spool_domain::changes::ChangeStatus;
 use spool_domain::modules::ModuleRepository;
 
 #[derive(Debug, serde::Serialize)]
@@ -134,7 +134,7 @@ pub(crate) fn handle_list(rt: &Runtime, args: &[String]) -> CliResult<()> {
                 return fail("No Spool changes directory found. Run 'spool init' first.");
             }
 
-            let change_repo = ChangeRepository::new(spool_path);
+            let change_repo = spool_core::workflow::change_repository(spool_path, rt.ctx());
             let mut summaries = change_repo.list().map_err(to_cli_error)?;
 
             // Filter to ready changes if requested
@@ -182,7 +182,7 @@ pub(crate) fn handle_list(rt: &Runtime, args: &[String]) -> CliResult<()> {
             if sort == "name" {
                 summaries.sort_by(|a, b| a.id.cmp(&b.id));
             } else {
-                summaries.sort_by(|a, b| b.last_modified.cmp(&a.last_modified));
+                summaries.sort_by_key(|s| std::cmp::Reverse(s.last_modified));
             }
 
             if want_json {
@@ -205,6 +205,18 @@ pub(crate) fn handle_list(rt: &Runtime, args: &[String]) -> CliResult<()> {
                             status: status.to_string(),
                             work_status: s.work_status().to_string(),
                             completed: is_completed(s),
+                            tracks: if s.tracks.len() > 1 {
+                                s.tracks
+                                    .iter()
+                                    .map(|t| spool_core::list::TrackListItem {
+                                        file: t.file.clone(),
+                                        completed_tasks: t.progress.complete as u32,
+                                        total_tasks: t.progress.total as u32,
+                                    })
+                                    .collect()
+                            } else {
+                                Vec::new()
+                            },
                         }
                     })
                     .collect();
@@ -400,6 +412,7 @@ mod tests {
             has_design: false,
             has_specs: true,
             has_tasks: true,
+            tracks: Vec::new(),
         };
 
         // No tasks
@@ -447,6 +460,7 @@ mod tests {
             has_design: false,
             has_specs: true,
             has_tasks: total > 0,
+            tracks: Vec::new(),
         };
 
         // No tasks - none of the predicates match
@@ -4,11 +4,12 @@ mod entrypoint;
 mod init;
 mod instructions;
 mod list;
+pub(crate) mod picker;
 mod ralph;
 mod run;
 mod show;
 mod status;
 mod update;
-mod validate;
+pub(crate) mod validate;
 
 pub(crate) use entrypoint::main;
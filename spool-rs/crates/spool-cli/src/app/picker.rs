@@ -0,0 +1,167 @@
+//! Interactive change picker shared by commands that take an optional change id.
+//!
+//! The picker is line based so it works in any terminal (and through the PTY test harness):
+//! it lists the candidate changes with their task progress, then reads either a list number
+//! or filter text. Filter text narrows the list by fuzzy match until one change is left.
+
+use crate::cli_error::{CliError, CliResult, fail, to_cli_error};
+use crate::runtime::Runtime;
+use spool_core::r#match::nearest_matches;
+use spool_domain::changes::ChangeSummary;
+use std::io::{BufRead, Write};
+
+/// Whether prompts may be shown: not disabled by `--no-interactive`, and either forced on by
+/// `SPOOL_INTERACTIVE` or running on a terminal.
+pub(crate) fn ui_interactive(args: &[String]) -> bool {
+    spool_core::output::resolve_ui_options(
+        false,
+        std::env::var("NO_COLOR").ok().as_deref(),
+        args.iter().any(|a| a == "--no-interactive"),
+        std::env::var("SPOOL_INTERACTIVE").ok().as_deref(),
+    )
+    .interactive
+}
+
+/// Ask the user to pick a change, optionally limited to one module.
+///
+/// Returns the only candidate without prompting when there is just one.
+pub(crate) fn pick_change(rt: &Runtime, module_id: Option<&str>) -> CliResult<String> {
+    let repo = spool_core::workflow::change_repository(rt.spool_path(), rt.ctx());
+    let mut changes = match module_id {
+        Some(module) => repo.list_by_module(module),
+        None => repo.list(),
+    }
+    .map_err(to_cli_error)?;
+    changes.sort_by(|a, b| a.id.cmp(&b.id));
+
+    if changes.is_empty() {
+        return fail(match module_id {
+            Some(module) => format!("No changes found for module {module}"),
+            None => "No changes found".to_string(),
+        });
+    }
+    if changes.len() == 1 {
+        return Ok(changes[0].id.clone());
+    }
+
+    let stdin = std::io::stdin();
+    let mut input = stdin.lock();
+    let mut shown: Vec<&ChangeSummary> = changes.iter().collect();
+    loop {
+        eprintln!("Select a change:");
+        for (idx, change) in shown.iter().enumerate() {
+            eprintln!(
+                "  {n:>2}. {id}  {progress}",
+                n = idx + 1,
+                id = change.id,
+                progress = format_progress(change)
+            );
+        }
+        eprint!("Number or filter text (empty to cancel): ");
+        std::io::stderr().flush().ok();
+
+        let mut line = String::new();
+        let read = input
+            .read_line(&mut line)
+            .map_err(|_| CliError::msg("Failed to read input"))?;
+        let query = line.trim();
+        if read == 0 || query.is_empty() {
+            return fail("No change selected");
+        }
+
+        if let Ok(n) = query.parse::<usize>()
+            && (1..=shown.len()).contains(&n)
+        {
+            return Ok(shown[n - 1].id.clone());
+        }
+
+        let matches = filter_changes(query, &changes);
+        match matches.len() {
+            0 => eprintln!("No changes match '{query}'.\n"),
+            1 => return Ok(matches[0].id.clone()),
+            _ => {
+                eprintln!();
+                shown = matches;
+            }
+        }
+    }
+}
+
+/// Changes whose id contains the query's characters in order (case-insensitive), closest first.
+/// An exact id match wins outright.
+fn filter_changes<'a>(query: &str, changes: &'a [ChangeSummary]) -> Vec<&'a ChangeSummary> {
+    if let Some(exact) = changes.iter().find(|c| c.id == query) {
+        return vec![exact];
+    }
+
+    let query_lower = query.to_lowercase();
+    let ids: Vec<String> = changes
+        .iter()
+        .filter(|c| is_subsequence(&query_lower, &c.id.to_lowercase()))
+        .map(|c| c.id.clone())
+        .collect();
+    nearest_matches(query, &ids, ids.len())
+        .into_iter()
+        .filter_map(|id| changes.iter().find(|c| c.id == id))
+        .collect()
+}
+
+fn is_subsequence(needle: &str, haystack: &str) -> bool {
+    let mut chars = haystack.chars();
+    needle.chars().all(|n| chars.any(|h| h == n))
+}
+
+fn format_progress(change: &ChangeSummary) -> String {
+    if change.total_tasks == 0 {
+        return "(no tasks)".to_string();
+    }
+    format!(
+        "({done}/{total} tasks)",
+        done = change.completed_tasks,
+        total = change.total_tasks
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn summary(id: &str) -> ChangeSummary {
+        ChangeSummary {
+            id: id.to_string(),
+            module_id: None,
+            completed_tasks: 0,
+            shelved_tasks: 0,
+            in_progress_tasks: 0,
+            pending_tasks: 0,
+            total_tasks: 0,
+            last_modified: chrono::Utc::now(),
+            has_proposal: true,
+            has_design: false,
+            has_specs: false,
+            has_tasks: false,
+            tracks: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn filter_changes_matches_fuzzily_and_prefers_exact_ids() {
+        let changes = vec![
+            summary("001-01_add-auth"),
+            summary("001-02_add-audit-log"),
+            summary("002-01_refactor-cli"),
+        ];
+
+        let ids = |q: &str| -> Vec<String> {
+            filter_changes(q, &changes)
+                .into_iter()
+                .map(|c| c.id.clone())
+                .collect()
+        };
+        assert_eq!(ids("refcli"), vec!["002-01_refactor-cli"]);
+        assert_eq!(ids("AUD").len(), 1);
+        assert_eq!(ids("001-0").len(), 2);
+        assert_eq!(ids("001-01_add-auth"), vec!["001-01_add-auth"]);
+        assert!(ids("zzz").is_empty());
+    }
+}
@@ -50,6 +50,14 @@ pub(crate) fn handle_ralph(rt: &Runtime, args: &[String]) -> CliResult<()> {
                     | "--add-context"
                     | "--timeout"
                     | "--stub-script"
+                    | "--log"
+                    | "--parallel"
+                    | "--verify"
+                    | "--max-failures"
+                    | "--retry-backoff"
+                    | "--max-cost"
+                    | "--max-duration"
+                    | "--context-budget"
             );
 
             if takes_value {
@@ -67,6 +75,14 @@ pub(crate) fn handle_ralph(rt: &Runtime, args: &[String]) -> CliResult<()> {
                 || a.starts_with("--add-context=")
                 || a.starts_with("--timeout=")
                 || a.starts_with("--stub-script=")
+                || a.starts_with("--log=")
+                || a.starts_with("--parallel=")
+                || a.starts_with("--verify=")
+                || a.starts_with("--max-failures=")
+                || a.starts_with("--retry-backoff=")
+                || a.starts_with("--max-cost=")
+                || a.starts_with("--max-duration=")
+                || a.starts_with("--context-budget=")
             {
                 i += 1;
                 continue;
@@ -101,7 +117,39 @@ pub(crate) fn handle_ralph(rt: &Runtime, args: &[String]) -> CliResult<()> {
         )
     });
     let no_commit = args.iter().any(|a| a == "--no-commit");
+    let mut gates = core_ralph::CompletionGates {
+        tasks: args.iter().any(|a| a == "--require-tasks"),
+        validate: args.iter().any(|a| a == "--require-validate"),
+        command: parse_string_flag(args, "--verify"),
+        coverage: None,
+    };
+    let require_coverage = args.iter().any(|a| a == "--require-coverage");
     let status = args.iter().any(|a| a == "--status");
+    let log = match parse_string_flag(args, "--log") {
+        Some(raw) => match raw.parse::<u32>() {
+            Ok(n) if n > 0 => Some(n),
+            _ => {
+                return fail(format!(
+                    "Invalid --log '{raw}': expected an iteration number"
+                ));
+            }
+        },
+        None => None,
+    };
+    let parallel = match parse_string_flag(args, "--parallel") {
+        Some(raw) => match raw.parse::<usize>() {
+            Ok(n) if n > 0 => Some(n),
+            _ => {
+                return fail(format!(
+                    "Invalid --parallel '{raw}': expected a positive number"
+                ));
+            }
+        },
+        None => None,
+    };
+    let worktree = args.iter().any(|a| a == "--worktree");
+    let merge = args.iter().any(|a| a == "--merge");
+    let discard = args.iter().any(|a| a == "--discard");
     let add_context = parse_string_flag(args, "--add-context");
     let clear_context = args.iter().any(|a| a == "--clear-context");
     let interactive = !args.iter().any(|a| a == "--no-interactive");
@@ -118,6 +166,57 @@ pub(crate) fn handle_ralph(rt: &Runtime, args: &[String]) -> CliResult<()> {
         None
     };
 
+    let max_failures = match parse_string_flag(args, "--max-failures") {
+        Some(raw) => match raw.parse::<u32>() {
+            Ok(n) if n > 0 => n,
+            _ => {
+                return fail(format!(
+                    "Invalid --max-failures '{raw}': expected a positive number"
+                ));
+            }
+        },
+        None => 3,
+    };
+    let retry_backoff = match parse_string_flag(args, "--retry-backoff") {
+        Some(raw) if matches!(raw.trim(), "0" | "0s") => std::time::Duration::ZERO,
+        Some(raw) => match core_ralph::parse_duration(&raw) {
+            Ok(d) => d,
+            Err(e) => return fail(format!("Invalid --retry-backoff '{raw}': {e}")),
+        },
+        None => std::time::Duration::from_secs(5),
+    };
+
+    let max_cost = match parse_string_flag(args, "--max-cost") {
+        Some(raw) => match raw.trim_start_matches('$').parse::<f64>() {
+            Ok(v) if v.is_finite() && v > 0.0 => Some(v),
+            _ => {
+                return fail(format!(
+                    "Invalid --max-cost '{raw}': expected a positive amount in USD"
+                ));
+            }
+        },
+        None => None,
+    };
+    let max_duration = match parse_string_flag(args, "--max-duration") {
+        Some(raw) => match core_ralph::parse_duration(&raw) {
+            Ok(d) => Some(d),
+            Err(e) => return fail(format!("Invalid --max-duration '{raw}': {e}")),
+        },
+        None => None,
+    };
+    let context_budget = match parse_string_flag(args, "--context-budget") {
+        Some(raw) => match raw.parse::<usize>() {
+            Ok(v) if v > 0 => Some(v),
+            _ => {
+                return fail(format!(
+                    "Invalid --context-budget '{raw}': expected a positive number of tokens"
+                ));
+            }
+        },
+        None => None,
+    };
+    let pricing = model.as_deref().and_then(core_ralph::cost::lookup_pricing);
+
     // Hidden testing flag.
     let stub_script = parse_string_flag(args, "--stub-script");
 
@@ -125,6 +224,9 @@ pub(crate) fn handle_ralph(rt: &Runtime, args: &[String]) -> CliResult<()> {
         && change_id.is_none()
         && module_id.is_none()
         && !status
+        && log.is_none()
+        && !merge
+        && !discard
         && add_context.is_none()
         && !clear_context
     {
@@ -142,11 +244,52 @@ pub(crate) fn handle_ralph(rt: &Runtime, args: &[String]) -> CliResult<()> {
     if status && change_id.is_none() && module_id.is_none() {
         return fail("--change is required for --status, or provide --module to auto-select");
     }
+    if log.is_some() && change_id.is_none() && module_id.is_none() {
+        return fail("--change is required for --log, or provide --module to auto-select");
+    }
+    if parallel.is_some() && (module_id.is_none() || change_id.is_some()) {
+        return fail("--parallel requires --module and cannot be combined with --change");
+    }
+    if merge && discard {
+        return fail("--merge and --discard cannot be used together");
+    }
+    if (merge || discard) && change_id.is_none() {
+        return fail("--change is required for --merge and --discard");
+    }
 
     let prompt = collect_prompt(args);
 
     let spool_path = rt.spool_path();
 
+    if let (Some(parallel), Some(module_id)) = (parallel, module_id.as_ref()) {
+        let program = match std::env::current_exe() {
+            Ok(p) => p,
+            Err(e) => return fail(format!("Failed to locate the spool executable: {e}")),
+        };
+        let opts = core_ralph::ParallelOptions {
+            module_id: module_id.clone(),
+            parallel,
+            program,
+            args: parallel_loop_args(args),
+            ctx: rt.ctx().clone(),
+        };
+        let summary = core_ralph::run_parallel(spool_path, &opts).map_err(to_cli_error)?;
+        let failed = summary.failed();
+        if failed > 0 {
+            return fail(format!("{failed} Ralph loop(s) failed"));
+        }
+        return Ok(());
+    }
+
+    // Ask which change to run when none was given and a terminal is available.
+    let change_id = match change_id {
+        Some(id) => Some(id),
+        None if interactive && super::picker::ui_interactive(args) => {
+            Some(super::picker::pick_change(rt, module_id.as_deref())?)
+        }
+        None => None,
+    };
+
     let mut harness_impl: Box<dyn Harness> = match harness.as_str() {
         "opencode" => Box::new(OpencodeHarness),
         "stub" => {
@@ -163,6 +306,19 @@ pub(crate) fn handle_ralph(rt: &Runtime, args: &[String]) -> CliResult<()> {
         _ => return fail(format!("Unknown harness: {h}", h = harness)),
     };
 
+    let project_root = spool_path.parent().unwrap_or(spool_path);
+    let hooks =
+        core_ralph::load_ralph_hooks(project_root, spool_path, rt.ctx()).map_err(to_cli_error)?;
+    if require_coverage {
+        let coverage =
+            spool_core::coverage::load_coverage_defaults(project_root, spool_path, rt.ctx())
+                .map_err(to_cli_error)?;
+        gates.coverage = Some(core_ralph::CoverageGate {
+            report: coverage.report,
+            target_percent: coverage.target_percent,
+        });
+    }
+
     let opts = core_ralph::RalphOptions {
         prompt,
         change_id,
@@ -179,6 +335,19 @@ pub(crate) fn handle_ralph(rt: &Runtime, args: &[String]) -> CliResult<()> {
         clear_context,
         verbose,
         inactivity_timeout,
+        log,
+        worktree,
+        merge,
+        discard,
+        gates,
+        max_failures,
+        retry_backoff,
+        pricing,
+        max_cost,
+        max_duration,
+        context_budget,
+        hooks,
+        ctx: rt.ctx().clone(),
     };
 
     core_ralph::run_ralph(spool_path, opts, harness_impl.as_mut()).map_err(to_cli_error)?;
@@ -186,6 +355,30 @@ pub(crate) fn handle_ralph(rt: &Runtime, args: &[String]) -> CliResult<()> {
     Ok(())
 }
 
+/// Arguments forwarded to each `--parallel` child loop: everything except target selection.
+fn parallel_loop_args(args: &[String]) -> Vec<String> {
+    let mut out = Vec::new();
+    let mut i = 0;
+    while i < args.len() {
+        let a = args[i].as_str();
+        if matches!(a, "--module" | "--change" | "--parallel") {
+            i += 2;
+            continue;
+        }
+        if a.starts_with("--module=")
+            || a.starts_with("--change=")
+            || a.starts_with("--parallel=")
+            || matches!(a, "--worktree" | "--no-interactive")
+        {
+            i += 1;
+            continue;
+        }
+        out.push(args[i].clone());
+        i += 1;
+    }
+    out
+}
+
 pub(crate) fn handle_ralph_clap(rt: &Runtime, args: &RalphArgs) -> CliResult<()> {
     let argv = ralph_args_to_argv(args);
     handle_ralph(rt, &argv)
@@ -220,6 +413,35 @@ fn ralph_args_to_argv(args: &RalphArgs) -> Vec<String> {
     }
     argv.push("--completion-promise".to_string());
     argv.push(args.completion_promise.clone());
+    if args.require_tasks {
+        argv.push("--require-tasks".to_string());
+    }
+    if args.require_validate {
+        argv.push("--require-validate".to_string());
+    }
+    if args.require_coverage {
+        argv.push("--require-coverage".to_string());
+    }
+    if let Some(verify) = &args.verify {
+        argv.push("--verify".to_string());
+        argv.push(verify.clone());
+    }
+    argv.push("--max-failures".to_string());
+    argv.push(args.max_failures.to_string());
+    argv.push("--retry-backoff".to_string());
+    argv.push(args.retry_backoff.clone());
+    if let Some(max_cost) = args.max_cost {
+        argv.push("--max-cost".to_string());
+        argv.push(max_cost.to_string());
+    }
+    if let Some(max_duration) = &args.max_duration {
+        argv.push("--max-duration".to_string());
+        argv.push(max_duration.clone());
+    }
+    if let Some(context_budget) = args.context_budget {
+        argv.push("--context-budget".to_string());
+        argv.push(context_budget.to_string());
+    }
     if args.allow_all {
         argv.push("--allow-all".to_string());
     }
@@ -229,6 +451,23 @@ fn ralph_args_to_argv(args: &RalphArgs) -> Vec<String> {
     if args.status {
         argv.push("--status".to_string());
     }
+    if let Some(log) = args.log {
+        argv.push("--log".to_string());
+        argv.push(log.to_string());
+    }
+    if let Some(parallel) = args.parallel {
+        argv.push("--parallel".to_string());
+        argv.push(parallel.to_string());
+    }
+    if args.worktree {
+        argv.push("--worktree".to_string());
+    }
+    if args.merge {
+        argv.push("--merge".to_string());
+    }
+    if args.discard {
+        argv.push("--discard".to_string());
+    }
     if let Some(add_context) = &args.add_context {
         argv.push("--add-context".to_string());
         argv.push(add_context.clone());
@@ -137,6 +137,24 @@ pub(super) fn run(args: &[String]) -> CliResult<()> {
                 || commands::handle_workflow_clap(&rt, args),
             );
         }
+        Some(Commands::Coverage(args)) => {
+            return util::with_logging(
+                &rt,
+                &command_id,
+                &project_root,
+                &spool_path_for_logging,
+                || commands::handle_coverage_clap(&rt, args),
+            );
+        }
+        Some(Commands::Schema(args)) => {
+            return util::with_logging(
+                &rt,
+                &command_id,
+                &project_root,
+                &spool_path_for_logging,
+                || commands::handle_schema_clap(&rt, args),
+            );
+        }
         Some(Commands::Status(args)) => {
             return util::with_logging(
                 &rt,
@@ -4,7 +4,6 @@ use crate::runtime::Runtime;
 use crate::util::parse_string_flag;
 use spool_core::paths as core_paths;
 use spool_core::{r#match::nearest_matches, show as core_show};
-use spool_domain::changes::ChangeRepository;
 use spool_domain::modules::ModuleRepository;
 
 pub(crate) fn handle_show(rt: &Runtime, args: &[String]) -> CliResult<()> {
@@ -40,11 +39,11 @@ pub(crate) fn handle_show(rt: &Runtime, args: &[String]) -> CliResult<()> {
         .or_else(|| parse_string_flag(args, "-r"))
         .and_then(|s| s.parse::<usize>().ok());
 
-    let item = super::common::last_positional(args);
+    let mut item = super::common::last_positional(args);
+    if item.is_none() && ui.interactive {
+        item = Some(super::picker::pick_change(rt, None)?);
+    }
     if item.is_none() {
-        if ui.interactive {
-            // Interactive selection is not implemented yet.
-        }
         return fail(
             "Nothing to show. Try one of:\n  spool show <item>\n  spool show (for interactive selection)\nOr run in an interactive terminal.",
         );
@@ -132,7 +131,7 @@ pub(crate) fn handle_show(rt: &Runtime, args: &[String]) -> CliResult<()> {
             Ok(())
         }
         "change" => {
-            let change_repo = ChangeRepository::new(spool_path);
+            let change_repo = spool_core::workflow::change_repository(spool_path, rt.ctx());
             if !change_repo.exists(&item) {
                 return fail(format!("Change '{item}' not found"));
             }
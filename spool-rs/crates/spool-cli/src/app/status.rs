@@ -3,7 +3,6 @@ use crate::cli_error::{CliResult, fail, to_cli_error};
 use crate::runtime::Runtime;
 use crate::util::parse_string_flag;
 use spool_core::workflow as core_workflow;
-use spool_domain::changes::ChangeRepository;
 
 pub(crate) fn handle_status(rt: &Runtime, args: &[String]) -> CliResult<()> {
     if args.iter().any(|a| a == "--help" || a == "-h") {
@@ -15,9 +14,12 @@ pub(crate) fn handle_status(rt: &Runtime, args: &[String]) -> CliResult<()> {
     }
 
     let want_json = args.iter().any(|a| a == "--json");
-    let change = parse_string_flag(args, "--change");
+    let mut change = parse_string_flag(args, "--change");
+    if change.as_deref().unwrap_or("").is_empty() && super::picker::ui_interactive(args) {
+        change = Some(super::picker::pick_change(rt, None)?);
+    }
     if change.as_deref().unwrap_or("").is_empty() {
-        let change_repo = ChangeRepository::new(rt.spool_path());
+        let change_repo = core_workflow::change_repository(rt.spool_path(), rt.ctx());
         let changes = change_repo.list().unwrap_or_default();
         let mut msg = "Missing required option --change".to_string();
         if !changes.is_empty() {
@@ -44,7 +46,7 @@ pub(crate) fn handle_status(rt: &Runtime, args: &[String]) -> CliResult<()> {
                 return fail("Invalid change name");
             }
             Err(core_workflow::WorkflowError::ChangeNotFound(name)) => {
-                let change_repo = ChangeRepository::new(spool_path);
+                let change_repo = core_workflow::change_repository(spool_path, rt.ctx());
                 let changes = change_repo.list().unwrap_or_default();
                 let mut msg = format!("Change '{name}' not found");
                 if !changes.is_empty() {
@@ -56,7 +58,9 @@ pub(crate) fn handle_status(rt: &Runtime, args: &[String]) -> CliResult<()> {
                 return fail(msg);
             }
             Err(core_workflow::WorkflowError::SchemaNotFound(name)) => {
-                return fail(super::common::schema_not_found_message(ctx, &name));
+                return fail(super::common::schema_not_found_message(
+                    spool_path, ctx, &name,
+                ));
             }
             Err(e) => {
                 return Err(to_cli_error(e));
@@ -94,6 +98,12 @@ pub(crate) fn handle_status(rt: &Runtime, args: &[String]) -> CliResult<()> {
                 a.id,
                 a.missing_deps.join(", ")
             );
+        } else if a.stale {
+            println!(
+                "{mark} {} (stale: {} modified since)",
+                a.id,
+                a.stale_against.join(", ")
+            );
         } else {
             println!("{mark} {}", a.id);
         }
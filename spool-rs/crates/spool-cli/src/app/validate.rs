@@ -3,9 +3,7 @@ use crate::cli_error::{CliResult, fail, silent_fail, to_cli_error};
 use crate::runtime::Runtime;
 use crate::util::parse_string_flag;
 use spool_core::paths as core_paths;
-use spool_core::{r#match::nearest_matches, validate as core_validate};
-use spool_domain::changes::ChangeRepository;
-use spool_domain::tasks as domain_tasks;
+use spool_core::{r#match::nearest_matches, validate as core_validate, workflow as core_workflow};
 use std::path::Path;
 
 fn format_issue_loc(i: &core_validate::ValidationIssue) -> String {
@@ -75,17 +73,9 @@ pub(crate) fn handle_validate(rt: &Runtime, args: &[String]) -> CliResult<()> {
 
             let change_dirs = repo_index.change_dir_names.clone();
 
-            let repo_integrity =
-                core_validate::validate_change_dirs_repo_integrity(spool_path).unwrap_or_default();
-
             for dir_name in change_dirs {
                 let mut issues: Vec<core_validate::ValidationIssue> = Vec::new();
 
-                // Repo integrity checks (naming/module/duplicate numeric ids)
-                if let Some(extra) = repo_integrity.get(&dir_name) {
-                    issues.extend(extra.clone());
-                }
-
                 // Preserve the legacy module existence check for dirs that might not be parsed.
                 if let Ok(p) = spool_core::id::parse_change_id(&dir_name)
                     && !module_ids.contains(p.module_id.as_str())
@@ -99,20 +89,18 @@ pub(crate) fn handle_validate(rt: &Runtime, args: &[String]) -> CliResult<()> {
                     ));
                 }
 
-                // Existing delta validation (if we can)
-                let report = core_validate::validate_change(spool_path, &dir_name, strict)
-                    .unwrap_or_else(|e| {
-                        core_validate::ValidationReport::new(
-                            vec![core_validate::error(
-                                "validate",
-                                format!("Validation failed: {e}"),
-                            )],
-                            strict,
-                        )
-                    });
-
-                // tasks.md validation (enhanced + checkbox)
-                issues.extend(validate_tasks_file(spool_path, &dir_name));
+                // Delta, repo integrity, tracking file and staleness checks (if we can)
+                let report =
+                    core_validate::validate_change_all(spool_path, &dir_name, strict, rt.ctx())
+                        .unwrap_or_else(|e| {
+                            core_validate::ValidationReport::new(
+                                vec![core_validate::error(
+                                    "validate",
+                                    format!("Validation failed: {e}"),
+                                )],
+                                strict,
+                            )
+                        });
 
                 let mut merged = report.issues.clone();
                 merged.extend(issues);
@@ -314,7 +302,7 @@ pub(crate) fn handle_validate(rt: &Runtime, args: &[String]) -> CliResult<()> {
             Ok(())
         }
         "change" => {
-            let change_repo = ChangeRepository::new(spool_path);
+            let change_repo = core_workflow::change_repository(spool_path, rt.ctx());
             if !change_repo.exists(&item) {
                 let candidates = super::common::list_change_ids(rt);
                 let suggestions = nearest_matches(&item, &candidates, 5);
@@ -329,21 +317,8 @@ pub(crate) fn handle_validate(rt: &Runtime, args: &[String]) -> CliResult<()> {
             let summary = change_repo.get_summary(&item).map_err(to_cli_error)?;
             let actual = summary.id;
 
-            let mut issues = Vec::new();
-            let repo_integrity =
-                core_validate::validate_change_dirs_repo_integrity(spool_path).unwrap_or_default();
-            if let Some(extra) = repo_integrity.get(&actual) {
-                issues.extend(extra.clone());
-            }
-
-            let report = core_validate::validate_change(spool_path, &actual, strict)
+            let report = core_validate::validate_change_all(spool_path, &actual, strict, rt.ctx())
                 .map_err(to_cli_error)?;
-            let mut merged = report.issues.clone();
-            merged.extend(issues);
-
-            // tasks.md validation (enhanced + checkbox)
-            merged.extend(validate_tasks_file(spool_path, &actual));
-            let report = core_validate::ValidationReport::new(merged, strict);
             let ok = render_validate_result("change", &item, report, want_json);
             if !ok {
                 return silent_fail();
@@ -363,51 +338,6 @@ pub(crate) fn handle_validate(rt: &Runtime, args: &[String]) -> CliResult<()> {
     }
 }
 
-fn validate_tasks_file(spool_path: &Path, change_id: &str) -> Vec<core_validate::ValidationIssue> {
-    let path = domain_tasks::tasks_path(spool_path, change_id);
-    if !path.exists() {
-        return Vec::new();
-    }
-
-    let report_path = format!(".spool/changes/{change_id}/tasks.md");
-
-    let contents = match spool_core::io::read_to_string(&path) {
-        Ok(c) => c,
-        Err(e) => {
-            return vec![core_validate::error(
-                &report_path,
-                format!("Failed to read {report_path}: {e}"),
-            )];
-        }
-    };
-
-    let parsed = domain_tasks::parse_tasks_tracking_file(&contents);
-    let mut issues = Vec::new();
-    for d in parsed.diagnostics {
-        let mut issue = match d.level {
-            domain_tasks::DiagnosticLevel::Error => core_validate::error(&report_path, d.message),
-            domain_tasks::DiagnosticLevel::Warning => {
-                core_validate::warning(&report_path, d.message)
-            }
-        };
-        if let Some(line) = d.line {
-            issue = core_validate::with_line(issue, line as u32);
-        }
-
-        if let Some(task_id) = d.task_id {
-            issue = core_validate::with_metadata(
-                issue,
-                serde_json::json!({
-                    "taskId": task_id,
-                }),
-            );
-        }
-
-        issues.push(issue);
-    }
-    issues
-}
-
 pub(crate) fn handle_validate_clap(rt: &Runtime, args: &ValidateArgs) -> CliResult<()> {
     let mut argv: Vec<String> = Vec::new();
 
@@ -501,6 +431,16 @@ fn render_validate_result(
     report: core_validate::ValidationReport,
     want_json: bool,
 ) -> bool {
+    render_validate_results(typ, &[(id.to_string(), report)], want_json)
+}
+
+/// Print validation results for items of one type; returns whether all were valid.
+pub(crate) fn render_validate_results(
+    typ: &str,
+    results: &[(String, core_validate::ValidationReport)],
+    want_json: bool,
+) -> bool {
+    let all_valid = results.iter().all(|(_, report)| report.valid);
     if want_json {
         // Match TS validate JSON envelope for single-item validation.
         #[derive(serde::Serialize)]
@@ -538,29 +478,33 @@ fn render_validate_result(
             version: &'static str,
         }
 
-        let passed = if report.valid { 1 } else { 0 };
-        let failed = if report.valid { 0 } else { 1 };
+        let items = results.len() as u32;
+        let passed = results.iter().filter(|(_, report)| report.valid).count() as u32;
+        let failed = items - passed;
         let mut by_type = std::collections::BTreeMap::new();
         by_type.insert(
             typ.to_string(),
             ByType {
-                items: 1,
+                items,
                 passed,
                 failed,
             },
         );
 
         let env = Envelope {
-            items: vec![Item {
-                id,
-                typ,
-                valid: report.valid,
-                issues: report.issues.clone(),
-                duration_ms: 1,
-            }],
+            items: results
+                .iter()
+                .map(|(id, report)| Item {
+                    id,
+                    typ,
+                    valid: report.valid,
+                    issues: report.issues.clone(),
+                    duration_ms: 1,
+                })
+                .collect(),
             summary: Summary {
                 totals: Totals {
-                    items: 1,
+                    items,
                     passed,
                     failed,
                 },
@@ -570,39 +514,40 @@ fn render_validate_result(
         };
         let rendered = serde_json::to_string_pretty(&env).expect("json should serialize");
         println!("{rendered}");
-        return report.valid;
+        return all_valid;
     }
 
-    let label = if typ == "spec" {
-        "Specification"
-    } else if typ == "change" {
-        "Change"
-    } else {
-        "Item"
+    let label = match typ {
+        "spec" => "Specification",
+        "change" => "Change",
+        "schema" => "Schema",
+        _ => "Item",
     };
 
-    if report.valid {
-        println!("{label} '{id}' is valid");
-        return true;
-    }
+    for (id, report) in results {
+        if report.valid {
+            println!("{label} '{id}' is valid");
+            continue;
+        }
 
-    eprintln!("{label} '{id}' has issues");
-    for issue in &report.issues {
-        eprintln!(
-            "✗ [{}] {}: {}",
-            issue.level,
-            format_issue_loc(issue),
-            issue.message
-        );
-    }
+        eprintln!("{label} '{id}' has issues");
+        for issue in &report.issues {
+            eprintln!(
+                "✗ [{}] {}: {}",
+                issue.level,
+                format_issue_loc(issue),
+                issue.message
+            );
+        }
 
-    // Minimal next steps matching TS for spec validation.
-    if typ == "spec" {
-        eprintln!("Next steps:");
-        eprintln!("  - Ensure spec includes ## Purpose and ## Requirements sections");
-        eprintln!("  - Each requirement MUST include at least one #### Scenario: block");
-        eprintln!("  - Re-run with --json to see structured report");
+        // Minimal next steps matching TS for spec validation.
+        if typ == "spec" {
+            eprintln!("Next steps:");
+            eprintln!("  - Ensure spec includes ## Purpose and ## Requirements sections");
+            eprintln!("  - Each requirement MUST include at least one #### Scenario: block");
+            eprintln!("  - Re-run with --json to see structured report");
+        }
     }
 
-    false
+    all_valid
 }
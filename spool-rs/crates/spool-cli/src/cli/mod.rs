@@ -4,6 +4,14 @@ use clap::builder::Styles;
 use clap::builder::styling::{AnsiColor, Color, Style};
 use clap::{Args, Parser, Subcommand, ValueEnum};
 
+mod plan;
+mod ralph;
+mod state;
+
+pub use plan::*;
+pub use ralph::*;
+pub use state::*;
+
 fn cli_styles() -> Styles {
     Styles::styled()
         .header(Style::new().bold())
@@ -101,6 +109,18 @@ pub enum Commands {
     #[command(verbatim_doc_comment)]
     Validate(ValidateArgs),
 
+    /// Check test coverage against the configured target
+    ///
+    /// Reads an LCOV or Cobertura report and compares line coverage, overall and
+    /// for the files listed in the change's task Files: entries, with
+    /// defaults.testing.coverage.target_percent. Returns non-zero when below it.
+    ///
+    /// Examples:
+    ///   spool coverage check 005-01_add-auth
+    ///   spool coverage check 005-01_add-auth --report coverage/lcov.info --json
+    #[command(verbatim_doc_comment)]
+    Coverage(CoverageArgs),
+
     /// Move a completed change to archive and update main specs
     ///
     /// Archives the change directory and merges spec deltas into the main specs.
@@ -109,6 +129,7 @@ pub enum Commands {
     /// Examples:
     ///   spool archive 005-01_add-auth
     ///   spool archive 005-01_add-auth -y --skip-specs
+    ///   spool archive --all-complete --module 005
     #[command(verbatim_doc_comment)]
     Archive(ArchiveArgs),
 
@@ -141,6 +162,8 @@ pub enum Commands {
     /// Examples:
     ///   spool plan init
     ///   spool plan status
+    ///   spool plan milestone add v2-scale --target "Handle 10x load"
+    ///   spool plan phase add "Auth flow" --change 005-01_add-auth
     #[command(verbatim_doc_comment)]
     Plan(PlanArgs),
 
@@ -154,6 +177,8 @@ pub enum Commands {
     ///   spool state focus "implementing auth flow"
     ///   spool state decision "using JWT for tokens"
     ///   spool state blocker "waiting on API spec"
+    ///   spool state list --type blocker --open --json
+    ///   spool state resolve blocker 1
     #[command(verbatim_doc_comment)]
     State(StateArgs),
 
@@ -178,6 +203,12 @@ pub enum Commands {
     /// Examples:
     ///   spool ralph --change 005-01_add-auth
     ///   spool ralph --change 005-01_add-auth --harness claude --max-iterations 5
+    ///   spool ralph --change 005-01_add-auth --max-cost 5 --max-duration 2h
+    ///   spool ralph --change 005-01_add-auth --require-tasks --verify "cargo test"
+    ///   spool ralph --change 005-01_add-auth --log 3
+    ///   spool ralph --change 005-01_add-auth --worktree
+    ///   spool ralph --change 005-01_add-auth --merge
+    ///   spool ralph --module 005 --parallel 3 --max-iterations 10
     #[command(verbatim_doc_comment)]
     Ralph(RalphArgs),
 
@@ -208,15 +239,19 @@ pub enum Commands {
     #[command(verbatim_doc_comment)]
     Update(UpdateArgs),
 
-    /// Read and write global Spool settings
+    /// Read and write Spool settings
     ///
-    /// Manages configuration in ~/.config/spool/config.json. Settings include
-    /// default schemas, tool preferences, and execution options.
+    /// Manages configuration in ~/.config/spool/config.json, or with --scope in
+    /// the project's spool.json, .spool.json, or .spool/config.json. Settings
+    /// include default schemas, tool preferences, and execution options.
     ///
     /// Examples:
     ///   spool config path
     ///   spool config get defaults.schema
     ///   spool config set defaults.schema "spec-driven"
+    ///   spool config set defaults.testing.coverage.target_percent 90 --scope spool-dir
+    ///   spool config explain defaults.testing.coverage.target_percent
+    ///   spool config check --strict
     #[command(verbatim_doc_comment)]
     Config(ConfigArgs),
 
@@ -232,6 +267,20 @@ pub enum Commands {
     #[command(verbatim_doc_comment)]
     Workflow(WorkflowArgs),
 
+    /// Create, preview, and check artifact schemas
+    ///
+    /// Scaffolds custom schemas, previews their artifacts, and lints schema.yaml
+    /// files (project, user, or built-in) for duplicate or unknown artifact ids,
+    /// dependency cycles, missing templates, and bad file patterns.
+    ///
+    /// Examples:
+    ///   spool schema new team --from spec-driven
+    ///   spool schema preview team
+    ///   spool schema validate
+    ///   spool schema validate spec-driven --json
+    #[command(verbatim_doc_comment)]
+    Schema(SchemaArgs),
+
     // ─── Local Docs Server ──────────────────────────────────────────────────────
     /// Serve local Spool artifacts and docs over HTTP
     ///
@@ -338,6 +387,10 @@ pub enum NewAction {
         /// Description (writes README.md)
         #[arg(long)]
         description: Option<String>,
+
+        /// Comma-separated tracking files, overriding the schema's `apply.tracks`
+        #[arg(long)]
+        tracks: Option<String>,
     },
 
     /// Forward unknown subcommands to legacy handler
@@ -349,96 +402,101 @@ pub enum NewAction {
 #[derive(Args, Debug, Clone)]
 pub struct StatsArgs {}
 
-/// Project planning tools.
+/// Manage and run workflows.
 #[derive(Args, Debug, Clone)]
 #[command(subcommand_required = true, arg_required_else_help = true)]
 #[command(disable_help_subcommand = true)]
-pub struct PlanArgs {
+pub struct WorkflowArgs {
     #[command(subcommand)]
-    pub action: Option<PlanAction>,
+    pub action: Option<WorkflowAction>,
 }
 
 #[derive(Subcommand, Debug, Clone)]
-pub enum PlanAction {
-    /// Initialize planning structure
+pub enum WorkflowAction {
+    /// Initialize workflow templates
     Init,
 
-    /// Show current milestone progress
-    Status,
+    /// List available workflows
+    List,
+
+    /// Show workflow details, or an artifact schema's resolved artifacts
+    Show {
+        /// Workflow or schema name
+        #[arg(value_name = "WORKFLOW", num_args = 0.., trailing_var_arg = true)]
+        workflow_name: Vec<String>,
+    },
 }
 
-/// View and update planning/STATE.md.
 #[derive(Args, Debug, Clone)]
 #[command(subcommand_required = true, arg_required_else_help = true)]
 #[command(disable_help_subcommand = true)]
-pub struct StateArgs {
+pub struct SchemaArgs {
     #[command(subcommand)]
-    pub action: Option<StateAction>,
+    pub action: Option<SchemaAction>,
 }
 
 #[derive(Subcommand, Debug, Clone)]
-pub enum StateAction {
-    /// Show current project state
-    Show,
-
-    /// Record a decision
-    Decision {
-        /// Text to record
-        #[arg(value_name = "TEXT", num_args = 0.., trailing_var_arg = true)]
-        text: Vec<String>,
+pub enum SchemaAction {
+    /// Create a schema (schema.yaml plus templates) by copying an existing one
+    New {
+        /// New schema name
+        #[arg(value_name = "NAME")]
+        name: String,
+
+        /// Schema to copy (default: spec-driven)
+        #[arg(long, value_name = "SCHEMA")]
+        from: Option<String>,
+
+        /// Create it in the user schema directory instead of the project
+        #[arg(long)]
+        user: bool,
     },
 
-    /// Record a blocker
-    Blocker {
-        /// Text to record
-        #[arg(value_name = "TEXT", num_args = 0.., trailing_var_arg = true)]
-        text: Vec<String>,
+    /// Show a schema's dependency graph and its templates rendered with sample values
+    Preview {
+        /// Schema name
+        #[arg(value_name = "NAME")]
+        name: String,
     },
 
-    /// Add a session note
-    Note {
-        /// Text to record
-        #[arg(value_name = "TEXT", num_args = 0.., trailing_var_arg = true)]
-        text: Vec<String>,
-    },
+    /// Validate a schema, or every available schema
+    Validate {
+        /// Schema name (default: all available schemas)
+        #[arg(value_name = "NAME")]
+        name: Option<String>,
 
-    /// Set current focus
-    Focus {
-        /// Text to record
-        #[arg(value_name = "TEXT", num_args = 0.., trailing_var_arg = true)]
-        text: Vec<String>,
-    },
+        /// Treat warnings as errors
+        #[arg(long)]
+        strict: bool,
 
-    /// Add an open question
-    Question {
-        /// Text to record
-        #[arg(value_name = "TEXT", num_args = 0.., trailing_var_arg = true)]
-        text: Vec<String>,
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
     },
 }
 
-/// Manage and run workflows.
+/// Check test coverage against the configured target.
 #[derive(Args, Debug, Clone)]
-#[command(subcommand_required = true, arg_required_else_help = true)]
-#[command(disable_help_subcommand = true)]
-pub struct WorkflowArgs {
+pub struct CoverageArgs {
     #[command(subcommand)]
-    pub action: Option<WorkflowAction>,
+    pub action: Option<CoverageAction>,
 }
 
 #[derive(Subcommand, Debug, Clone)]
-pub enum WorkflowAction {
-    /// Initialize workflow templates
-    Init,
-
-    /// List available workflows
-    List,
+pub enum CoverageAction {
+    /// Fail when a change's coverage is below defaults.testing.coverage.target_percent
+    Check {
+        /// Change id (directory name)
+        #[arg(value_name = "CHANGE")]
+        change: String,
+
+        /// LCOV or Cobertura report (default: defaults.testing.coverage.report, then lcov.info, coverage.xml, ...)
+        #[arg(long, value_name = "PATH")]
+        report: Option<std::path::PathBuf>,
 
-    /// Show workflow details
-    Show {
-        /// Workflow name
-        #[arg(value_name = "WORKFLOW", num_args = 0.., trailing_var_arg = true)]
-        workflow_name: Vec<String>,
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
     },
 }
 
@@ -521,7 +579,7 @@ pub struct AgentInstructionArgs {
     pub json: bool,
 }
 
-/// View and modify global Spool configuration.
+/// View and modify Spool configuration.
 #[derive(Args, Debug, Clone)]
 pub struct ConfigArgs {
     #[command(subcommand)]
@@ -566,6 +624,23 @@ pub enum ConfigCommand {
         common: ConfigCommonArgs,
     },
 
+    /// Show a project config key's effective value and the file that set it
+    Explain {
+        /// Key path (dot-separated)
+        key: String,
+    },
+
+    /// Check project config files against the config schema
+    Check {
+        /// Treat unknown keys and type mismatches as errors
+        #[arg(long)]
+        strict: bool,
+
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+
     /// Print JSON schema for Spool config
     Schema {
         /// Write schema to file instead of stdout
@@ -583,6 +658,22 @@ pub struct ConfigCommonArgs {
     /// Treat <value> as a string
     #[arg(long)]
     pub string: bool,
+
+    /// Config file to use (default: global)
+    #[arg(long, value_enum)]
+    pub scope: Option<ConfigScopeArg>,
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy)]
+pub enum ConfigScopeArg {
+    /// <repo-root>/.spool.json
+    Project,
+    /// <repo-root>/spool.json
+    Repo,
+    /// <spool-dir>/config.json
+    SpoolDir,
+    /// ~/.config/spool/config.json
+    Global,
 }
 
 /// Create items.
@@ -624,6 +715,10 @@ pub enum CreateAction {
         /// Description (writes README.md)
         #[arg(long)]
         description: Option<String>,
+
+        /// Comma-separated tracking files, overriding the schema's `apply.tracks`
+        #[arg(long)]
+        tracks: Option<String>,
     },
 
     /// Forward unknown subcommands to legacy handler
@@ -769,14 +864,14 @@ pub struct TasksArgs {
 pub enum TasksAction {
     /// Create enhanced tasks.md
     Init {
-        /// Change id (e.g. 005-08_migrate-cli-to-clap)
-        change_id: String,
+        /// Change id (e.g. 005-08_migrate-cli-to-clap); prompts for one when omitted
+        change_id: Option<String>,
     },
 
     /// Show task progress
     Status {
-        /// Change id (e.g. 005-08_migrate-cli-to-clap)
-        change_id: String,
+        /// Change id (e.g. 005-08_migrate-cli-to-clap); prompts for one when omitted
+        change_id: Option<String>,
 
         /// Wave number (optional)
         #[arg(long)]
@@ -785,8 +880,8 @@ pub enum TasksAction {
 
     /// Show the next available task
     Next {
-        /// Change id (e.g. 005-08_migrate-cli-to-clap)
-        change_id: String,
+        /// Change id (e.g. 005-08_migrate-cli-to-clap); prompts for one when omitted
+        change_id: Option<String>,
     },
 
     /// Show all ready tasks (pending tasks in earliest incomplete wave)
@@ -840,6 +935,20 @@ pub enum TasksAction {
         task_id: String,
     },
 
+    /// Move a task to a TDD phase (red, green, refactor)
+    ///
+    /// Runs the task's Verify command first: it must fail to enter red and
+    /// pass to enter green or refactor. Phases must follow the
+    /// red -> green -> refactor cycle.
+    Phase {
+        /// Change id (e.g. 005-08_migrate-cli-to-clap)
+        change_id: String,
+        /// Task id (e.g. 1.1)
+        task_id: String,
+        /// Phase to move the task to
+        phase: TasksPhase,
+    },
+
     /// Add a new task (enhanced only)
     Add {
         /// Change id (e.g. 005-08_migrate-cli-to-clap)
@@ -849,12 +958,15 @@ pub enum TasksAction {
         /// Wave number (default: 1)
         #[arg(long, default_value_t = 1)]
         wave: u32,
+        /// Tracking file to add to, when the change tracks several (default: the first)
+        #[arg(long)]
+        file: Option<String>,
     },
 
     /// Print tasks.md
     Show {
-        /// Change id (e.g. 005-08_migrate-cli-to-clap)
-        change_id: String,
+        /// Change id (e.g. 005-08_migrate-cli-to-clap); prompts for one when omitted
+        change_id: Option<String>,
     },
 
     /// Forward unknown subcommands to legacy handler
@@ -862,6 +974,13 @@ pub enum TasksAction {
     External(Vec<String>),
 }
 
+#[derive(ValueEnum, Debug, Clone, Copy)]
+pub enum TasksPhase {
+    Red,
+    Green,
+    Refactor,
+}
+
 #[derive(Args, Debug, Clone)]
 pub struct RawArgs {
     #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
@@ -907,6 +1026,18 @@ pub struct ArchiveArgs {
     /// Skip validation checks
     #[arg(long = "no-validate")]
     pub no_validate: bool,
+
+    /// Refuse to archive unless the coverage report meets defaults.testing.coverage.target_percent
+    #[arg(long = "require-coverage")]
+    pub require_coverage: bool,
+
+    /// Archive every change whose tasks are all complete
+    #[arg(long = "all-complete", conflicts_with = "change")]
+    pub all_complete: bool,
+
+    /// Limit --all-complete to a module id
+    #[arg(long, requires = "all_complete")]
+    pub module: Option<String>,
 }
 
 /// Display artifact completion status for a change.
@@ -994,78 +1125,6 @@ pub enum ValidateItemType {
     Module,
 }
 
-/// Run iterative AI loop against a change proposal.
-#[derive(Args, Debug, Clone)]
-pub struct RalphArgs {
-    /// Target a specific change
-    #[arg(long)]
-    pub change: Option<String>,
-
-    /// Target a module (selects a change)
-    #[arg(long)]
-    pub module: Option<String>,
-
-    /// Harness to run
-    #[arg(long, default_value = "opencode")]
-    pub harness: String,
-
-    /// Model id for the harness
-    #[arg(long)]
-    pub model: Option<String>,
-
-    /// Minimum iterations before stopping
-    #[arg(long = "min-iterations", default_value_t = 1)]
-    pub min_iterations: u32,
-
-    /// Maximum iterations (default: unlimited)
-    #[arg(long = "max-iterations")]
-    pub max_iterations: Option<u32>,
-
-    /// Completion promise token
-    #[arg(long = "completion-promise", default_value = "COMPLETE")]
-    pub completion_promise: String,
-
-    /// Allow all tool actions (dangerous)
-    #[arg(long = "allow-all", alias = "yolo", alias = "dangerously-allow-all")]
-    pub allow_all: bool,
-
-    /// Do not create git commits per iteration
-    #[arg(long = "no-commit")]
-    pub no_commit: bool,
-
-    /// Show current Ralph state for the change
-    #[arg(long)]
-    pub status: bool,
-
-    /// Append extra context to the Ralph loop
-    #[arg(long = "add-context")]
-    pub add_context: Option<String>,
-
-    /// Clear the Ralph loop context file
-    #[arg(long = "clear-context")]
-    pub clear_context: bool,
-
-    /// Do not prompt for selections
-    #[arg(long = "no-interactive")]
-    pub no_interactive: bool,
-
-    /// Verbose output
-    #[arg(short = 'v', long)]
-    pub verbose: bool,
-
-    /// Hidden testing flag
-    #[arg(long = "stub-script", hide = true)]
-    pub stub_script: Option<String>,
-
-    /// Inactivity timeout (e.g. 15m)
-    #[arg(long = "timeout")]
-    pub timeout: Option<String>,
-
-    /// Prompt text
-    #[arg(value_name = "PROMPT", num_args = 0.., trailing_var_arg = true)]
-    pub prompt: Vec<String>,
-}
-
 /// Split a large change into smaller changes.
 ///
 /// Note: This command is currently a stub in `spool-cli`.
@@ -0,0 +1,107 @@
+use clap::{Args, Subcommand, ValueEnum};
+
+/// Project planning tools.
+#[derive(Args, Debug, Clone)]
+#[command(subcommand_required = true, arg_required_else_help = true)]
+#[command(disable_help_subcommand = true)]
+pub struct PlanArgs {
+    #[command(subcommand)]
+    pub action: Option<PlanAction>,
+}
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum PlanAction {
+    /// Initialize planning structure
+    Init,
+
+    /// Show current milestone progress
+    Status,
+
+    /// Add or complete milestones
+    Milestone {
+        #[command(subcommand)]
+        action: PlanMilestoneAction,
+    },
+
+    /// Add phases, link them to changes, or set their status
+    Phase {
+        #[command(subcommand)]
+        action: PlanPhaseAction,
+    },
+}
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum PlanMilestoneAction {
+    /// Add a milestone to ROADMAP.md
+    Add {
+        /// Milestone name (e.g. v2-scale)
+        name: String,
+
+        /// Goal for the milestone
+        #[arg(long)]
+        target: Option<String>,
+
+        /// Make it the current milestone
+        #[arg(long)]
+        current: bool,
+    },
+
+    /// Mark a milestone complete (defaults to the current milestone)
+    Complete {
+        /// Milestone name
+        name: Option<String>,
+    },
+}
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum PlanPhaseAction {
+    /// Add a phase to a milestone
+    Add {
+        /// Phase name
+        #[arg(value_name = "NAME", num_args = 1.., required = true)]
+        name: Vec<String>,
+
+        /// Milestone to add to (defaults to the current milestone)
+        #[arg(long)]
+        milestone: Option<String>,
+
+        /// Change id whose task progress drives the phase status (repeatable)
+        #[arg(long = "change", value_name = "CHANGE_ID")]
+        changes: Vec<String>,
+    },
+
+    /// Link changes to a phase
+    Link {
+        /// Phase number
+        phase: String,
+
+        /// Change ids to link
+        #[arg(value_name = "CHANGE_ID", num_args = 1.., required = true)]
+        changes: Vec<String>,
+
+        /// Milestone containing the phase (defaults to the current milestone)
+        #[arg(long)]
+        milestone: Option<String>,
+    },
+
+    /// Set the status of a phase that is not linked to changes
+    SetStatus {
+        /// Phase number
+        phase: String,
+
+        /// New status
+        #[arg(value_enum)]
+        status: PlanPhaseStatus,
+
+        /// Milestone containing the phase (defaults to the current milestone)
+        #[arg(long)]
+        milestone: Option<String>,
+    },
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy)]
+pub enum PlanPhaseStatus {
+    Pending,
+    InProgress,
+    Complete,
+}
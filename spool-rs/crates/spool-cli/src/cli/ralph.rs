@@ -0,0 +1,129 @@
+use clap::Args;
+
+/// Run iterative AI loop against a change proposal.
+#[derive(Args, Debug, Clone)]
+pub struct RalphArgs {
+    /// Target a specific change
+    #[arg(long)]
+    pub change: Option<String>,
+
+    /// Target a module (selects a change)
+    #[arg(long)]
+    pub module: Option<String>,
+
+    /// Harness to run
+    #[arg(long, default_value = "opencode")]
+    pub harness: String,
+
+    /// Model id for the harness
+    #[arg(long)]
+    pub model: Option<String>,
+
+    /// Minimum iterations before stopping
+    #[arg(long = "min-iterations", default_value_t = 1)]
+    pub min_iterations: u32,
+
+    /// Maximum iterations (default: unlimited)
+    #[arg(long = "max-iterations")]
+    pub max_iterations: Option<u32>,
+
+    /// Completion promise token
+    #[arg(long = "completion-promise", default_value = "COMPLETE")]
+    pub completion_promise: String,
+
+    /// Only finish once every task in tasks.md is complete
+    #[arg(long = "require-tasks")]
+    pub require_tasks: bool,
+
+    /// Only finish once `spool validate <change> --strict` is clean
+    #[arg(long = "require-validate")]
+    pub require_validate: bool,
+
+    /// Only finish once the coverage report meets defaults.testing.coverage.target_percent
+    #[arg(long = "require-coverage")]
+    pub require_coverage: bool,
+
+    /// Only finish once this shell command exits 0 (e.g. "cargo test")
+    #[arg(long, value_name = "CMD")]
+    pub verify: Option<String>,
+
+    /// Give up after N consecutive failed or timed-out attempts
+    #[arg(long = "max-failures", value_name = "N", default_value_t = 3)]
+    pub max_failures: u32,
+
+    /// Delay before retrying a failed attempt, doubled per consecutive failure (0 disables)
+    #[arg(long = "retry-backoff", value_name = "DURATION", default_value = "5s")]
+    pub retry_backoff: String,
+
+    /// Stop once the loop has spent this much, in USD (e.g. 5.00)
+    #[arg(long = "max-cost", value_name = "USD")]
+    pub max_cost: Option<f64>,
+
+    /// Stop once the loop has run this long (e.g. 2h, 45m)
+    #[arg(long = "max-duration", value_name = "DURATION")]
+    pub max_duration: Option<String>,
+
+    /// Approximate token budget for change, module and git context in each prompt
+    #[arg(long = "context-budget", value_name = "TOKENS")]
+    pub context_budget: Option<usize>,
+
+    /// Allow all tool actions (dangerous)
+    #[arg(long = "allow-all", alias = "yolo", alias = "dangerously-allow-all")]
+    pub allow_all: bool,
+
+    /// Do not create git commits per iteration
+    #[arg(long = "no-commit")]
+    pub no_commit: bool,
+
+    /// Show current Ralph state for the change
+    #[arg(long)]
+    pub status: bool,
+
+    /// Replay the recorded transcript of iteration N
+    #[arg(long, value_name = "N")]
+    pub log: Option<u32>,
+
+    /// Run one loop per ready change in --module, N at a time, each in its own worktree
+    #[arg(long, value_name = "N", requires = "module", conflicts_with = "change")]
+    pub parallel: Option<usize>,
+
+    /// Run iterations on a dedicated branch in a separate git worktree
+    #[arg(long)]
+    pub worktree: bool,
+
+    /// Merge the change's Ralph worktree branch and remove the worktree
+    #[arg(long, conflicts_with_all = ["discard", "worktree"])]
+    pub merge: bool,
+
+    /// Remove the change's Ralph worktree and branch without merging
+    #[arg(long, conflicts_with = "worktree")]
+    pub discard: bool,
+
+    /// Append extra context to the Ralph loop
+    #[arg(long = "add-context")]
+    pub add_context: Option<String>,
+
+    /// Clear the Ralph loop context file
+    #[arg(long = "clear-context")]
+    pub clear_context: bool,
+
+    /// Do not prompt for selections
+    #[arg(long = "no-interactive")]
+    pub no_interactive: bool,
+
+    /// Verbose output
+    #[arg(short = 'v', long)]
+    pub verbose: bool,
+
+    /// Hidden testing flag
+    #[arg(long = "stub-script", hide = true)]
+    pub stub_script: Option<String>,
+
+    /// Inactivity timeout (e.g. 15m)
+    #[arg(long = "timeout")]
+    pub timeout: Option<String>,
+
+    /// Prompt text
+    #[arg(value_name = "PROMPT", num_args = 0.., trailing_var_arg = true)]
+    pub prompt: Vec<String>,
+}
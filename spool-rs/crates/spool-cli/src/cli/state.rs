@@ -0,0 +1,119 @@
+use clap::{Args, Subcommand, ValueEnum};
+
+/// View and update planning/STATE.md.
+#[derive(Args, Debug, Clone)]
+#[command(subcommand_required = true, arg_required_else_help = true)]
+#[command(disable_help_subcommand = true)]
+pub struct StateArgs {
+    #[command(subcommand)]
+    pub action: Option<StateAction>,
+}
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum StateAction {
+    /// Show current project state
+    Show,
+
+    /// Record a decision
+    Decision {
+        /// Text to record
+        #[arg(value_name = "TEXT", num_args = 0.., trailing_var_arg = true)]
+        text: Vec<String>,
+    },
+
+    /// Record a blocker
+    Blocker {
+        /// Text to record
+        #[arg(value_name = "TEXT", num_args = 0.., trailing_var_arg = true)]
+        text: Vec<String>,
+    },
+
+    /// Add a session note
+    Note {
+        /// Text to record
+        #[arg(value_name = "TEXT", num_args = 0.., trailing_var_arg = true)]
+        text: Vec<String>,
+    },
+
+    /// Set current focus
+    Focus {
+        /// Text to record
+        #[arg(value_name = "TEXT", num_args = 0.., trailing_var_arg = true)]
+        text: Vec<String>,
+    },
+
+    /// Add an open question
+    Question {
+        /// Text to record
+        #[arg(value_name = "TEXT", num_args = 0.., trailing_var_arg = true)]
+        text: Vec<String>,
+    },
+
+    /// List recorded entries
+    List {
+        /// Only list entries of this type
+        #[arg(long = "type", value_enum)]
+        entry_type: Option<StateEntryType>,
+
+        /// Hide answered questions and resolved blockers
+        #[arg(long)]
+        open: bool,
+
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Mark a blocker as resolved
+    Resolve {
+        /// Entry type to resolve
+        #[arg(value_enum)]
+        target: StateResolveTarget,
+
+        /// Entry number (as shown by `spool state list`)
+        number: usize,
+
+        /// Optional resolution note
+        #[arg(value_name = "TEXT", num_args = 0.., trailing_var_arg = true)]
+        text: Vec<String>,
+    },
+
+    /// Answer an open question
+    Answer {
+        /// Entry type to answer
+        #[arg(value_enum)]
+        target: StateAnswerTarget,
+
+        /// Entry number (as shown by `spool state list`)
+        number: usize,
+
+        /// Answer text
+        #[arg(value_name = "TEXT", num_args = 1.., trailing_var_arg = true, required = true)]
+        text: Vec<String>,
+    },
+
+    /// Remove session notes older than a number of days
+    Prune {
+        /// Age in days; sessions dated before today minus this are removed
+        #[arg(long = "older-than", value_name = "DAYS", default_value_t = 30)]
+        older_than: u32,
+    },
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy)]
+pub enum StateEntryType {
+    Decision,
+    Question,
+    Blocker,
+    Note,
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy)]
+pub enum StateResolveTarget {
+    Blocker,
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy)]
+pub enum StateAnswerTarget {
+    Question,
+}
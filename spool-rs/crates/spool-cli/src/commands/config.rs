@@ -1,6 +1,8 @@
-use crate::cli::{ConfigArgs, ConfigCommand};
-use crate::cli_error::{CliError, CliResult, fail, to_cli_error};
+use crate::cli::{ConfigArgs, ConfigCommand, ConfigCommonArgs, ConfigScopeArg};
+use crate::cli_error::{CliError, CliResult, fail, silent_fail, to_cli_error};
 use crate::runtime::Runtime;
+use crate::util::parse_string_flag;
+use spool_core::config::ConfigScope;
 use std::path::{Path, PathBuf};
 
 pub(crate) fn handle_config(rt: &Runtime, args: &[String]) -> CliResult<()> {
@@ -24,7 +26,36 @@ pub(crate) fn handle_config(rt: &Runtime, args: &[String]) -> CliResult<()> {
         return handle_config_schema(output.as_deref());
     }
 
-    let Some(path) = spool_core::config::global_config_path(rt.ctx()) else {
+    if sub == "explain" {
+        let key = args.get(1).map(|s| s.as_str()).unwrap_or("");
+        if key.is_empty() || key.starts_with('-') {
+            return fail("Missing required argument <key>");
+        }
+        return handle_config_explain(rt, key);
+    }
+
+    if sub == "check" {
+        let strict = args.iter().any(|a| a == "--strict");
+        let want_json = args.iter().any(|a| a == "--json");
+        return handle_config_check(rt, strict, want_json);
+    }
+
+    let scope = match parse_string_flag(args, "--scope").as_deref() {
+        None | Some("global") => ConfigScope::Global,
+        Some("repo") => ConfigScope::Repo,
+        Some("project") => ConfigScope::Project,
+        Some("spool-dir") => ConfigScope::SpoolDir,
+        Some(other) => {
+            return fail(format!(
+                "Invalid scope '{other}' (expected project, repo, spool-dir or global)"
+            ));
+        }
+    };
+    let spool_path = rt.spool_path();
+    let project_root = spool_path.parent().unwrap_or(spool_path);
+    let Some(path) =
+        spool_core::config::config_scope_path(scope, project_root, spool_path, rt.ctx())
+    else {
         return fail("No Spool config directory found");
     };
 
@@ -68,6 +99,9 @@ pub(crate) fn handle_config(rt: &Runtime, args: &[String]) -> CliResult<()> {
             let value = parse_json_value_arg(raw, force_string)?;
             json_set_path(&mut v, key, value)?;
 
+            if let Some(parent) = path.parent() {
+                spool_core::io::create_dir_all_std(parent).map_err(to_cli_error)?;
+            }
             let bytes = serde_json::to_vec_pretty(&v).map_err(to_cli_error)?;
             let mut bytes = bytes;
             bytes.push(b'\n');
@@ -100,40 +134,25 @@ pub(crate) fn handle_config_clap(rt: &Runtime, args: &ConfigArgs) -> CliResult<(
             handle_config(rt, &argv)
         }
         Some(ConfigCommand::Path(common)) => {
-            let mut argv: Vec<String> = vec!["path".to_string()];
-            if common.string {
-                argv.push("--string".to_string());
-            }
-            handle_config(rt, &argv)
+            handle_config(rt, &with_common(vec!["path".to_string()], common))
         }
         Some(ConfigCommand::List(common)) => {
-            let mut argv: Vec<String> = vec!["list".to_string()];
-            if common.string {
-                argv.push("--string".to_string());
-            }
-            handle_config(rt, &argv)
-        }
-        Some(ConfigCommand::Get { key, common }) => {
-            let mut argv: Vec<String> = vec!["get".to_string(), key.clone()];
-            if common.string {
-                argv.push("--string".to_string());
-            }
-            handle_config(rt, &argv)
-        }
-        Some(ConfigCommand::Set { key, value, common }) => {
-            let mut argv: Vec<String> = vec!["set".to_string(), key.clone(), value.clone()];
-            if common.string {
-                argv.push("--string".to_string());
-            }
-            handle_config(rt, &argv)
-        }
-        Some(ConfigCommand::Unset { key, common }) => {
-            let mut argv: Vec<String> = vec!["unset".to_string(), key.clone()];
-            if common.string {
-                argv.push("--string".to_string());
-            }
-            handle_config(rt, &argv)
+            handle_config(rt, &with_common(vec!["list".to_string()], common))
         }
+        Some(ConfigCommand::Get { key, common }) => handle_config(
+            rt,
+            &with_common(vec!["get".to_string(), key.clone()], common),
+        ),
+        Some(ConfigCommand::Set { key, value, common }) => handle_config(
+            rt,
+            &with_common(vec!["set".to_string(), key.clone(), value.clone()], common),
+        ),
+        Some(ConfigCommand::Unset { key, common }) => handle_config(
+            rt,
+            &with_common(vec!["unset".to_string(), key.clone()], common),
+        ),
+        Some(ConfigCommand::Explain { key }) => handle_config_explain(rt, key),
+        Some(ConfigCommand::Check { strict, json }) => handle_config_check(rt, *strict, *json),
         Some(ConfigCommand::Schema { output }) => handle_config_schema(output.as_deref()),
         Some(ConfigCommand::External(v)) => {
             let sub = v.first().map(|s| s.as_str()).unwrap_or("");
@@ -143,6 +162,133 @@ pub(crate) fn handle_config_clap(rt: &Runtime, args: &ConfigArgs) -> CliResult<(
     }
 }
 
+fn with_common(mut argv: Vec<String>, common: &ConfigCommonArgs) -> Vec<String> {
+    if common.string {
+        argv.push("--string".to_string());
+    }
+    if let Some(scope) = common.scope {
+        let scope = match scope {
+            ConfigScopeArg::Project => "project",
+            ConfigScopeArg::Repo => "repo",
+            ConfigScopeArg::SpoolDir => "spool-dir",
+            ConfigScopeArg::Global => "global",
+        };
+        argv.push("--scope".to_string());
+        argv.push(scope.to_string());
+    }
+    argv
+}
+
+/// Print a project config key's effective value and every source that sets it.
+fn handle_config_explain(rt: &Runtime, key: &str) -> CliResult<()> {
+    let spool_path = rt.spool_path();
+    let project_root = spool_path.parent().unwrap_or(spool_path);
+    let explanation =
+        spool_core::config::explain_project_config_key(project_root, spool_path, rt.ctx(), key);
+    let Some(value) = &explanation.value else {
+        return fail(format!("Key '{key}' is not set in the project config"));
+    };
+
+    let label = |source: &spool_core::config::ConfigKeySource| match &source.path {
+        Some(p) => p
+            .strip_prefix(project_root)
+            .unwrap_or(p)
+            .display()
+            .to_string(),
+        None => "built-in default".to_string(),
+    };
+    println!("{key} = {}", json_render_inline(value));
+    let mut sources = explanation.sources.iter().rev();
+    if let Some(top) = sources.next() {
+        println!("Source: {}", label(top));
+    }
+    let overridden: Vec<_> = sources.collect();
+    if !overridden.is_empty() {
+        let verb = if value.is_object() {
+            "Merged with"
+        } else {
+            "Overrides"
+        };
+        println!("{verb}:");
+        for source in overridden {
+            println!("  {}: {}", label(source), json_render_inline(&source.value));
+        }
+    }
+    Ok(())
+}
+
+/// Report schema problems in every project config file; issues fail the command with `--strict`.
+fn handle_config_check(rt: &Runtime, strict: bool, want_json: bool) -> CliResult<()> {
+    let spool_path = rt.spool_path();
+    let project_root = spool_path.parent().unwrap_or(spool_path);
+    let checks = spool_core::config::check_project_config(project_root, spool_path, rt.ctx());
+    let level = if strict { "ERROR" } else { "WARNING" };
+    let display = |p: &Path| {
+        p.strip_prefix(project_root)
+            .unwrap_or(p)
+            .display()
+            .to_string()
+    };
+    let issue_count: usize = checks.iter().map(|c| c.issues.len()).sum();
+    let valid = !strict || issue_count == 0;
+
+    if want_json {
+        let files: Vec<serde_json::Value> = checks
+            .iter()
+            .map(|c| {
+                let issues: Vec<serde_json::Value> = c
+                    .issues
+                    .iter()
+                    .map(|i| {
+                        serde_json::json!({
+                            "level": level,
+                            "pointer": i.pointer,
+                            "message": i.message,
+                        })
+                    })
+                    .collect();
+                serde_json::json!({ "path": display(&c.path), "issues": issues })
+            })
+            .collect();
+        let out = serde_json::json!({ "valid": valid, "files": files });
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&out).map_err(to_cli_error)?
+        );
+    } else if checks.is_empty() {
+        println!("No project config files found");
+    } else {
+        for c in &checks {
+            if c.issues.is_empty() {
+                println!("✓ {}", display(&c.path));
+                continue;
+            }
+            println!("✗ {}", display(&c.path));
+            for issue in &c.issues {
+                println!("  [{level}] {issue}");
+            }
+        }
+        let files = checks.len();
+        println!(
+            "Checked {files} config file{}: {issue_count} issue{}",
+            if files == 1 { "" } else { "s" },
+            if issue_count == 1 { "" } else { "s" }
+        );
+    }
+
+    if !valid {
+        return silent_fail();
+    }
+    Ok(())
+}
+
+fn json_render_inline(v: &serde_json::Value) -> String {
+    match v {
+        serde_json::Value::String(s) => s.clone(),
+        _ => serde_json::to_string(v).unwrap_or_else(|_| "null".to_string()),
+    }
+}
+
 fn handle_config_schema(output: Option<&Path>) -> CliResult<()> {
     let schema = spool_core::config::schema::config_schema_pretty_json();
 
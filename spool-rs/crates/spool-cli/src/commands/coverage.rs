@@ -0,0 +1,97 @@
+use crate::cli::{CoverageAction, CoverageArgs};
+use crate::cli_error::{CliError, CliResult, fail, silent_fail, to_cli_error};
+use crate::runtime::Runtime;
+use spool_core::coverage::{self as core_coverage, LineCoverage};
+use spool_domain::changes::ChangeRepository;
+
+pub(crate) fn handle_coverage_clap(rt: &Runtime, args: &CoverageArgs) -> CliResult<()> {
+    let Some(action) = &args.action else {
+        return Err(CliError::msg("Missing required coverage subcommand"));
+    };
+
+    match action {
+        CoverageAction::Check {
+            change,
+            report,
+            json,
+        } => {
+            let spool_path = rt.spool_path();
+            let project_root = spool_path.parent().unwrap_or(spool_path);
+            if !ChangeRepository::new(spool_path).exists(change) {
+                return fail(format!("Change '{change}' not found"));
+            }
+            let defaults =
+                core_coverage::load_coverage_defaults(project_root, spool_path, rt.ctx())
+                    .map_err(to_cli_error)?;
+            let report = match report {
+                Some(p) if p.is_file() => p.clone(),
+                Some(p) => return fail(format!("Coverage report not found: {}", p.display())),
+                None => {
+                    core_coverage::find_coverage_report(project_root, defaults.report.as_deref())
+                        .map_err(to_cli_error)?
+                }
+            };
+            let check = core_coverage::check_change_coverage(
+                spool_path,
+                change,
+                &report,
+                defaults.target_percent,
+                rt.ctx(),
+            )
+            .map_err(to_cli_error)?;
+
+            if *json {
+                let rendered = serde_json::to_string_pretty(&check).map_err(to_cli_error)?;
+                println!("{rendered}");
+            } else {
+                let report = check.report.strip_prefix(project_root).unwrap_or(&report);
+                println!(
+                    "Coverage report: {} ({})",
+                    report.display(),
+                    match check.format {
+                        core_coverage::CoverageFormat::Lcov => "LCOV",
+                        core_coverage::CoverageFormat::Cobertura => "Cobertura",
+                    }
+                );
+                println!("Target: {}%", check.target_percent);
+                println!("Overall: {}", describe(&check.overall));
+                match &check.change {
+                    Some(c) => println!("Change files: {}", describe(c)),
+                    None if check.files.is_empty() => {
+                        println!("Change files: none listed in tasks")
+                    }
+                    None => println!("Change files: no coverage data"),
+                }
+                let width = check.files.iter().map(|f| f.path.len()).max().unwrap_or(0);
+                for f in &check.files {
+                    let detail = match &f.coverage {
+                        Some(c) => describe(c),
+                        None => "no coverage data".to_string(),
+                    };
+                    println!("  {:<width$}  {detail}", f.path);
+                }
+                println!();
+                if check.passed {
+                    println!("✔ Coverage meets the {}% target", check.target_percent);
+                } else {
+                    for reason in check.failures() {
+                        if reason.starts_with(' ') {
+                            eprintln!("{reason}");
+                        } else {
+                            eprintln!("✗ {reason}");
+                        }
+                    }
+                }
+            }
+
+            if !check.passed {
+                return silent_fail();
+            }
+            Ok(())
+        }
+    }
+}
+
+fn describe(c: &LineCoverage) -> String {
+    format!("{:.1}% ({}/{} lines)", c.percent, c.covered, c.total)
+}
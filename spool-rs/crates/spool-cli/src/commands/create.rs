@@ -35,6 +35,7 @@ pub(crate) fn handle_create_clap(rt: &Runtime, args: &CreateArgs) -> CliResult<(
             schema,
             module,
             description,
+            tracks,
         } => {
             let mut out = vec!["change".to_string()];
             if let Some(name) = name {
@@ -52,6 +53,10 @@ pub(crate) fn handle_create_clap(rt: &Runtime, args: &CreateArgs) -> CliResult<(
                 out.push("--description".to_string());
                 out.push(description.clone());
             }
+            if let Some(tracks) = tracks {
+                out.push("--tracks".to_string());
+                out.push(tracks.clone());
+            }
             out
         }
         CreateAction::External(rest) => rest.clone(),
@@ -102,6 +107,9 @@ pub(crate) fn handle_create(rt: &Runtime, args: &[String]) -> CliResult<()> {
                 .unwrap_or_else(|| core_workflow::default_schema_name().to_string());
             let module = parse_string_flag(args, "--module");
             let description = parse_string_flag(args, "--description");
+            let tracks = parse_string_flag(args, "--tracks")
+                .map(|raw| split_csv(&raw))
+                .unwrap_or_default();
 
             let module_id = module
                 .as_deref()
@@ -127,6 +135,7 @@ pub(crate) fn handle_create(rt: &Runtime, args: &[String]) -> CliResult<()> {
                 spool_path,
                 name,
                 &schema,
+                &tracks,
                 module.as_deref(),
                 description.as_deref(),
             ) {
@@ -168,6 +177,9 @@ pub(crate) fn handle_new(rt: &Runtime, args: &[String]) -> CliResult<()> {
         .unwrap_or_else(|| core_workflow::default_schema_name().to_string());
     let module = parse_string_flag(args, "--module");
     let description = parse_string_flag(args, "--description");
+    let tracks = parse_string_flag(args, "--tracks")
+        .map(|raw| split_csv(&raw))
+        .unwrap_or_default();
 
     let spool_path = rt.spool_path();
 
@@ -193,6 +205,7 @@ pub(crate) fn handle_new(rt: &Runtime, args: &[String]) -> CliResult<()> {
         spool_path,
         name,
         &schema,
+        &tracks,
         module.as_deref(),
         description.as_deref(),
     ) {
@@ -222,6 +235,7 @@ pub(crate) fn handle_new_clap(rt: &Runtime, args: &NewArgs) -> CliResult<()> {
             schema,
             module,
             description,
+            tracks,
         } => {
             let mut out = vec!["change".to_string()];
             if let Some(name) = name {
@@ -239,6 +253,10 @@ pub(crate) fn handle_new_clap(rt: &Runtime, args: &NewArgs) -> CliResult<()> {
                 out.push("--description".to_string());
                 out.push(description.clone());
             }
+            if let Some(tracks) = tracks {
+                out.push("--tracks".to_string());
+                out.push(tracks.clone());
+            }
             out
         }
         NewAction::External(rest) => rest.clone(),
@@ -1,8 +1,10 @@
 pub(crate) mod completions;
 pub(crate) mod config;
+pub(crate) mod coverage;
 pub(crate) mod create;
 pub(crate) mod help;
 pub(crate) mod plan;
+pub(crate) mod schema;
 pub(crate) mod serve;
 pub(crate) mod state;
 pub(crate) mod stats;
@@ -11,11 +13,13 @@ pub(crate) mod workflow;
 
 pub(crate) use completions::handle_completions;
 pub(crate) use config::handle_config_clap;
+pub(crate) use coverage::handle_coverage_clap;
 pub(crate) use create::handle_create_clap;
 pub(crate) use create::handle_new_clap;
 pub(crate) use help::handle_help_all_flags;
 pub(crate) use help::handle_help_clap;
 pub(crate) use plan::handle_plan_clap;
+pub(crate) use schema::handle_schema_clap;
 pub(crate) use serve::handle_serve_clap;
 pub(crate) use state::handle_state_clap;
 pub(crate) use stats::handle_stats_clap;
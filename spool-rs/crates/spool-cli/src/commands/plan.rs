@@ -1,7 +1,9 @@
-use crate::cli::{PlanAction, PlanArgs};
+use crate::cli::{PlanAction, PlanArgs, PlanMilestoneAction, PlanPhaseAction, PlanPhaseStatus};
 use crate::cli_error::{CliError, CliResult, to_cli_error};
 use crate::runtime::Runtime;
 use spool_domain::planning as wf_planning;
+use spool_domain::planning::{ChangeProgress, PhaseStatus, Roadmap};
+use std::path::Path;
 
 pub(crate) fn handle_plan_clap(rt: &Runtime, args: &PlanArgs) -> CliResult<()> {
     let Some(action) = &args.action else {
@@ -27,40 +29,170 @@ pub(crate) fn handle_plan_clap(rt: &Runtime, args: &PlanArgs) -> CliResult<()> {
             Ok(())
         }
         PlanAction::Status => {
-            let roadmap_path = wf_planning::planning_dir(spool_path).join("ROADMAP.md");
-            let contents = spool_core::io::read_to_string(&roadmap_path).map_err(|_| {
-                CliError::msg(
-                    "ROADMAP.md not found. Run \"spool init\" or \"spool plan init\" first.",
-                )
-            })?;
-
-            let Some((milestone, status, phase)) = wf_planning::read_current_progress(&contents)
-            else {
+            let contents = read_roadmap(spool_path)?;
+            let mut roadmap = Roadmap::parse(&contents);
+            if roadmap.current.is_none() {
                 return Err(CliError::msg(
                     "Could not find current milestone section in ROADMAP.md",
                 ));
+            }
+            roadmap.refresh_progress(|id| change_progress(rt, id));
+            let Some(current) = &roadmap.current else {
+                return Ok(());
             };
-            let phases = wf_planning::read_phase_rows(&contents);
 
             println!("Current Progress");
             println!("────────────────────────────────────────");
-            println!("Milestone: {milestone}");
-            println!("Status: {status}");
-            println!("Phase: {phase}");
+            println!("Milestone: {}", current.name);
+            println!("Status: {}", current.status);
+            println!("Phase: {}", current.phase);
             println!();
             println!("Phases");
             println!("────────────────────────────────────────");
-            for (num, name, st, _changes) in phases {
-                let icon = if st.eq_ignore_ascii_case("Complete") {
-                    "✓"
-                } else if st.eq_ignore_ascii_case("In Progress") {
-                    "●"
-                } else {
-                    "○"
-                };
-                println!("  {icon} Phase {num}: {name} [{st}]");
+            for milestone in &roadmap.milestones {
+                for phase in &milestone.phases {
+                    let icon = match phase.status {
+                        PhaseStatus::Complete => "✓",
+                        PhaseStatus::InProgress => "●",
+                        PhaseStatus::Pending => "○",
+                    };
+                    println!(
+                        "  {icon} Phase {}: {} [{}]",
+                        phase.number,
+                        phase.name,
+                        phase.status.as_str()
+                    );
+                    for change in &phase.changes {
+                        let p = change_progress(rt, change);
+                        println!("      - {change} ({}/{} tasks)", p.complete, p.total);
+                    }
+                }
             }
             Ok(())
         }
+        PlanAction::Milestone { action } => {
+            let contents = read_roadmap(spool_path)?;
+            let mut roadmap = Roadmap::parse(&contents);
+            let message = match action {
+                PlanMilestoneAction::Add {
+                    name,
+                    target,
+                    current,
+                } => {
+                    roadmap
+                        .add_milestone(name, target.as_deref(), *current)
+                        .map_err(CliError::msg)?;
+                    format!("Milestone added: {name}")
+                }
+                PlanMilestoneAction::Complete { name } => {
+                    let name = milestone_or_current(&roadmap, name.as_deref())?;
+                    roadmap
+                        .complete_milestone(&name, &current_date)
+                        .map_err(CliError::msg)?;
+                    format!("Milestone completed: {name}")
+                }
+            };
+            write_roadmap(rt, &mut roadmap)?;
+            eprintln!("✔ {message}");
+            Ok(())
+        }
+        PlanAction::Phase { action } => {
+            let contents = read_roadmap(spool_path)?;
+            let mut roadmap = Roadmap::parse(&contents);
+            let message = match action {
+                PlanPhaseAction::Add {
+                    name,
+                    milestone,
+                    changes,
+                } => {
+                    let milestone = milestone_or_current(&roadmap, milestone.as_deref())?;
+                    let name = name.join(" ");
+                    let number = roadmap
+                        .add_phase(&milestone, &name, changes.clone())
+                        .map_err(CliError::msg)?;
+                    format!("Phase {number} added to {milestone}: {name}")
+                }
+                PlanPhaseAction::Link {
+                    phase,
+                    changes,
+                    milestone,
+                } => {
+                    let milestone = milestone_or_current(&roadmap, milestone.as_deref())?;
+                    roadmap
+                        .link_phase(&milestone, phase, changes)
+                        .map_err(CliError::msg)?;
+                    format!("Phase {phase} linked to {}", changes.join(", "))
+                }
+                PlanPhaseAction::SetStatus {
+                    phase,
+                    status,
+                    milestone,
+                } => {
+                    let milestone = milestone_or_current(&roadmap, milestone.as_deref())?;
+                    let status = match status {
+                        PlanPhaseStatus::Pending => PhaseStatus::Pending,
+                        PlanPhaseStatus::InProgress => PhaseStatus::InProgress,
+                        PlanPhaseStatus::Complete => PhaseStatus::Complete,
+                    };
+                    roadmap
+                        .set_phase_status(&milestone, phase, status)
+                        .map_err(CliError::msg)?;
+                    format!("Phase {phase} status: {}", status.as_str())
+                }
+            };
+            write_roadmap(rt, &mut roadmap)?;
+            eprintln!("✔ {message}");
+            Ok(())
+        }
+    }
+}
+
+fn read_roadmap(spool_path: &Path) -> CliResult<String> {
+    let roadmap_path = wf_planning::roadmap_path(spool_path);
+    spool_core::io::read_to_string(&roadmap_path).map_err(|_| {
+        CliError::msg("ROADMAP.md not found. Run \"spool init\" or \"spool plan init\" first.")
+    })
+}
+
+fn write_roadmap(rt: &Runtime, roadmap: &mut Roadmap) -> CliResult<()> {
+    roadmap.refresh_progress(|id| change_progress(rt, id));
+    let roadmap_path = wf_planning::roadmap_path(rt.spool_path());
+    spool_core::io::write(&roadmap_path, roadmap.to_markdown().as_bytes()).map_err(to_cli_error)
+}
+
+fn milestone_or_current(roadmap: &Roadmap, name: Option<&str>) -> CliResult<String> {
+    if let Some(name) = name {
+        return Ok(name.to_string());
+    }
+    roadmap
+        .current_name()
+        .map(str::to_string)
+        .ok_or_else(|| CliError::msg("No current milestone in ROADMAP.md; pass --milestone"))
+}
+
+/// Task progress for a linked change. Archived changes count as complete.
+fn change_progress(rt: &Runtime, change_id: &str) -> ChangeProgress {
+    let spool_path = rt.spool_path();
+    if spool_core::paths::change_dir(spool_path, change_id).exists() {
+        let (complete, total) = spool_core::workflow::task_repository(spool_path, rt.ctx())
+            .get_task_counts(change_id)
+            .unwrap_or((0, 0));
+        return ChangeProgress {
+            complete: complete as usize,
+            total: total as usize,
+        };
+    }
+    let archive_dir = spool_core::paths::changes_archive_dir(spool_path);
+    let suffix = format!("-{change_id}");
+    let archived = spool_core::discovery::list_dir_names(&archive_dir)
+        .unwrap_or_default()
+        .iter()
+        .any(|name| name.ends_with(&suffix));
+    if archived {
+        return ChangeProgress {
+            complete: 1,
+            total: 1,
+        };
     }
+    ChangeProgress::default()
 }
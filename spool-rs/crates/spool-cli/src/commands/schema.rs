@@ -0,0 +1,82 @@
+use crate::app::common::schema_not_found_message;
+use crate::app::validate::render_validate_results;
+use crate::cli::{SchemaAction, SchemaArgs};
+use crate::cli_error::{CliError, CliResult, fail, silent_fail, to_cli_error};
+use crate::runtime::Runtime;
+use spool_core::{validate as core_validate, workflow as core_workflow};
+
+pub(crate) fn handle_schema_clap(rt: &Runtime, args: &SchemaArgs) -> CliResult<()> {
+    let Some(action) = &args.action else {
+        return Err(CliError::msg("Missing required schema subcommand"));
+    };
+
+    let spool_path = rt.spool_path();
+    let ctx = rt.ctx();
+
+    match action {
+        SchemaAction::New { name, from, user } => {
+            let from = from
+                .as_deref()
+                .unwrap_or(core_workflow::default_schema_name());
+            if core_workflow::find_schema_dir(spool_path, from, ctx).is_none() {
+                return fail(schema_not_found_message(spool_path, ctx, from));
+            }
+            let root = if *user {
+                let Some(dir) = core_workflow::user_schemas_dir(ctx) else {
+                    return fail("Cannot locate the user schema directory (HOME is not set)");
+                };
+                dir
+            } else {
+                core_workflow::project_schemas_dir(spool_path)
+            };
+            let dest = core_workflow::scaffold_schema(spool_path, &root, name, from, ctx)
+                .map_err(to_cli_error)?;
+            println!(
+                "Created schema '{name}' from '{from}' at {}",
+                dest.display()
+            );
+            println!();
+            println!("Next steps:");
+            println!("  - Edit schema.yaml and the files in templates/");
+            println!("  - spool schema preview {name}");
+            println!("  - spool schema validate {name}");
+            Ok(())
+        }
+        SchemaAction::Preview { name } => {
+            let resolved = core_workflow::resolve_schema(spool_path, Some(name), ctx).map_err(
+                |e| match e {
+                    core_workflow::WorkflowError::SchemaNotFound(name) => {
+                        CliError::msg(schema_not_found_message(spool_path, ctx, &name))
+                    }
+                    other => to_cli_error(other),
+                },
+            )?;
+            let preview = core_workflow::render_schema_preview(&resolved).map_err(to_cli_error)?;
+            print!("{preview}");
+            Ok(())
+        }
+        SchemaAction::Validate { name, strict, json } => {
+            let names = match name {
+                Some(name) => {
+                    if core_workflow::find_schema_dir(spool_path, name, ctx).is_none() {
+                        return fail(schema_not_found_message(spool_path, ctx, name));
+                    }
+                    vec![name.clone()]
+                }
+                None => core_workflow::list_available_schemas(spool_path, ctx),
+            };
+
+            let mut results = Vec::with_capacity(names.len());
+            for name in names {
+                let report = core_validate::validate_schema(spool_path, &name, ctx, *strict)
+                    .map_err(to_cli_error)?;
+                results.push((name, report));
+            }
+            if render_validate_results("schema", &results, *json) {
+                Ok(())
+            } else {
+                silent_fail()
+            }
+        }
+    }
+}
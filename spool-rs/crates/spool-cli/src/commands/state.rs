@@ -1,7 +1,8 @@
-use crate::cli::{StateAction, StateArgs};
+use crate::cli::{StateAction, StateArgs, StateEntryType};
 use crate::cli_error::{CliError, CliResult, to_cli_error};
 use crate::runtime::Runtime;
 use spool_domain::state as wf_state;
+use spool_domain::state::StateEntryKind;
 
 pub(crate) fn handle_state_clap(rt: &Runtime, args: &StateArgs) -> CliResult<()> {
     let Some(action) = &args.action else {
@@ -21,28 +22,38 @@ pub(crate) fn handle_state_clap(rt: &Runtime, args: &StateArgs) -> CliResult<()>
         )));
     }
 
-    if matches!(action, StateAction::Show) {
-        let contents = spool_core::io::read_to_string(&state_path)
-            .map_err(|_| CliError::msg("Failed to read STATE.md"))?;
-        print!("{contents}");
-        return Ok(());
+    let contents = spool_core::io::read_to_string(&state_path)
+        .map_err(|_| CliError::msg("Failed to read STATE.md"))?;
+
+    match action {
+        StateAction::Show => {
+            print!("{contents}");
+            return Ok(());
+        }
+        StateAction::List {
+            entry_type,
+            open,
+            json,
+        } => return list_entries(&contents, *entry_type, *open, *json),
+        _ => {}
     }
 
     let text = match action {
-        StateAction::Show => String::new(),
+        StateAction::Show | StateAction::List { .. } | StateAction::Prune { .. } => String::new(),
         StateAction::Decision { text }
         | StateAction::Blocker { text }
         | StateAction::Note { text }
         | StateAction::Focus { text }
-        | StateAction::Question { text } => text.join(" "),
+        | StateAction::Question { text }
+        | StateAction::Resolve { text, .. }
+        | StateAction::Answer { text, .. } => text.join(" "),
     };
 
-    let contents = spool_core::io::read_to_string(&state_path)
-        .map_err(|_| CliError::msg("Failed to read STATE.md"))?;
     let date = wf_state::now_date();
+    let mut pruned = 0usize;
 
     let updated = match action {
-        StateAction::Show => Ok(contents),
+        StateAction::Show | StateAction::List { .. } => Ok(contents),
         StateAction::Decision { .. } => wf_state::add_decision(&contents, &date, &text),
         StateAction::Blocker { .. } => wf_state::add_blocker(&contents, &date, &text),
         StateAction::Question { .. } => wf_state::add_question(&contents, &date, &text),
@@ -51,6 +62,22 @@ pub(crate) fn handle_state_clap(rt: &Runtime, args: &StateArgs) -> CliResult<()>
             let time = wf_state::now_time();
             wf_state::add_note(&contents, &date, &time, &text)
         }
+        StateAction::Resolve { number, .. } => {
+            let note = (!text.is_empty()).then_some(text.as_str());
+            wf_state::resolve_blocker(&contents, &date, *number, note)
+        }
+        StateAction::Answer { number, .. } => {
+            wf_state::answer_question(&contents, &date, *number, &text)
+        }
+        StateAction::Prune { older_than } => {
+            let cutoff = (chrono::Local::now().date_naive()
+                - chrono::Duration::days(i64::from(*older_than)))
+            .format("%Y-%m-%d")
+            .to_string();
+            let (out, n) = wf_state::prune_notes(&contents, &cutoff);
+            pruned = n;
+            Ok(out)
+        }
     };
 
     let updated = match updated {
@@ -61,13 +88,73 @@ pub(crate) fn handle_state_clap(rt: &Runtime, args: &StateArgs) -> CliResult<()>
     spool_core::io::write(&state_path, updated.as_bytes()).map_err(to_cli_error)?;
 
     match action {
-        StateAction::Show => {}
+        StateAction::Show | StateAction::List { .. } => {}
         StateAction::Decision { .. } => eprintln!("✔ Decision recorded: {text}"),
         StateAction::Blocker { .. } => eprintln!("✔ Blocker recorded: {text}"),
         StateAction::Note { .. } => eprintln!("✔ Note recorded: {text}"),
         StateAction::Focus { .. } => eprintln!("✔ Focus updated: {text}"),
         StateAction::Question { .. } => eprintln!("✔ Question added: {text}"),
+        StateAction::Resolve { number, .. } => eprintln!("✔ Blocker #{number} resolved"),
+        StateAction::Answer { number, .. } => eprintln!("✔ Question #{number} answered"),
+        StateAction::Prune { older_than } => {
+            eprintln!("✔ Pruned {pruned} session(s) older than {older_than} day(s)")
+        }
     }
 
     Ok(())
 }
+
+fn list_entries(
+    contents: &str,
+    entry_type: Option<StateEntryType>,
+    open: bool,
+    json: bool,
+) -> CliResult<()> {
+    let kind = entry_type.map(|t| match t {
+        StateEntryType::Decision => StateEntryKind::Decision,
+        StateEntryType::Question => StateEntryKind::Question,
+        StateEntryType::Blocker => StateEntryKind::Blocker,
+        StateEntryType::Note => StateEntryKind::Note,
+    });
+    let state = wf_state::parse_state(contents);
+    let entries: Vec<&wf_state::StateEntry> = state
+        .entries
+        .iter()
+        .filter(|e| kind.is_none_or(|k| e.kind == k))
+        .filter(|e| !open || !e.resolved)
+        .collect();
+
+    if json {
+        let rendered = serde_json::to_string_pretty(&serde_json::json!({ "entries": entries }))
+            .expect("json should serialize");
+        println!("{rendered}");
+        return Ok(());
+    }
+
+    if entries.is_empty() {
+        println!("No entries found.");
+        return Ok(());
+    }
+    for e in entries {
+        let status = match (e.kind, e.resolved) {
+            (StateEntryKind::Question, true) => " [answered]",
+            (StateEntryKind::Blocker, true) => " [resolved]",
+            _ => "",
+        };
+        let when = match (&e.date, &e.time) {
+            (Some(d), Some(t)) => format!(" {d} {t}"),
+            (Some(d), None) => format!(" {d}"),
+            _ => String::new(),
+        };
+        println!(
+            "{} #{}{when}{status}: {}",
+            e.kind.as_str(),
+            e.number,
+            e.text
+        );
+        if let Some(resolution) = &e.resolution {
+            println!("    → {resolution}");
+        }
+    }
+    Ok(())
+}
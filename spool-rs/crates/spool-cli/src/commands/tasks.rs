@@ -1,9 +1,10 @@
-use crate::cli::{TasksAction, TasksArgs};
+use crate::cli::{TasksAction, TasksArgs, TasksPhase};
 use crate::cli_error::{CliError, CliResult, fail, to_cli_error};
 use crate::diagnostics;
 use crate::runtime::Runtime;
 use spool_core::paths as core_paths;
 use spool_domain::tasks as wf_tasks;
+use std::path::{Path, PathBuf};
 
 pub(crate) fn handle_tasks_clap(rt: &Runtime, args: &TasksArgs) -> CliResult<()> {
     let Some(action) = &args.action else {
@@ -12,16 +13,16 @@ pub(crate) fn handle_tasks_clap(rt: &Runtime, args: &TasksArgs) -> CliResult<()>
     };
 
     let forwarded: Vec<String> = match action {
-        TasksAction::Init { change_id } => vec!["init".to_string(), change_id.clone()],
+        TasksAction::Init { change_id } => with_change_id("init", change_id),
         TasksAction::Status { change_id, wave } => {
-            let mut out = vec!["status".to_string(), change_id.clone()];
+            let mut out = with_change_id("status", change_id);
             if let Some(wave) = wave {
                 out.push("--wave".to_string());
                 out.push(wave.to_string());
             }
             out
         }
-        TasksAction::Next { change_id } => vec!["next".to_string(), change_id.clone()],
+        TasksAction::Next { change_id } => with_change_id("next", change_id),
         TasksAction::Ready { change_id, json } => {
             let mut out = vec!["ready".to_string()];
             if let Some(id) = change_id {
@@ -44,24 +45,55 @@ pub(crate) fn handle_tasks_clap(rt: &Runtime, args: &TasksArgs) -> CliResult<()>
         TasksAction::Unshelve { change_id, task_id } => {
             vec!["unshelve".to_string(), change_id.clone(), task_id.clone()]
         }
+        TasksAction::Phase {
+            change_id,
+            task_id,
+            phase,
+        } => {
+            let phase = match phase {
+                TasksPhase::Red => wf_tasks::TddPhase::Red,
+                TasksPhase::Green => wf_tasks::TddPhase::Green,
+                TasksPhase::Refactor => wf_tasks::TddPhase::Refactor,
+            };
+            vec![
+                "phase".to_string(),
+                change_id.clone(),
+                task_id.clone(),
+                phase.as_label().to_string(),
+            ]
+        }
         TasksAction::Add {
             change_id,
             task_name,
             wave,
-        } => vec![
-            "add".to_string(),
-            change_id.clone(),
-            task_name.clone(),
-            "--wave".to_string(),
-            wave.to_string(),
-        ],
-        TasksAction::Show { change_id } => vec!["show".to_string(), change_id.clone()],
+            file,
+        } => {
+            let mut out = vec![
+                "add".to_string(),
+                change_id.clone(),
+                task_name.clone(),
+                "--wave".to_string(),
+                wave.to_string(),
+            ];
+            if let Some(file) = file {
+                out.push("--file".to_string());
+                out.push(file.clone());
+            }
+            out
+        }
+        TasksAction::Show { change_id } => with_change_id("show", change_id),
         TasksAction::External(rest) => rest.clone(),
     };
 
     handle_tasks(rt, &forwarded)
 }
 
+fn with_change_id(sub: &str, change_id: &Option<String>) -> Vec<String> {
+    let mut out = vec![sub.to_string()];
+    out.extend(change_id.iter().cloned());
+    out
+}
+
 pub(crate) fn handle_tasks(rt: &Runtime, args: &[String]) -> CliResult<()> {
     fn parse_wave_flag(args: &[String]) -> u32 {
         args.iter()
@@ -92,10 +124,21 @@ pub(crate) fn handle_tasks(rt: &Runtime, args: &[String]) -> CliResult<()> {
         return handle_tasks_ready(rt, args);
     }
 
-    let change_id = args.get(1).map(|s| s.as_str()).unwrap_or("");
-    if change_id.is_empty() || change_id.starts_with('-') {
+    let mut change_id = args
+        .get(1)
+        .filter(|s| !s.starts_with('-'))
+        .cloned()
+        .unwrap_or_default();
+    if change_id.is_empty()
+        && matches!(sub, "init" | "status" | "next" | "show")
+        && crate::app::picker::ui_interactive(args)
+    {
+        change_id = crate::app::picker::pick_change(rt, None)?;
+    }
+    if change_id.is_empty() {
         return fail("Missing required argument <change-id>");
     }
+    let change_id = change_id.as_str();
 
     let change_dir = core_paths::change_dir(spool_path, change_id);
 
@@ -104,28 +147,40 @@ pub(crate) fn handle_tasks(rt: &Runtime, args: &[String]) -> CliResult<()> {
             if !change_dir.exists() {
                 return fail(format!("Change '{change_id}' not found"));
             }
-            let path = wf_tasks::tasks_path(spool_path, change_id);
-            if path.exists() {
+            let tracked =
+                spool_core::workflow::change_tracked_files(spool_path, change_id, rt.ctx());
+            let missing: Vec<&String> = tracked
+                .iter()
+                .filter(|f| !wf_tasks::tracked_file_path(spool_path, change_id, f).exists())
+                .collect();
+            if missing.is_empty() {
                 return fail(format!(
-                    "tasks.md already exists for \"{change_id}\". Use \"tasks add\" to add tasks."
+                    "{} already exists for \"{change_id}\". Use \"tasks add\" to add tasks.",
+                    tracked.join(", ")
                 ));
             }
 
             let now = chrono::Local::now();
             let contents = wf_tasks::enhanced_tasks_template(change_id, now);
-            if let Some(parent) = path.parent() {
-                spool_core::io::create_dir_all(parent).map_err(to_cli_error)?;
+            for file in missing {
+                let path = wf_tasks::tracked_file_path(spool_path, change_id, file);
+                if let Some(parent) = path.parent() {
+                    spool_core::io::create_dir_all(parent).map_err(to_cli_error)?;
+                }
+                spool_core::io::write(&path, contents.as_bytes()).map_err(to_cli_error)?;
+                eprintln!("✔ Enhanced {file} created for \"{change_id}\"");
             }
-            spool_core::io::write(&path, contents.as_bytes()).map_err(to_cli_error)?;
-            eprintln!("✔ Enhanced tasks.md created for \"{change_id}\"");
             Ok(())
         }
         "status" => {
-            let path = wf_tasks::tasks_path(spool_path, change_id);
+            let tracked =
+                spool_core::workflow::change_tracked_files(spool_path, change_id, rt.ctx());
+            if tracked.len() > 1 {
+                return print_tracked_status(rt, change_id);
+            }
+            let path = wf_tasks::tracked_file_path(spool_path, change_id, &tracked[0]);
             if !path.exists() {
-                println!(
-                    "No tasks.md found for \"{change_id}\". Run \"spool tasks init {change_id}\" first."
-                );
+                println!("{}", missing_tracking_file(&path, change_id));
                 return Ok(());
             }
 
@@ -143,65 +198,17 @@ pub(crate) fn handle_tasks(rt: &Runtime, args: &[String]) -> CliResult<()> {
             println!("──────────────────────────────────────────────────");
             println!();
 
-            let warnings = diagnostics::render_task_diagnostics(
-                &path,
-                &parsed.diagnostics,
-                wf_tasks::DiagnosticLevel::Warning,
-            );
-            if !warnings.is_empty() {
-                println!("Warnings");
-                print!("{warnings}");
-                println!();
-            }
-
-            match parsed.format {
-                wf_tasks::TasksFormat::Enhanced => {
-                    let done = parsed.progress.complete + parsed.progress.shelved;
-                    println!(
-                        "Progress: {}/{} done ({} complete, {} shelved), {} in-progress, {} pending",
-                        done,
-                        parsed.progress.total,
-                        parsed.progress.complete,
-                        parsed.progress.shelved,
-                        parsed.progress.in_progress,
-                        parsed.progress.pending
-                    );
-                }
-                wf_tasks::TasksFormat::Checkbox => {
-                    println!(
-                        "Progress (compat): {}/{} complete, {} in-progress, {} pending",
-                        parsed.progress.complete,
-                        parsed.progress.total,
-                        parsed.progress.in_progress,
-                        parsed.progress.pending
-                    );
-                }
-            }
-
-            let (ready, blocked) = wf_tasks::compute_ready_and_blocked(&parsed);
-            println!();
-            println!("Ready");
-            for t in &ready {
-                println!("  - {}: {}", t.id, t.name);
-            }
-            println!();
-            println!("Blocked");
-            for (t, blockers) in &blocked {
-                println!("  - {}: {}", t.id, t.name);
-                for b in blockers {
-                    println!("    - {b}");
-                }
-            }
-
+            print_task_status(&path, &parsed, None);
             Ok(())
         }
         "next" => {
-            let path = wf_tasks::tasks_path(spool_path, change_id);
-            let contents = spool_core::io::read_to_string(&path).map_err(|_| {
-                CliError::msg(format!(
-                    "No tasks.md found for \"{change_id}\". Run \"spool tasks init {change_id}\" first."
-                ))
-            })?;
+            let (path, file) = next_tracking_file(rt, change_id)?;
+            let q = |id: &str| match &file {
+                Some(f) => wf_tasks::qualified_task_id(f, id),
+                None => id.to_string(),
+            };
+            let contents = spool_core::io::read_to_string(&path)
+                .map_err(|_| CliError::msg(missing_tracking_file(&path, change_id)))?;
             let parsed = wf_tasks::parse_tasks_tracking_file(&contents);
 
             if let Some(msg) = diagnostics::blocking_task_error_message(&path, &parsed.diagnostics)
@@ -218,10 +225,10 @@ pub(crate) fn handle_tasks(rt: &Runtime, args: &[String]) -> CliResult<()> {
                     if let Some(t) = current {
                         println!("Current Task (compat)");
                         println!("──────────────────────────────────────────────────");
-                        println!("Task {}: {}", t.id, t.name);
+                        println!("Task {}: {}", q(&t.id), t.name);
                         println!(
                             "Run \"spool tasks complete {change_id} {}\" when done",
-                            t.id
+                            q(&t.id)
                         );
                         return Ok(());
                     }
@@ -233,11 +240,14 @@ pub(crate) fn handle_tasks(rt: &Runtime, args: &[String]) -> CliResult<()> {
                     if let Some(t) = next {
                         println!("Next Task (compat)");
                         println!("──────────────────────────────────────────────────");
-                        println!("Task {}: {}", t.id, t.name);
-                        println!("Run \"spool tasks start {change_id} {}\" to begin", t.id);
+                        println!("Task {}: {}", q(&t.id), t.name);
+                        println!(
+                            "Run \"spool tasks start {change_id} {}\" to begin",
+                            q(&t.id)
+                        );
                         println!(
                             "Run \"spool tasks complete {change_id} {}\" when done",
-                            t.id
+                            q(&t.id)
                         );
                         return Ok(());
                     }
@@ -255,7 +265,7 @@ pub(crate) fn handle_tasks(rt: &Runtime, args: &[String]) -> CliResult<()> {
                     if ready.is_empty() {
                         println!("No ready tasks.");
                         if let Some((t, blockers)) = blocked.first() {
-                            println!("First blocked task: {} - {}", t.id, t.name);
+                            println!("First blocked task: {} - {}", q(&t.id), t.name);
                             println!("{}", format_blockers(blockers));
                         }
                         return Ok(());
@@ -264,7 +274,7 @@ pub(crate) fn handle_tasks(rt: &Runtime, args: &[String]) -> CliResult<()> {
                     let t = &ready[0];
                     println!("Next Task");
                     println!("──────────────────────────────────────────────────");
-                    println!("Task {}: {}", t.id, t.name);
+                    println!("Task {}: {}", q(&t.id), t.name);
                     println!();
                     if !t.files.is_empty() {
                         println!("Files: {}", t.files.join(", "));
@@ -282,22 +292,23 @@ pub(crate) fn handle_tasks(rt: &Runtime, args: &[String]) -> CliResult<()> {
                         println!("Done When: {v}");
                     }
                     println!();
-                    println!("Run \"spool tasks start {change_id} {}\" to begin", t.id);
+                    println!(
+                        "Run \"spool tasks start {change_id} {}\" to begin",
+                        q(&t.id)
+                    );
                     Ok(())
                 }
             }
         }
         "start" => {
-            let task_id = args.get(2).map(|s| s.as_str()).unwrap_or("");
-            if task_id.is_empty() || task_id.starts_with('-') {
+            let task_ref = args.get(2).map(|s| s.as_str()).unwrap_or("");
+            if task_ref.is_empty() || task_ref.starts_with('-') {
                 return fail("Missing required argument <task-id>");
             }
-            let path = wf_tasks::tasks_path(spool_path, change_id);
-            let contents = spool_core::io::read_to_string(&path).map_err(|_| {
-                CliError::msg(format!(
-                    "No tasks.md found for \"{change_id}\". Run \"spool tasks init {change_id}\" first."
-                ))
-            })?;
+            let (path, task_id) = resolve_task_ref(rt, change_id, task_ref)?;
+            let task_id = task_id.as_str();
+            let contents = spool_core::io::read_to_string(&path)
+                .map_err(|_| CliError::msg(missing_tracking_file(&path, change_id)))?;
             let parsed = wf_tasks::parse_tasks_tracking_file(&contents);
             if parsed.format == wf_tasks::TasksFormat::Checkbox {
                 let Some(current) = parsed
@@ -317,7 +328,7 @@ pub(crate) fn handle_tasks(rt: &Runtime, args: &[String]) -> CliResult<()> {
                             return fail(format!("Task \"{task_id}\" is already complete"));
                         }
                         wf_tasks::TaskStatus::Shelved => {
-                            return fail("Checkbox-only tasks.md does not support shelving.");
+                            return fail(checkbox_unsupported(&path, "shelving"));
                         }
                     }
 
@@ -344,7 +355,7 @@ pub(crate) fn handle_tasks(rt: &Runtime, args: &[String]) -> CliResult<()> {
             }
 
             let Some(task) = parsed.tasks.iter().find(|t| t.id == task_id) else {
-                return fail(format!("Task \"{task_id}\" not found in tasks.md"));
+                return fail(task_not_found(&path, task_id));
             };
             let status_label = match task.status {
                 wf_tasks::TaskStatus::Pending => "pending",
@@ -382,16 +393,14 @@ pub(crate) fn handle_tasks(rt: &Runtime, args: &[String]) -> CliResult<()> {
             Ok(())
         }
         "complete" => {
-            let task_id = args.get(2).map(|s| s.as_str()).unwrap_or("");
-            if task_id.is_empty() || task_id.starts_with('-') {
+            let task_ref = args.get(2).map(|s| s.as_str()).unwrap_or("");
+            if task_ref.is_empty() || task_ref.starts_with('-') {
                 return fail("Missing required argument <task-id>");
             }
-            let path = wf_tasks::tasks_path(spool_path, change_id);
-            let contents = spool_core::io::read_to_string(&path).map_err(|_| {
-                CliError::msg(format!(
-                    "No tasks.md found for \"{change_id}\". Run \"spool tasks init {change_id}\" first."
-                ))
-            })?;
+            let (path, task_id) = resolve_task_ref(rt, change_id, task_ref)?;
+            let task_id = task_id.as_str();
+            let contents = spool_core::io::read_to_string(&path)
+                .map_err(|_| CliError::msg(missing_tracking_file(&path, change_id)))?;
             let parsed = wf_tasks::parse_tasks_tracking_file(&contents);
             if parsed.format == wf_tasks::TasksFormat::Checkbox {
                 let updated = wf_tasks::update_checkbox_task_status(
@@ -421,19 +430,17 @@ pub(crate) fn handle_tasks(rt: &Runtime, args: &[String]) -> CliResult<()> {
             Ok(())
         }
         "shelve" => {
-            let task_id = args.get(2).map(|s| s.as_str()).unwrap_or("");
-            if task_id.is_empty() || task_id.starts_with('-') {
+            let task_ref = args.get(2).map(|s| s.as_str()).unwrap_or("");
+            if task_ref.is_empty() || task_ref.starts_with('-') {
                 return fail("Missing required argument <task-id>");
             }
-            let path = wf_tasks::tasks_path(spool_path, change_id);
-            let contents = spool_core::io::read_to_string(&path).map_err(|_| {
-                CliError::msg(format!(
-                    "No tasks.md found for \"{change_id}\". Run \"spool tasks init {change_id}\" first."
-                ))
-            })?;
+            let (path, task_id) = resolve_task_ref(rt, change_id, task_ref)?;
+            let task_id = task_id.as_str();
+            let contents = spool_core::io::read_to_string(&path)
+                .map_err(|_| CliError::msg(missing_tracking_file(&path, change_id)))?;
             let parsed = wf_tasks::parse_tasks_tracking_file(&contents);
             if parsed.format == wf_tasks::TasksFormat::Checkbox {
-                return fail("Checkbox-only tasks.md does not support shelving.");
+                return fail(checkbox_unsupported(&path, "shelving"));
             }
 
             if let Some(msg) = diagnostics::blocking_task_error_message(&path, &parsed.diagnostics)
@@ -442,7 +449,7 @@ pub(crate) fn handle_tasks(rt: &Runtime, args: &[String]) -> CliResult<()> {
             }
 
             let Some(task) = parsed.tasks.iter().find(|t| t.id == task_id) else {
-                return fail(format!("Task \"{task_id}\" not found in tasks.md"));
+                return fail(task_not_found(&path, task_id));
             };
             if task.status == wf_tasks::TaskStatus::Complete {
                 return fail(format!("Task \"{task_id}\" is already complete"));
@@ -459,19 +466,17 @@ pub(crate) fn handle_tasks(rt: &Runtime, args: &[String]) -> CliResult<()> {
             Ok(())
         }
         "unshelve" => {
-            let task_id = args.get(2).map(|s| s.as_str()).unwrap_or("");
-            if task_id.is_empty() || task_id.starts_with('-') {
+            let task_ref = args.get(2).map(|s| s.as_str()).unwrap_or("");
+            if task_ref.is_empty() || task_ref.starts_with('-') {
                 return fail("Missing required argument <task-id>");
             }
-            let path = wf_tasks::tasks_path(spool_path, change_id);
-            let contents = spool_core::io::read_to_string(&path).map_err(|_| {
-                CliError::msg(format!(
-                    "No tasks.md found for \"{change_id}\". Run \"spool tasks init {change_id}\" first."
-                ))
-            })?;
+            let (path, task_id) = resolve_task_ref(rt, change_id, task_ref)?;
+            let task_id = task_id.as_str();
+            let contents = spool_core::io::read_to_string(&path)
+                .map_err(|_| CliError::msg(missing_tracking_file(&path, change_id)))?;
             let parsed = wf_tasks::parse_tasks_tracking_file(&contents);
             if parsed.format == wf_tasks::TasksFormat::Checkbox {
-                return fail("Checkbox-only tasks.md does not support shelving.");
+                return fail(checkbox_unsupported(&path, "shelving"));
             }
 
             if let Some(msg) = diagnostics::blocking_task_error_message(&path, &parsed.diagnostics)
@@ -480,7 +485,7 @@ pub(crate) fn handle_tasks(rt: &Runtime, args: &[String]) -> CliResult<()> {
             }
 
             let Some(task) = parsed.tasks.iter().find(|t| t.id == task_id) else {
-                return fail(format!("Task \"{task_id}\" not found in tasks.md"));
+                return fail(task_not_found(&path, task_id));
             };
             if task.status != wf_tasks::TaskStatus::Shelved {
                 return fail(format!("Task \"{task_id}\" is not shelved"));
@@ -502,12 +507,26 @@ pub(crate) fn handle_tasks(rt: &Runtime, args: &[String]) -> CliResult<()> {
                 return fail("Missing required argument <task-name>");
             }
             let wave = parse_wave_flag(args);
-            let path = wf_tasks::tasks_path(spool_path, change_id);
-            let contents = spool_core::io::read_to_string(&path).map_err(|_| {
-                CliError::msg(format!(
-                    "No tasks.md found for \"{change_id}\". Run \"spool tasks init {change_id}\" first."
-                ))
-            })?;
+            let tracked =
+                spool_core::workflow::change_tracked_files(spool_path, change_id, rt.ctx());
+            let file = match args
+                .iter()
+                .position(|a| a == "--file")
+                .map(|i| args.get(i + 1))
+            {
+                Some(Some(f)) if tracked.contains(f) => f.clone(),
+                Some(Some(f)) => {
+                    return fail(format!(
+                        "Unknown tracking file \"{f}\" (tracked: {})",
+                        tracked.join(", ")
+                    ));
+                }
+                Some(None) => return fail("Missing value for --file"),
+                None => tracked[0].clone(),
+            };
+            let path = wf_tasks::tracked_file_path(spool_path, change_id, &file);
+            let contents = spool_core::io::read_to_string(&path)
+                .map_err(|_| CliError::msg(missing_tracking_file(&path, change_id)))?;
             let parsed = wf_tasks::parse_tasks_tracking_file(&contents);
             if parsed.format != wf_tasks::TasksFormat::Enhanced {
                 return fail(
@@ -562,13 +581,22 @@ pub(crate) fn handle_tasks(rt: &Runtime, args: &[String]) -> CliResult<()> {
             }
 
             spool_core::io::write(&path, out.as_bytes()).map_err(to_cli_error)?;
-            eprintln!("✔ Task {new_id} \"{task_name}\" added to Wave {wave}");
+            if tracked.len() > 1 {
+                eprintln!("✔ Task {new_id} \"{task_name}\" added to Wave {wave} in {file}");
+            } else {
+                eprintln!("✔ Task {new_id} \"{task_name}\" added to Wave {wave}");
+            }
             Ok(())
         }
         "show" => {
-            let path = wf_tasks::tasks_path(spool_path, change_id);
+            let tracked =
+                spool_core::workflow::change_tracked_files(spool_path, change_id, rt.ctx());
+            if tracked.len() > 1 {
+                return show_tracked_files(spool_path, change_id, &tracked);
+            }
+            let path = wf_tasks::tracked_file_path(spool_path, change_id, &tracked[0]);
             let contents = spool_core::io::read_to_string(&path)
-                .map_err(|_| CliError::msg(format!("tasks.md not found for \"{change_id}\"")))?;
+                .map_err(|_| CliError::msg(missing_tracking_file(&path, change_id)))?;
             let parsed = wf_tasks::parse_tasks_tracking_file(&contents);
 
             if let Some(msg) = diagnostics::blocking_task_error_message(&path, &parsed.diagnostics)
@@ -578,10 +606,355 @@ pub(crate) fn handle_tasks(rt: &Runtime, args: &[String]) -> CliResult<()> {
             print!("{contents}");
             Ok(())
         }
+        "phase" => {
+            let task_ref = args.get(2).map(|s| s.as_str()).unwrap_or("");
+            if task_ref.is_empty() || task_ref.starts_with('-') {
+                return fail("Missing required argument <task-id>");
+            }
+            let raw = args.get(3).map(|s| s.as_str()).unwrap_or("");
+            let Some(phase) = wf_tasks::TddPhase::from_label(raw) else {
+                return fail(format!(
+                    "Invalid phase '{raw}' (expected red, green or refactor)"
+                ));
+            };
+            let (path, task_id) = resolve_task_ref(rt, change_id, task_ref)?;
+            handle_tasks_phase(spool_path, change_id, &path, &task_id, phase)
+        }
         _ => fail(format!("Unknown tasks subcommand '{sub}'")),
     }
 }
 
+/// Handle `tasks phase <change_id> <task_id> <phase>`
+fn handle_tasks_phase(
+    spool_path: &Path,
+    change_id: &str,
+    path: &Path,
+    task_id: &str,
+    phase: wf_tasks::TddPhase,
+) -> CliResult<()> {
+    let contents = spool_core::io::read_to_string(path)
+        .map_err(|_| CliError::msg(missing_tracking_file(path, change_id)))?;
+    let parsed = wf_tasks::parse_tasks_tracking_file(&contents);
+    if parsed.format == wf_tasks::TasksFormat::Checkbox {
+        return fail(checkbox_unsupported(path, "TDD phases"));
+    }
+
+    if let Some(msg) = diagnostics::blocking_task_error_message(path, &parsed.diagnostics) {
+        return Err(CliError::msg(msg));
+    }
+
+    let Some(task) = parsed.tasks.iter().find(|t| t.id == task_id) else {
+        return fail(task_not_found(path, task_id));
+    };
+    match task.status {
+        wf_tasks::TaskStatus::Complete => {
+            return fail(format!("Task \"{task_id}\" is already complete"));
+        }
+        wf_tasks::TaskStatus::Shelved => {
+            return fail(format!(
+                "Task \"{task_id}\" is shelved (run \"spool tasks unshelve\" first)"
+            ));
+        }
+        wf_tasks::TaskStatus::Pending | wf_tasks::TaskStatus::InProgress => {}
+    }
+    if let Err(e) = phase.check_transition(task.tdd_phase) {
+        return fail(format!("Task \"{task_id}\": {e}"));
+    }
+    let Some(command) = task.verify.as_deref() else {
+        return fail(format!(
+            "Task \"{task_id}\" has no Verify command; TDD phases need one to check against"
+        ));
+    };
+
+    let project_root = spool_path.parent().unwrap_or(spool_path);
+    let out = std::process::Command::new("sh")
+        .args(["-c", command])
+        .current_dir(project_root)
+        .output()
+        .map_err(|e| CliError::msg(format!("Failed to run verify command `{command}`: {e}")))?;
+    if out.status.success() != phase.requires_passing_verify() {
+        let mut msg = if phase.requires_passing_verify() {
+            format!(
+                "Cannot move task \"{task_id}\" to {}: `{command}` failed",
+                phase.as_label()
+            )
+        } else {
+            format!(
+                "Cannot move task \"{task_id}\" to red: `{command}` passed (write a failing test first)"
+            )
+        };
+        let mut combined = String::from_utf8_lossy(&out.stdout).to_string();
+        combined.push_str(&String::from_utf8_lossy(&out.stderr));
+        let combined = combined.trim_end();
+        if !combined.is_empty() {
+            msg.push_str(":\n");
+            msg.push_str(combined);
+        }
+        return fail(msg);
+    }
+
+    let Some(updated) =
+        wf_tasks::update_enhanced_task_phase(&contents, task_id, phase, chrono::Local::now())
+    else {
+        return fail(task_not_found(path, task_id));
+    };
+    spool_core::io::write(path, updated.as_bytes()).map_err(to_cli_error)?;
+    eprintln!("✔ Task \"{task_id}\" moved to {}", phase.as_label());
+    Ok(())
+}
+
+/// Print warnings, progress and ready/blocked tasks for one tracking file. Task ids are
+/// qualified with `file` when given.
+fn print_task_status(path: &Path, parsed: &wf_tasks::TasksParseResult, file: Option<&str>) {
+    let q = |id: &str| match file {
+        Some(f) => wf_tasks::qualified_task_id(f, id),
+        None => id.to_string(),
+    };
+    let warnings = diagnostics::render_task_diagnostics(
+        path,
+        &parsed.diagnostics,
+        wf_tasks::DiagnosticLevel::Warning,
+    );
+    if !warnings.is_empty() {
+        println!("Warnings");
+        print!("{warnings}");
+        println!();
+    }
+
+    match parsed.format {
+        wf_tasks::TasksFormat::Enhanced => {
+            let done = parsed.progress.complete + parsed.progress.shelved;
+            println!(
+                "Progress: {}/{} done ({} complete, {} shelved), {} in-progress, {} pending",
+                done,
+                parsed.progress.total,
+                parsed.progress.complete,
+                parsed.progress.shelved,
+                parsed.progress.in_progress,
+                parsed.progress.pending
+            );
+        }
+        wf_tasks::TasksFormat::Checkbox => {
+            println!(
+                "Progress (compat): {}/{} complete, {} in-progress, {} pending",
+                parsed.progress.complete,
+                parsed.progress.total,
+                parsed.progress.in_progress,
+                parsed.progress.pending
+            );
+        }
+    }
+
+    let (ready, blocked) = wf_tasks::compute_ready_and_blocked(parsed);
+    println!();
+    println!("Ready");
+    for t in &ready {
+        println!("  - {}: {}", q(&t.id), t.name);
+    }
+    println!();
+    println!("Blocked");
+    for (t, blockers) in &blocked {
+        println!("  - {}: {}", q(&t.id), t.name);
+        for b in blockers {
+            println!("    - {b}");
+        }
+    }
+}
+
+/// `tasks status` for a change that tracks several files.
+fn print_tracked_status(rt: &Runtime, change_id: &str) -> CliResult<()> {
+    let spool_path = rt.spool_path();
+    let tracked = spool_core::workflow::task_repository(spool_path, rt.ctx())
+        .load_tracked(change_id)
+        .map_err(to_cli_error)?;
+    if !tracked.iter().any(|t| t.exists) {
+        let files: Vec<&str> = tracked.iter().map(|t| t.file.as_str()).collect();
+        println!(
+            "No tracking files ({}) found for \"{change_id}\". Run \"spool tasks init {change_id}\" first.",
+            files.join(", ")
+        );
+        return Ok(());
+    }
+    for t in tracked.iter().filter(|t| t.exists) {
+        if let Some(msg) = diagnostics::blocking_task_error_message(&t.path, &t.result.diagnostics)
+        {
+            return Err(CliError::msg(msg));
+        }
+    }
+
+    println!("Tasks for: {change_id}");
+    println!("──────────────────────────────────────────────────");
+    let mut overall = wf_tasks::TasksParseResult::empty().progress;
+    for t in &tracked {
+        println!();
+        println!("File: {}", t.file);
+        if !t.exists {
+            println!("Not created yet. Run \"spool tasks init {change_id}\" to create it.");
+            continue;
+        }
+        println!();
+        print_task_status(&t.path, &t.result, Some(&t.file));
+        overall.add(&t.result.progress);
+    }
+    println!();
+    println!(
+        "Overall: {}/{} done ({} complete, {} shelved), {} in-progress, {} pending",
+        overall.complete + overall.shelved,
+        overall.total,
+        overall.complete,
+        overall.shelved,
+        overall.in_progress,
+        overall.pending
+    );
+    Ok(())
+}
+
+/// Tracking file `tasks next` reads: the only one for single-file changes, otherwise the
+/// first file with a ready task, or else with remaining work (returned with its name so
+/// ids can be qualified).
+fn next_tracking_file(rt: &Runtime, change_id: &str) -> CliResult<(PathBuf, Option<String>)> {
+    let spool_path = rt.spool_path();
+    let tracked = spool_core::workflow::task_repository(spool_path, rt.ctx())
+        .load_tracked(change_id)
+        .map_err(to_cli_error)?;
+    if tracked.len() == 1 {
+        return Ok((tracked[0].path.clone(), None));
+    }
+    let existing: Vec<&wf_tasks::TrackedTasks> = tracked.iter().filter(|t| t.exists).collect();
+    let Some(last) = existing.last() else {
+        let files: Vec<&str> = tracked.iter().map(|t| t.file.as_str()).collect();
+        return fail(format!(
+            "No tracking files ({}) found for \"{change_id}\". Run \"spool tasks init {change_id}\" first.",
+            files.join(", ")
+        ));
+    };
+    let t = existing
+        .iter()
+        .find(|t| !wf_tasks::compute_ready_and_blocked(&t.result).0.is_empty())
+        .or_else(|| existing.iter().find(|t| t.result.progress.remaining > 0))
+        .unwrap_or(last);
+    Ok((t.path.clone(), Some(t.file.clone())))
+}
+
+/// `tasks show` for a change that tracks several files.
+fn show_tracked_files(spool_path: &Path, change_id: &str, tracked: &[String]) -> CliResult<()> {
+    let mut shown = 0;
+    for file in tracked {
+        let path = wf_tasks::tracked_file_path(spool_path, change_id, file);
+        let Ok(contents) = spool_core::io::read_to_string(&path) else {
+            continue;
+        };
+        let parsed = wf_tasks::parse_tasks_tracking_file(&contents);
+        if let Some(msg) = diagnostics::blocking_task_error_message(&path, &parsed.diagnostics) {
+            return Err(CliError::msg(msg));
+        }
+        if shown > 0 {
+            println!();
+        }
+        println!("==> {file} <==");
+        print!("{contents}");
+        shown += 1;
+    }
+    if shown == 0 {
+        return fail(format!(
+            "No tracking files ({}) found for \"{change_id}\"",
+            tracked.join(", ")
+        ));
+    }
+    Ok(())
+}
+
+/// Message for a change whose tracking file has not been created yet.
+fn missing_tracking_file(path: &Path, change_id: &str) -> String {
+    let file = tracking_file_name(path);
+    format!("No {file} found for \"{change_id}\". Run \"spool tasks init {change_id}\" first.")
+}
+
+fn task_not_found(path: &Path, task_id: &str) -> String {
+    let file = tracking_file_name(path);
+    format!("Task \"{task_id}\" not found in {file}")
+}
+
+fn checkbox_unsupported(path: &Path, feature: &str) -> String {
+    let file = tracking_file_name(path);
+    format!("Checkbox-only {file} does not support {feature}.")
+}
+
+fn tracking_file_name(path: &Path) -> std::borrow::Cow<'_, str> {
+    path.file_name().unwrap_or_default().to_string_lossy()
+}
+
+/// Resolve a task reference (`<id>` or `<file>:<id>`) to its tracking file and task id.
+///
+/// Unqualified ids are looked up across the change's tracking files and must be unique.
+fn resolve_task_ref(rt: &Runtime, change_id: &str, task_ref: &str) -> CliResult<(PathBuf, String)> {
+    let spool_path = rt.spool_path();
+    let tracked = spool_core::workflow::change_tracked_files(spool_path, change_id, rt.ctx());
+    let path = |file: &str| wf_tasks::tracked_file_path(spool_path, change_id, file);
+    let (file, id) = wf_tasks::split_task_ref(task_ref);
+    if let Some(file) = file {
+        if !tracked.iter().any(|f| f == file) {
+            return fail(format!(
+                "Unknown tracking file \"{file}\" (tracked: {})",
+                tracked.join(", ")
+            ));
+        }
+        return Ok((path(file), id.to_string()));
+    }
+    if tracked.len() > 1 {
+        let found: Vec<&String> = tracked
+            .iter()
+            .filter(|f| {
+                spool_core::io::read_to_string(&path(f)).is_ok_and(|c| {
+                    wf_tasks::parse_tasks_tracking_file(&c)
+                        .tasks
+                        .iter()
+                        .any(|t| t.id == id)
+                })
+            })
+            .collect();
+        match found.as_slice() {
+            [] => {}
+            [only] => return Ok((path(only), id.to_string())),
+            several => {
+                let refs: Vec<String> = several
+                    .iter()
+                    .map(|f| wf_tasks::qualified_task_id(f, id))
+                    .collect();
+                return fail(format!(
+                    "Task \"{id}\" is in several tracking files; use one of: {}",
+                    refs.join(", ")
+                ));
+            }
+        }
+    }
+    Ok((path(&tracked[0]), id.to_string()))
+}
+
+/// Ready tasks and remaining count across a change's tracking files. Ids are qualified
+/// as `<file>:<id>` when the change tracks several files; blocking diagnostics are errors.
+fn ready_across_files(
+    tracked: &[wf_tasks::TrackedTasks],
+) -> Result<(Vec<wf_tasks::TaskItem>, usize), String> {
+    let mut ready_out = Vec::new();
+    let mut remaining = 0;
+    for t in tracked.iter().filter(|t| t.exists) {
+        if let Some(msg) = diagnostics::blocking_task_error_message(&t.path, &t.result.diagnostics)
+        {
+            return Err(msg);
+        }
+        let (ready, _blocked) = wf_tasks::compute_ready_and_blocked(&t.result);
+        for mut task in ready {
+            if tracked.len() > 1 {
+                task.id = wf_tasks::qualified_task_id(&t.file, &task.id);
+            }
+            ready_out.push(task);
+        }
+        remaining += t.result.progress.remaining;
+    }
+    Ok((ready_out, remaining))
+}
+
 /// Handle `tasks ready [change_id] [--json]`
 fn handle_tasks_ready(rt: &Runtime, args: &[String]) -> CliResult<()> {
     let want_json = args.iter().any(|a| a == "--json");
@@ -604,21 +977,18 @@ fn handle_tasks_ready(rt: &Runtime, args: &[String]) -> CliResult<()> {
 /// Show ready tasks for a single change
 fn handle_tasks_ready_single(rt: &Runtime, change_id: &str, want_json: bool) -> CliResult<()> {
     let spool_path = rt.spool_path();
-    let path = wf_tasks::tasks_path(spool_path, change_id);
-
-    let contents = spool_core::io::read_to_string(&path).map_err(|_| {
-        CliError::msg(format!(
-            "No tasks.md found for \"{change_id}\". Run \"spool tasks init {change_id}\" first."
-        ))
-    })?;
-
-    let parsed = wf_tasks::parse_tasks_tracking_file(&contents);
-
-    if let Some(msg) = diagnostics::blocking_task_error_message(&path, &parsed.diagnostics) {
-        return Err(CliError::msg(msg));
+    let tracked = spool_core::workflow::task_repository(spool_path, rt.ctx())
+        .load_tracked(change_id)
+        .map_err(to_cli_error)?;
+    if !tracked.iter().any(|t| t.exists) {
+        let files: Vec<&str> = tracked.iter().map(|t| t.file.as_str()).collect();
+        return fail(format!(
+            "No {} found for \"{change_id}\". Run \"spool tasks init {change_id}\" first.",
+            files.join(", ")
+        ));
     }
 
-    let (ready, _blocked) = wf_tasks::compute_ready_and_blocked(&parsed);
+    let (ready, remaining) = ready_across_files(&tracked).map_err(CliError::msg)?;
 
     if want_json {
         let json_tasks: Vec<serde_json::Value> = ready
@@ -644,7 +1014,7 @@ fn handle_tasks_ready_single(rt: &Runtime, change_id: &str, want_json: bool) ->
     }
 
     if ready.is_empty() {
-        if parsed.progress.remaining == 0 {
+        if remaining == 0 {
             println!("All tasks complete for \"{change_id}\"!");
         } else {
             println!("No ready tasks for \"{change_id}\" (tasks may be blocked or shelved).");
@@ -671,10 +1041,8 @@ fn handle_tasks_ready_single(rt: &Runtime, change_id: &str, want_json: bool) ->
 
 /// Show ready tasks across all changes
 fn handle_tasks_ready_all(rt: &Runtime, want_json: bool) -> CliResult<()> {
-    use spool_domain::changes::ChangeRepository;
-
     let spool_path = rt.spool_path();
-    let change_repo = ChangeRepository::new(spool_path);
+    let change_repo = spool_core::workflow::change_repository(spool_path, rt.ctx());
     let summaries = change_repo.list().map_err(to_cli_error)?;
 
     // Only process changes that are ready (have proposal, specs, tasks, and pending work)
@@ -693,19 +1061,16 @@ fn handle_tasks_ready_all(rt: &Runtime, want_json: bool) -> CliResult<()> {
     let mut has_any_tasks = false;
 
     for summary in &ready_changes {
-        let path = wf_tasks::tasks_path(spool_path, &summary.id);
-        let Ok(contents) = spool_core::io::read_to_string(&path) else {
+        let Ok(tracked) =
+            spool_core::workflow::task_repository(spool_path, rt.ctx()).load_tracked(&summary.id)
+        else {
             continue;
         };
 
-        let parsed = wf_tasks::parse_tasks_tracking_file(&contents);
-
         // Skip if there are blocking errors
-        if diagnostics::blocking_task_error_message(&path, &parsed.diagnostics).is_some() {
+        let Ok((ready, _remaining)) = ready_across_files(&tracked) else {
             continue;
-        }
-
-        let (ready, _blocked) = wf_tasks::compute_ready_and_blocked(&parsed);
+        };
 
         if ready.is_empty() {
             continue;
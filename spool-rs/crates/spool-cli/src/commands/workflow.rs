@@ -1,6 +1,7 @@
 use crate::cli::{WorkflowAction, WorkflowArgs};
 use crate::cli_error::{CliError, CliResult, to_cli_error};
 use crate::runtime::Runtime;
+use spool_core::workflow as core_workflow;
 use spool_domain::workflow as wf_workflow;
 
 pub(crate) fn handle_workflow_clap(rt: &Runtime, args: &WorkflowArgs) -> CliResult<()> {
@@ -54,6 +55,15 @@ pub(crate) fn handle_workflow_clap(rt: &Runtime, args: &WorkflowArgs) -> CliResu
                 return Err(CliError::msg("Missing required argument <workflow-name>"));
             }
 
+            // Artifact schemas share the name space; show one when no workflow file matches.
+            if !wf_workflow::workflow_file_path(spool_path, &workflow_name).exists()
+                && let Ok(resolved) =
+                    core_workflow::resolve_schema(spool_path, Some(&workflow_name), rt.ctx())
+            {
+                print_schema(&resolved);
+                return Ok(());
+            }
+
             let wf = wf_workflow::load_workflow(spool_path, &workflow_name)
                 .map_err(|e| CliError::msg(format!("Invalid workflow: {e}")))?;
 
@@ -103,3 +113,39 @@ pub(crate) fn handle_workflow_clap(rt: &Runtime, args: &WorkflowArgs) -> CliResu
         }
     }
 }
+
+fn print_schema(resolved: &core_workflow::ResolvedSchema) {
+    let schema = &resolved.schema;
+    println!("# Schema: {}", schema.name);
+    println!("Source: {}", resolved.source.as_str());
+    if let Some(parent) = &schema.extends {
+        println!("Extends: {parent}");
+    }
+    if let Some(description) = &schema.description {
+        println!("Description: {}", description.trim());
+    }
+    println!();
+    println!("## Artifacts");
+    println!();
+    for a in &schema.artifacts {
+        let layers = resolved.artifact_layers(&a.id).join(" > ");
+        println!("  - {} [{layers}]", a.id);
+        println!("    Generates: {}", a.generates);
+        if !a.requires.is_empty() {
+            println!("    Requires: {}", a.requires.join(", "));
+        }
+        println!("    Template: {}", resolved.template_path(a).display());
+    }
+    println!();
+    if let Some(apply) = &schema.apply {
+        println!("## Apply");
+        if let Some(requires) = &apply.requires {
+            println!("Requires: {}", requires.join(", "));
+        }
+        let tracks = apply.tracks_files();
+        if !tracks.is_empty() {
+            println!("Tracks: {}", tracks.join(", "));
+        }
+        println!();
+    }
+}
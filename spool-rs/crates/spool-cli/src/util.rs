@@ -83,8 +83,8 @@ pub(crate) fn command_id_from_args(args: &[String]) -> String {
     parts.push(cmd);
 
     match cmd {
-        "create" | "new" | "plan" | "state" | "tasks" | "workflow" | "config" | "serve"
-        | "agent-config" => {
+        "create" | "new" | "plan" | "state" | "tasks" | "workflow" | "schema" | "coverage"
+        | "config" | "serve" | "agent-config" => {
             if let Some(sub) = positional.get(1).copied()
                 && !sub.starts_with('-')
             {
@@ -67,17 +67,30 @@ fn archive_with_specs_and_validation_smoke() {
     assert_eq!(out.code, 0, "stderr={}", out.stderr);
 
     let archive_root = repo.path().join(".spool/changes/archive");
-    let mut found = false;
+    let mut archived: Option<std::path::PathBuf> = None;
     for e in archive_root.read_dir().expect("archive dir") {
         let e = e.unwrap();
         if e.file_name()
             .to_string_lossy()
             .contains("000-01_test-change")
         {
-            found = true;
+            archived = Some(e.path());
         }
     }
-    assert!(found);
+    let archived = archived.expect("archived change dir");
+
+    // Archive manifest records what was changed.
+    let manifest = std::fs::read_to_string(archived.join("archive.json")).expect("archive.json");
+    let manifest: serde_json::Value = serde_json::from_str(&manifest).expect("manifest json");
+    assert_eq!(manifest["changeId"], "000-01_test-change");
+    assert_eq!(manifest["validationSkipped"], false);
+    assert_eq!(manifest["specsSkipped"], false);
+    assert_eq!(manifest["newSpecs"], serde_json::json!([]));
+    assert_eq!(manifest["updatedSpecs"], serde_json::json!(["alpha"]));
+    let spec = &manifest["specs"][0];
+    assert_eq!(spec["name"], "alpha");
+    assert!(spec["hashBefore"].as_str().unwrap().starts_with("sha256:"));
+    assert_ne!(spec["hashBefore"], spec["hashAfter"]);
 
     // Module should be marked complete.
     let module_md =
@@ -85,3 +98,204 @@ fn archive_with_specs_and_validation_smoke() {
             .expect("module.md");
     assert!(module_md.contains("- [x] 000-01_test-change"));
 }
+
+#[test]
+fn archive_manifest_records_skipped_specs_and_validation() {
+    let base = make_base_repo();
+    let repo = tempfile::tempdir().expect("work");
+    let home = tempfile::tempdir().expect("home");
+    let rust_path = assert_cmd::cargo::cargo_bin!("spool");
+
+    reset_repo(repo.path(), base.path());
+
+    let out = run_rust_candidate(
+        rust_path,
+        &[
+            "archive",
+            "000-01_test-change",
+            "-y",
+            "--skip-specs",
+            "--no-validate",
+        ],
+        repo.path(),
+        home.path(),
+    );
+    assert_eq!(out.code, 0, "stderr={}", out.stderr);
+
+    let archive_root = repo.path().join(".spool/changes/archive");
+    let archived = archive_root
+        .read_dir()
+        .expect("archive dir")
+        .map(|e| e.unwrap().path())
+        .find(|p| p.to_string_lossy().contains("000-01_test-change"))
+        .expect("archived change dir");
+
+    let manifest = std::fs::read_to_string(archived.join("archive.json")).expect("archive.json");
+    let manifest: serde_json::Value = serde_json::from_str(&manifest).expect("manifest json");
+    assert_eq!(manifest["validationSkipped"], true);
+    assert_eq!(manifest["specsSkipped"], true);
+    assert_eq!(manifest["specs"], serde_json::json!([]));
+}
+
+fn write_complete_change(root: &Path, id: &str, spec: &str, requirement: &str) {
+    write(
+        root.join(format!(".spool/changes/{id}/proposal.md")),
+        "## Why\nTest fixture\n\n## What Changes\n- Adds a small delta\n\n## Impact\n- None\n",
+    );
+    write(
+        root.join(format!(".spool/changes/{id}/tasks.md")),
+        "## 1. Implementation\n- [x] 1.1 Do a thing\n",
+    );
+    write(
+        root.join(format!(".spool/changes/{id}/specs/{spec}/spec.md")),
+        &format!(
+            "## ADDED Requirements\n\n### Requirement: Delta {id}\n{requirement}\n\n#### Scenario: Delta ok\n- **WHEN** running validation\n- **THEN** it passes\n"
+        ),
+    );
+}
+
+#[test]
+fn archive_all_complete_skips_invalid_and_conflicting_changes() {
+    let base = make_base_repo();
+    write_complete_change(
+        base.path(),
+        "000-02_invalid-change",
+        "beta",
+        "This requirement has no normative keyword.",
+    );
+    write_complete_change(
+        base.path(),
+        "000-03_conflicting-change",
+        "alpha",
+        "The system SHALL also do the alpha thing differently.",
+    );
+    write(
+        base.path()
+            .join(".spool/changes/000-04_unfinished-change/proposal.md"),
+        "## Why\nTest fixture\n\n## What Changes\n- None\n\n## Impact\n- None\n",
+    );
+    write(
+        base.path()
+            .join(".spool/changes/000-04_unfinished-change/tasks.md"),
+        "## 1. Implementation\n- [ ] 1.1 Do a thing\n",
+    );
+
+    let repo = tempfile::tempdir().expect("work");
+    let home = tempfile::tempdir().expect("home");
+    let rust_path = assert_cmd::cargo::cargo_bin!("spool");
+    reset_repo(repo.path(), base.path());
+
+    let out = run_rust_candidate(
+        rust_path,
+        &["archive", "--all-complete", "--module", "000", "-y"],
+        repo.path(),
+        home.path(),
+    );
+    assert_eq!(out.code, 0, "stderr={}", out.stderr);
+    assert!(out.stdout.contains("Archive summary:"), "{}", out.stdout);
+    assert!(out.stdout.contains("validation failed"), "{}", out.stdout);
+    assert!(
+        out.stdout
+            .contains("spec conflict: 'alpha' already updated by 000-01_test-change"),
+        "{}",
+        out.stdout
+    );
+    assert!(
+        out.stdout.contains("1 archived, 2 skipped"),
+        "{}",
+        out.stdout
+    );
+
+    let changes = repo.path().join(".spool/changes");
+    assert!(!changes.join("000-01_test-change").exists());
+    assert!(changes.join("000-02_invalid-change").exists());
+    assert!(changes.join("000-03_conflicting-change").exists());
+    assert!(changes.join("000-04_unfinished-change").exists());
+}
+
+#[test]
+fn coverage_check_and_archive_gate_use_the_configured_target() {
+    let base = make_base_repo();
+    let repo = tempfile::tempdir().expect("work");
+    let home = tempfile::tempdir().expect("home");
+    let rust_path = assert_cmd::cargo::cargo_bin!("spool");
+
+    reset_repo(repo.path(), base.path());
+    write(
+        repo.path()
+            .join(".spool/changes/000-01_test-change/tasks.md"),
+        "## Wave 1\n\n### Task 1.1: Do a thing\n\n- **Files**: `src/a.rs, docs/a.md`\n- **Dependencies**: None\n- **Action**:\n  Do it\n- **Updated At**: 2026-01-01\n- **Status**: [x] complete\n",
+    );
+    write(
+        repo.path().join("spool.json"),
+        "{\"defaults\":{\"testing\":{\"coverage\":{\"target_percent\":75}}}}",
+    );
+    write(
+        repo.path().join("coverage/lcov.info"),
+        "SF:src/a.rs\nDA:1,1\nDA:2,0\nend_of_record\nSF:src/b.rs\nDA:1,1\nDA:2,1\nDA:3,1\nDA:4,1\nend_of_record\n",
+    );
+
+    let out = run_rust_candidate(
+        rust_path,
+        &["coverage", "check", "000-01_test-change"],
+        repo.path(),
+        home.path(),
+    );
+    assert_ne!(out.code, 0);
+    assert!(
+        out.stdout
+            .contains("Coverage report: coverage/lcov.info (LCOV)")
+    );
+    assert!(out.stdout.contains("Overall: 83.3% (5/6 lines)"));
+    assert!(out.stdout.contains("Change files: 50.0% (1/2 lines)"));
+    assert!(out.stdout.contains("docs/a.md  no coverage data"));
+    assert!(
+        out.stderr
+            .contains("✗ Line coverage of the change's files 50.0% is below the 75% target"),
+        "stderr={}",
+        out.stderr
+    );
+
+    let out = run_rust_candidate(
+        rust_path,
+        &["archive", "000-01_test-change", "-y", "--require-coverage"],
+        repo.path(),
+        home.path(),
+    );
+    assert_ne!(out.code, 0);
+    assert!(
+        out.stderr.contains("Coverage gate failed"),
+        "stderr={}",
+        out.stderr
+    );
+    assert!(
+        repo.path()
+            .join(".spool/changes/000-01_test-change")
+            .exists()
+    );
+
+    write(
+        repo.path().join("coverage/lcov.info"),
+        "SF:src/a.rs\nDA:1,1\nDA:2,3\nend_of_record\n",
+    );
+    let out = run_rust_candidate(
+        rust_path,
+        &["coverage", "check", "000-01_test-change", "--json"],
+        repo.path(),
+        home.path(),
+    );
+    assert_eq!(out.code, 0, "stderr={}", out.stderr);
+    let v: serde_json::Value = serde_json::from_str(&out.stdout).expect("coverage json");
+    assert_eq!(v["passed"], true);
+    assert_eq!(v["target_percent"], 75);
+    assert_eq!(v["change"]["percent"], 100.0);
+
+    let out = run_rust_candidate(
+        rust_path,
+        &["archive", "000-01_test-change", "-y", "--require-coverage"],
+        repo.path(),
+        home.path(),
+    );
+    assert_eq!(out.code, 0, "stderr={}", out.stderr);
+    assert!(out.stderr.contains("✔ Coverage meets the 75% target"));
+}
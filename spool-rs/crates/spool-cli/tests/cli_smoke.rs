@@ -316,3 +316,120 @@ fn create_workflow_plan_state_config_smoke() {
     );
     assert_eq!(out.code, 0);
 }
+
+#[test]
+fn config_scopes_write_project_files_and_explain_reports_the_source() {
+    let repo = tempfile::tempdir().expect("work");
+    let home = tempfile::tempdir().expect("home");
+    let rust_path = assert_cmd::cargo::cargo_bin!("spool");
+    std::fs::create_dir_all(repo.path().join(".spool")).unwrap();
+    let key = "defaults.testing.coverage.target_percent";
+
+    for (scope, value) in [("repo", "70"), ("spool-dir", "90")] {
+        let out = run_rust_candidate(
+            rust_path,
+            &["config", "set", key, value, "--scope", scope],
+            repo.path(),
+            home.path(),
+        );
+        assert_eq!(out.code, 0, "stderr={}", out.stderr);
+    }
+    let repo_json = std::fs::read_to_string(repo.path().join("spool.json")).unwrap();
+    assert!(repo_json.contains("\"target_percent\": 70"));
+
+    let out = run_rust_candidate(
+        rust_path,
+        &["config", "get", key, "--scope", "repo"],
+        repo.path(),
+        home.path(),
+    );
+    assert_eq!(out.stdout.trim(), "70");
+
+    let out = run_rust_candidate(
+        rust_path,
+        &["config", "path", "--scope", "project"],
+        repo.path(),
+        home.path(),
+    );
+    assert!(out.stdout.trim().ends_with(".spool.json"));
+
+    let out = run_rust_candidate(
+        rust_path,
+        &["config", "explain", key],
+        repo.path(),
+        home.path(),
+    );
+    assert_eq!(out.code, 0, "stderr={}", out.stderr);
+    assert_eq!(
+        out.stdout,
+        format!(
+            "{key} = 90\nSource: .spool/config.json\nOverrides:\n  spool.json: 70\n  built-in default: 80\n"
+        )
+    );
+
+    let out = run_rust_candidate(
+        rust_path,
+        &["config", "explain", "nope"],
+        repo.path(),
+        home.path(),
+    );
+    assert_ne!(out.code, 0);
+    assert!(out.stderr.contains("Key 'nope' is not set"));
+}
+
+#[test]
+fn config_check_reports_unknown_keys_and_type_mismatches() {
+    let repo = tempfile::tempdir().expect("work");
+    let home = tempfile::tempdir().expect("home");
+    let rust_path = assert_cmd::cargo::cargo_bin!("spool");
+    std::fs::create_dir_all(repo.path().join(".spool")).unwrap();
+    std::fs::write(
+        repo.path().join("spool.json"),
+        "{\"projectPath\": \".spool\"}",
+    )
+    .unwrap();
+    std::fs::write(
+        repo.path().join(".spool/config.json"),
+        "{\"cache\": {\"ttlHours\": 12}, \"defaults\": {\"testing\": {\"coverage\": {\"target_percent\": \"90\"}}}}",
+    )
+    .unwrap();
+
+    let out = run_rust_candidate(rust_path, &["config", "check"], repo.path(), home.path());
+    assert_eq!(out.code, 0, "stderr={}", out.stderr);
+    assert_eq!(
+        out.stdout,
+        "✓ spool.json\n\
+         ✗ .spool/config.json\n\
+         \x20 [WARNING] /cache/ttlHours: unknown key 'ttlHours' (did you mean 'ttl_hours'?)\n\
+         \x20 [WARNING] /defaults/testing/coverage/target_percent: expected integer, found string\n\
+         Checked 2 config files: 2 issues\n"
+    );
+
+    let out = run_rust_candidate(
+        rust_path,
+        &["config", "check", "--strict", "--json"],
+        repo.path(),
+        home.path(),
+    );
+    assert_ne!(out.code, 0);
+    let v: serde_json::Value = serde_json::from_str(&out.stdout).expect("json");
+    assert_eq!(v["valid"], false);
+    assert_eq!(v["files"][1]["path"], ".spool/config.json");
+    assert_eq!(v["files"][1]["issues"][0]["level"], "ERROR");
+    assert_eq!(v["files"][1]["issues"][0]["pointer"], "/cache/ttlHours");
+
+    // Loading the config warns about the same problems.
+    let out = run_rust_candidate(
+        rust_path,
+        &["config", "explain", "cache.ttl_hours"],
+        repo.path(),
+        home.path(),
+    );
+    assert_eq!(out.code, 0, "stderr={}", out.stderr);
+    assert!(
+        out.stderr
+            .contains("config.json: /cache/ttlHours: unknown key 'ttlHours'"),
+        "stderr={}",
+        out.stderr
+    );
+}
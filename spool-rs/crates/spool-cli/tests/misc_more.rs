@@ -256,3 +256,162 @@ fn archive_prompts_on_incomplete_tasks_and_proceeds_when_confirmed() {
     let archive_root = repo.path().join(".spool/changes/archive");
     assert!(archive_root.exists());
 }
+
+#[test]
+fn status_uses_project_schemas_from_the_spool_dir() {
+    let base = fixtures::make_repo_all_valid();
+    let repo = tempfile::tempdir().expect("work");
+    let home = tempfile::tempdir().expect("home");
+    let rust_path = assert_cmd::cargo::cargo_bin!("spool");
+
+    fixtures::reset_repo(repo.path(), base.path());
+    fixtures::write(
+        repo.path().join(".spool/schemas/team/schema.yaml"),
+        "name: team\nartifacts:\n  - id: proposal\n    generates: proposal.md\n    template: proposal.md\n  - id: runbook\n    generates: runbook.md\n    template: runbook.md\n    requires: [proposal]\n",
+    );
+
+    let out = run_rust_candidate(
+        rust_path,
+        &[
+            "status",
+            "--change",
+            "000-01_test-change",
+            "--schema",
+            "team",
+            "--json",
+        ],
+        repo.path(),
+        home.path(),
+    );
+    assert_eq!(out.code, 0, "stderr={}", out.stderr);
+    assert!(out.stdout.contains("\"runbook\""));
+
+    let out = run_rust_candidate(
+        rust_path,
+        &[
+            "status",
+            "--change",
+            "000-01_test-change",
+            "--schema",
+            "missing",
+        ],
+        repo.path(),
+        home.path(),
+    );
+    assert_ne!(out.code, 0);
+    assert!(out.stderr.contains("  team"));
+}
+
+#[test]
+fn extended_schemas_report_artifact_layers() {
+    let base = fixtures::make_repo_all_valid();
+    let repo = tempfile::tempdir().expect("work");
+    let home = tempfile::tempdir().expect("home");
+    let rust_path = assert_cmd::cargo::cargo_bin!("spool");
+
+    fixtures::reset_repo(repo.path(), base.path());
+    fixtures::write(
+        repo.path().join(".spool/schemas/team/schema.yaml"),
+        "name: team\nextends: spec-driven\nremove: [design]\nartifacts:\n  - id: tasks\n    instruction: Keep tasks small.\n    requires: [specs]\n",
+    );
+
+    let out = run_rust_candidate(
+        rust_path,
+        &["workflow", "show", "team"],
+        repo.path(),
+        home.path(),
+    );
+    assert_eq!(out.code, 0, "stderr={}", out.stderr);
+    assert!(out.stdout.contains("# Schema: team"));
+    assert!(out.stdout.contains("Extends: spec-driven"));
+    assert!(out.stdout.contains("  - proposal [spec-driven]"));
+    assert!(out.stdout.contains("  - tasks [spec-driven > team]"));
+    assert!(!out.stdout.contains("  - design"));
+
+    let out = run_rust_candidate(
+        rust_path,
+        &[
+            "agent",
+            "instruction",
+            "tasks",
+            "--change",
+            "000-01_test-change",
+            "--schema",
+            "team",
+        ],
+        repo.path(),
+        home.path(),
+    );
+    assert_eq!(out.code, 0, "stderr={}", out.stderr);
+    assert!(out.stdout.contains("layers=\"spec-driven > team\""));
+    assert!(out.stdout.contains("Keep tasks small."));
+}
+
+#[test]
+fn schema_new_copies_a_schema_and_preview_renders_it() {
+    let base = fixtures::make_repo_all_valid();
+    let repo = tempfile::tempdir().expect("work");
+    let home = tempfile::tempdir().expect("home");
+    let rust_path = assert_cmd::cargo::cargo_bin!("spool");
+
+    fixtures::reset_repo(repo.path(), base.path());
+
+    let out = run_rust_candidate(
+        rust_path,
+        &["schema", "new", "team"],
+        repo.path(),
+        home.path(),
+    );
+    assert_eq!(out.code, 0, "stderr={}", out.stderr);
+    let team = repo.path().join(".spool/schemas/team");
+    let yaml = std::fs::read_to_string(team.join("schema.yaml")).expect("schema.yaml");
+    assert!(yaml.starts_with("name: team\n"));
+    assert!(team.join("templates/proposal.md").is_file());
+    assert!(team.join("templates/tasks.md").is_file());
+
+    let out = run_rust_candidate(
+        rust_path,
+        &["schema", "new", "team"],
+        repo.path(),
+        home.path(),
+    );
+    assert_ne!(out.code, 0);
+    assert!(out.stderr.contains("Schema directory already exists"));
+
+    fixtures::write(
+        team.join("templates/tasks.md"),
+        "# Tasks for {{ change_name }} ({{ artifact_id }} -> {{ output_path }})\n",
+    );
+    let out = run_rust_candidate(
+        rust_path,
+        &["schema", "preview", "team"],
+        repo.path(),
+        home.path(),
+    );
+    assert_eq!(out.code, 0, "stderr={}", out.stderr);
+    assert!(out.stdout.contains("# Schema: team (project)"));
+    assert!(out.stdout.contains("tasks.md <- specs, design"));
+    assert!(
+        out.stdout
+            .contains("# Tasks for 000-01_example-change (tasks -> tasks.md)")
+    );
+
+    // Layered sources are written out flattened.
+    fixtures::write(
+        repo.path().join(".spool/schemas/lean/schema.yaml"),
+        "name: lean\nextends: spec-driven\nremove: [design]\nartifacts:\n  - id: tasks\n    requires: [specs]\n",
+    );
+    let out = run_rust_candidate(
+        rust_path,
+        &["schema", "new", "lean-copy", "--from", "lean", "--user"],
+        repo.path(),
+        home.path(),
+    );
+    assert_eq!(out.code, 0, "stderr={}", out.stderr);
+    let copy = home.path().join("spool/schemas/lean-copy");
+    let yaml = std::fs::read_to_string(copy.join("schema.yaml")).expect("schema.yaml");
+    assert!(yaml.starts_with("name: lean-copy\n"));
+    assert!(!yaml.contains("extends:"));
+    assert!(!yaml.contains("id: design"));
+    assert!(!copy.join("templates/design.md").exists());
+}
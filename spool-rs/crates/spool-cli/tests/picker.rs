@@ -0,0 +1,126 @@
+#[path = "support/mod.rs"]
+mod fixtures;
+
+use spool_test_support::run_rust_candidate;
+
+// PTY-based interactive tests are skipped on Windows due to platform differences
+// in terminal handling that can cause hangs.
+#[cfg(unix)]
+use spool_test_support::pty::run_pty_interactive;
+
+fn repo_with_two_changes() -> tempfile::TempDir {
+    let td = fixtures::make_repo_all_valid();
+    fixtures::write(
+        td.path()
+            .join(".spool/changes/000-02_other-change/proposal.md"),
+        "## Why\nSecond fixture\n\n## What Changes\n- Nothing\n\n## Impact\n- None\n",
+    );
+    fixtures::write(
+        td.path()
+            .join(".spool/changes/000-02_other-change/tasks.md"),
+        "## 1. Implementation\n- [ ] 1.1 Pending thing\n- [ ] 1.2 Another thing\n",
+    );
+    td
+}
+
+#[test]
+#[cfg(unix)]
+fn tasks_status_prompts_for_a_change_by_number() {
+    let base = repo_with_two_changes();
+    let repo = tempfile::tempdir().expect("work");
+    let home = tempfile::tempdir().expect("home");
+    let rust_path = assert_cmd::cargo::cargo_bin!("spool");
+    fixtures::reset_repo(repo.path(), base.path());
+
+    let out = run_pty_interactive(
+        rust_path,
+        &["tasks", "status"],
+        repo.path(),
+        home.path(),
+        "2\n",
+    );
+    assert_eq!(out.code, 0, "stdout={}", out.stdout);
+    assert!(out.stdout.contains("1. 000-01_test-change  (1/1 tasks)"));
+    assert!(out.stdout.contains("2. 000-02_other-change  (0/2 tasks)"));
+    assert!(out.stdout.contains("Tasks for: 000-02_other-change"));
+}
+
+#[test]
+#[cfg(unix)]
+fn status_and_show_narrow_the_list_with_filter_text() {
+    let base = repo_with_two_changes();
+    let repo = tempfile::tempdir().expect("work");
+    let home = tempfile::tempdir().expect("home");
+    let rust_path = assert_cmd::cargo::cargo_bin!("spool");
+    fixtures::reset_repo(repo.path(), base.path());
+
+    // "000" matches both changes, so the picker asks again; "other" settles it.
+    let out = run_pty_interactive(
+        rust_path,
+        &["status", "--json"],
+        repo.path(),
+        home.path(),
+        "000\nother\n",
+    );
+    assert_eq!(out.code, 0, "stdout={}", out.stdout);
+    assert!(
+        out.stdout
+            .contains("\"changeName\": \"000-02_other-change\"")
+    );
+
+    let out = run_pty_interactive(rust_path, &["show"], repo.path(), home.path(), "tstchg\n");
+    assert_eq!(out.code, 0, "stdout={}", out.stdout);
+    assert!(out.stdout.contains("Test fixture"));
+
+    // An empty answer cancels.
+    let out = run_pty_interactive(rust_path, &["show"], repo.path(), home.path(), "\n");
+    assert_ne!(out.code, 0);
+    assert!(out.stdout.contains("No change selected"));
+}
+
+#[test]
+#[cfg(unix)]
+fn archive_prompts_for_a_change() {
+    let base = repo_with_two_changes();
+    let repo = tempfile::tempdir().expect("work");
+    let home = tempfile::tempdir().expect("home");
+    let rust_path = assert_cmd::cargo::cargo_bin!("spool");
+    fixtures::reset_repo(repo.path(), base.path());
+
+    let out = run_pty_interactive(
+        rust_path,
+        &["archive", "--skip-specs", "--yes"],
+        repo.path(),
+        home.path(),
+        "1\n",
+    );
+    assert_eq!(out.code, 0, "stdout={}", out.stdout);
+    assert!(
+        !repo
+            .path()
+            .join(".spool/changes/000-01_test-change")
+            .exists()
+    );
+    assert!(
+        repo.path()
+            .join(".spool/changes/000-02_other-change")
+            .exists()
+    );
+}
+
+#[test]
+fn commands_without_a_change_fail_when_not_interactive() {
+    let base = repo_with_two_changes();
+    let repo = tempfile::tempdir().expect("work");
+    let home = tempfile::tempdir().expect("home");
+    let rust_path = assert_cmd::cargo::cargo_bin!("spool");
+    fixtures::reset_repo(repo.path(), base.path());
+
+    let out = run_rust_candidate(rust_path, &["tasks", "status"], repo.path(), home.path());
+    assert_ne!(out.code, 0);
+    assert!(out.stderr.contains("Missing required argument <change-id>"));
+
+    let out = run_rust_candidate(rust_path, &["status"], repo.path(), home.path());
+    assert_ne!(out.code, 0);
+    assert!(out.stderr.contains("Missing required option --change"));
+}
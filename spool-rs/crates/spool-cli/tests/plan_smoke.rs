@@ -0,0 +1,105 @@
+use std::path::Path;
+
+use spool_test_support::run_rust_candidate;
+
+fn write(path: impl AsRef<Path>, contents: &str) {
+    let path = path.as_ref();
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).unwrap();
+    }
+    std::fs::write(path, contents).unwrap();
+}
+
+fn run_ok(repo: &Path, home: &Path, args: &[&str]) -> String {
+    let rust_path = assert_cmd::cargo::cargo_bin!("spool");
+    let out = run_rust_candidate(rust_path, args, repo, home);
+    assert_eq!(out.code, 0, "args={args:?} stderr={}", out.stderr);
+    out.stdout
+}
+
+#[test]
+fn plan_phase_status_follows_linked_change_tasks() {
+    let repo = tempfile::tempdir().expect("repo");
+    let home = tempfile::tempdir().expect("home");
+    std::fs::create_dir_all(repo.path().join(".spool")).unwrap();
+    write(
+        repo.path().join(".spool/changes/001-01_auth/tasks.md"),
+        "# Tasks\n\n- [x] 1.1 Add login\n- [ ] 1.2 Add logout\n",
+    );
+
+    run_ok(repo.path(), home.path(), &["plan", "init"]);
+    run_ok(
+        repo.path(),
+        home.path(),
+        &["plan", "phase", "add", "Auth", "--change", "001-01_auth"],
+    );
+    run_ok(repo.path(), home.path(), &["plan", "phase", "add", "Docs"]);
+    run_ok(
+        repo.path(),
+        home.path(),
+        &["plan", "phase", "set-status", "2", "complete"],
+    );
+
+    let roadmap = std::fs::read_to_string(repo.path().join(".spool/planning/ROADMAP.md")).unwrap();
+    assert!(roadmap.contains("| 1 | Auth | In Progress | 001-01_auth |"));
+    assert!(roadmap.contains("| 2 | Docs | Complete | - |"));
+    assert!(roadmap.contains("- Status: In Progress\n- Phase: 1 of 2"));
+    assert!(!roadmap.contains("[Phase Name]"));
+
+    let rust_path = assert_cmd::cargo::cargo_bin!("spool");
+    let out = run_rust_candidate(
+        rust_path,
+        &["plan", "phase", "set-status", "1", "complete"],
+        repo.path(),
+        home.path(),
+    );
+    assert_ne!(out.code, 0);
+    assert!(out.stderr.contains("linked to changes"));
+
+    write(
+        repo.path().join(".spool/changes/001-01_auth/tasks.md"),
+        "# Tasks\n\n- [x] 1.1 Add login\n- [x] 1.2 Add logout\n",
+    );
+    let stdout = run_ok(repo.path(), home.path(), &["plan", "status"]);
+    assert!(stdout.contains("Status: Complete"));
+    assert!(stdout.contains("✓ Phase 1: Auth [Complete]"));
+    assert!(stdout.contains("001-01_auth (2/2 tasks)"));
+}
+
+#[test]
+fn plan_milestone_add_and_complete() {
+    let repo = tempfile::tempdir().expect("repo");
+    let home = tempfile::tempdir().expect("home");
+    std::fs::create_dir_all(repo.path().join(".spool")).unwrap();
+
+    run_ok(repo.path(), home.path(), &["plan", "init"]);
+    run_ok(
+        repo.path(),
+        home.path(),
+        &[
+            "plan",
+            "milestone",
+            "add",
+            "v2-scale",
+            "--target",
+            "Handle load",
+        ],
+    );
+    run_ok(repo.path(), home.path(), &["plan", "milestone", "complete"]);
+
+    let roadmap = std::fs::read_to_string(repo.path().join(".spool/planning/ROADMAP.md")).unwrap();
+    assert!(roadmap.contains("## Current Milestone: v2-scale"));
+    assert!(roadmap.contains("### v2-scale\nTarget: Handle load"));
+    assert!(!roadmap.contains("### v1-core"));
+    assert!(roadmap.contains("## Completed Milestones\n- v1-core (completed "));
+
+    let rust_path = assert_cmd::cargo::cargo_bin!("spool");
+    let out = run_rust_candidate(
+        rust_path,
+        &["plan", "milestone", "complete", "nope"],
+        repo.path(),
+        home.path(),
+    );
+    assert_ne!(out.code, 0);
+    assert!(out.stderr.contains("Milestone 'nope' not found"));
+}
@@ -111,4 +111,465 @@ fn ralph_stub_harness_writes_state_and_status_works() {
     assert_eq!(out.code, 0, "stderr={}", out.stderr);
     assert!(out.stdout.contains("Iteration:"));
     assert!(out.stdout.contains("History entries:"));
+
+    // Replay the recorded transcript of iteration 1.
+    let out = run_rust_candidate(
+        rust_path,
+        &[
+            "ralph",
+            "--change",
+            "000-01_test-change",
+            "--log",
+            "1",
+            "--no-interactive",
+        ],
+        repo.path(),
+        home.path(),
+    );
+    assert_eq!(out.code, 0, "stderr={}", out.stderr);
+    assert!(
+        out.stdout
+            .contains("=== Ralph Iteration 1 for 000-01_test-change ===")
+    );
+    assert!(out.stdout.contains("--- Prompt ---"));
+    assert!(out.stdout.contains("do work"));
+    assert!(out.stdout.contains("<promise>COMPLETE</promise>"));
+
+    let out = run_rust_candidate(
+        rust_path,
+        &[
+            "ralph",
+            "--change",
+            "000-01_test-change",
+            "--log",
+            "7",
+            "--no-interactive",
+        ],
+        repo.path(),
+        home.path(),
+    );
+    assert_ne!(out.code, 0);
+    assert!(
+        out.stderr
+            .contains("No transcript recorded for iteration 7")
+    );
+
+    // Completion gates: tasks are all done, but the verify command fails every time.
+    let out = run_rust_candidate(
+        rust_path,
+        &[
+            "ralph",
+            "--change",
+            "000-01_test-change",
+            "--harness",
+            "stub",
+            "--no-commit",
+            "--no-interactive",
+            "--max-iterations",
+            "2",
+            "--require-tasks",
+            "--verify",
+            "echo not yet; exit 1",
+        ],
+        repo.path(),
+        home.path(),
+    );
+    assert_eq!(out.code, 0, "stderr={}", out.stderr);
+    assert!(out.stdout.contains("=== Completion gates failed ==="));
+    assert!(out.stdout.contains("not yet"));
+    assert!(!out.stdout.contains("Loop complete"));
+}
+
+fn git(repo: &Path, args: &[&str]) -> String {
+    let out = std::process::Command::new("git")
+        .args(args)
+        .current_dir(repo)
+        .output()
+        .unwrap();
+    assert!(
+        out.status.success(),
+        "git {args:?} failed: {}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    String::from_utf8_lossy(&out.stdout).trim().to_string()
+}
+
+#[test]
+fn ralph_worktree_runs_on_a_branch_and_can_be_merged_or_discarded() {
+    let base = make_base_repo();
+    let repo = tempfile::tempdir().expect("work");
+    let home = tempfile::tempdir().expect("home");
+    let rust_path = assert_cmd::cargo::cargo_bin!("spool");
+
+    reset_repo(repo.path(), base.path());
+    git(repo.path(), &["init", "-q"]);
+    git(repo.path(), &["config", "user.name", "Test"]);
+    git(repo.path(), &["config", "user.email", "test@example.com"]);
+    git(repo.path(), &["add", "-A"]);
+    git(repo.path(), &["commit", "-q", "-m", "initial"]);
+
+    let run_args = [
+        "ralph",
+        "--change",
+        "000-01_test-change",
+        "--harness",
+        "stub",
+        "--worktree",
+        "--no-interactive",
+        "--max-iterations",
+        "1",
+        "do",
+        "work",
+    ];
+    let out = run_rust_candidate(rust_path, &run_args, repo.path(), home.path());
+    assert_eq!(out.code, 0, "stderr={}", out.stderr);
+    assert!(out.stdout.contains("Created worktree"));
+    assert!(out.stdout.contains("spool/ralph/000-01_test-change"));
+    assert!(out.stdout.contains("--merge"));
+
+    let common = repo.path().join(".git/spool-worktrees/000-01_test-change");
+    assert!(common.join(".spool/changes/000-01_test-change").exists());
+
+    // Simulate the agent's work on the Ralph branch.
+    std::fs::write(common.join("feature.txt"), "done\n").unwrap();
+    git(&common, &["add", "-A"]);
+    git(&common, &["commit", "-q", "-m", "feature"]);
+
+    let out = run_rust_candidate(rust_path, &run_args, repo.path(), home.path());
+    assert_eq!(out.code, 0, "stderr={}", out.stderr);
+    assert!(out.stdout.contains("Reusing worktree"));
+
+    let out = run_rust_candidate(
+        rust_path,
+        &["ralph", "--change", "000-01_test-change", "--merge"],
+        repo.path(),
+        home.path(),
+    );
+    assert_eq!(out.code, 0, "stderr={}", out.stderr);
+    assert!(repo.path().join("feature.txt").exists());
+    assert!(!common.exists());
+
+    // A second loop can be thrown away without touching the main checkout.
+    let out = run_rust_candidate(rust_path, &run_args, repo.path(), home.path());
+    assert_eq!(out.code, 0, "stderr={}", out.stderr);
+    std::fs::write(common.join("scratch.txt"), "wip\n").unwrap();
+    git(&common, &["add", "-A"]);
+    git(&common, &["commit", "-q", "-m", "wip"]);
+
+    let out = run_rust_candidate(
+        rust_path,
+        &["ralph", "--change", "000-01_test-change", "--discard"],
+        repo.path(),
+        home.path(),
+    );
+    assert_eq!(out.code, 0, "stderr={}", out.stderr);
+    assert!(!repo.path().join("scratch.txt").exists());
+    assert!(!common.exists());
+    assert!(
+        git(
+            repo.path(),
+            &["branch", "--list", "spool/ralph/000-01_test-change"]
+        )
+        .is_empty()
+    );
+
+    let out = run_rust_candidate(
+        rust_path,
+        &[
+            "ralph",
+            "--change",
+            "000-01_test-change",
+            "--merge",
+            "--discard",
+        ],
+        repo.path(),
+        home.path(),
+    );
+    assert_ne!(out.code, 0);
+}
+
+#[test]
+fn ralph_parallel_runs_each_ready_change_in_its_own_worktree() {
+    let base = make_base_repo();
+    let repo = tempfile::tempdir().expect("work");
+    let home = tempfile::tempdir().expect("home");
+    let rust_path = assert_cmd::cargo::cargo_bin!("spool");
+
+    reset_repo(repo.path(), base.path());
+    for change in ["000-02_first", "000-03_second"] {
+        let dir = repo.path().join(".spool/changes").join(change);
+        write(
+            dir.join("proposal.md"),
+            "## Why\nTest fixture\n\n## What Changes\n- Adds a small delta\n\n## Impact\n- None\n",
+        );
+        write(
+            dir.join("specs/alpha/spec.md"),
+            "## ADDED Requirements\n\n### Requirement: Alpha\nThe system SHALL do alpha.\n\n#### Scenario: Works\n- **WHEN** used\n- **THEN** it works\n",
+        );
+        write(
+            dir.join("tasks.md"),
+            "## 1. Implementation\n- [ ] 1.1 Do it\n",
+        );
+    }
+    git(repo.path(), &["init", "-q"]);
+    git(repo.path(), &["config", "user.name", "Test"]);
+    git(repo.path(), &["config", "user.email", "test@example.com"]);
+    git(repo.path(), &["add", "-A"]);
+    git(repo.path(), &["commit", "-q", "-m", "initial"]);
+
+    let out = run_rust_candidate(
+        rust_path,
+        &[
+            "ralph",
+            "--module",
+            "000",
+            "--parallel",
+            "2",
+            "--harness",
+            "stub",
+            "--max-iterations",
+            "1",
+            "--no-commit",
+            "do",
+            "work",
+        ],
+        repo.path(),
+        home.path(),
+    );
+    assert_eq!(out.code, 0, "stdout={} stderr={}", out.stdout, out.stderr);
+    assert!(
+        out.stdout
+            .contains("[000-02_first] === Starting Ralph for 000-02_first")
+    );
+    assert!(
+        out.stdout
+            .contains("[000-03_second] === Starting Ralph for 000-03_second")
+    );
+    assert!(out.stdout.contains("=== Ralph Summary for module 000 ==="));
+    assert!(out.stdout.contains("2/2 complete, 0 failed"));
+    // 000-01 has no specs or pending tasks, so it is not ready.
+    assert!(!out.stdout.contains("[000-01_test-change]"));
+
+    let raw =
+        std::fs::read_to_string(repo.path().join(".spool/.state/ralph/parallel-000.json")).unwrap();
+    let summary: serde_json::Value = serde_json::from_str(&raw).unwrap();
+    let loops = summary["loops"].as_array().unwrap();
+    assert_eq!(loops.len(), 2);
+    assert_eq!(loops[0]["changeId"], "000-02_first");
+    assert_eq!(loops[0]["outcome"], "complete");
+    assert_eq!(loops[0]["iterations"], 1);
+    assert_eq!(loops[1]["branch"], "spool/ralph/000-03_second");
+
+    for change in ["000-02_first", "000-03_second"] {
+        assert!(
+            repo.path()
+                .join(".git/spool-worktrees")
+                .join(change)
+                .exists()
+        );
+    }
+
+    // The stub prints the completion promise every time, but a failing gate holds it back
+    // until the loops run out of iterations and exit cleanly.
+    let out = run_rust_candidate(
+        rust_path,
+        &[
+            "ralph",
+            "--module",
+            "000",
+            "--parallel",
+            "2",
+            "--harness",
+            "stub",
+            "--max-iterations",
+            "2",
+            "--no-commit",
+            "--verify",
+            "exit 1",
+            "do",
+            "work",
+        ],
+        repo.path(),
+        home.path(),
+    );
+    assert_eq!(out.code, 0, "stdout={} stderr={}", out.stdout, out.stderr);
+    assert!(
+        out.stdout.contains("0/2 complete, 0 failed"),
+        "{}",
+        out.stdout
+    );
+    let raw =
+        std::fs::read_to_string(repo.path().join(".spool/.state/ralph/parallel-000.json")).unwrap();
+    let summary: serde_json::Value = serde_json::from_str(&raw).unwrap();
+    assert_eq!(summary["loops"][0]["outcome"], "incomplete");
+    assert_eq!(summary["loops"][0]["iterations"], 2);
+
+    let out = run_rust_candidate(
+        rust_path,
+        &["ralph", "--change", "000-02_first", "--parallel", "2"],
+        repo.path(),
+        home.path(),
+    );
+    assert_ne!(out.code, 0);
+}
+
+#[test]
+fn ralph_retries_failed_attempts_and_gives_up_after_max_failures() {
+    let base = make_base_repo();
+    let repo = tempfile::tempdir().expect("work");
+    let home = tempfile::tempdir().expect("home");
+    let rust_path = assert_cmd::cargo::cargo_bin!("spool");
+
+    reset_repo(repo.path(), base.path());
+    let script = repo.path().join("stub.json");
+    write(
+        &script,
+        r#"[{"stdout": "", "stderr": "compile error\n", "exitCode": 2}, {"stdout": "<promise>COMPLETE</promise>\n"}]"#,
+    );
+    let script = script.to_string_lossy().to_string();
+
+    let out = run_rust_candidate(
+        rust_path,
+        &[
+            "ralph",
+            "--change",
+            "000-01_test-change",
+            "--harness",
+            "stub",
+            "--stub-script",
+            &script,
+            "--no-commit",
+            "--no-interactive",
+            "--retry-backoff",
+            "0s",
+        ],
+        repo.path(),
+        home.path(),
+    );
+    assert_eq!(out.code, 0, "stderr={}", out.stderr);
+    assert!(
+        out.stdout
+            .contains("Iteration 1 failed (exit code 2); retrying")
+    );
+    assert!(out.stdout.contains("Loop complete"));
+
+    write(
+        repo.path().join("stub.json"),
+        r#"[{"stdout": "", "stderr": "still broken\n", "exitCode": 2}]"#,
+    );
+    let out = run_rust_candidate(
+        rust_path,
+        &[
+            "ralph",
+            "--change",
+            "000-01_test-change",
+            "--harness",
+            "stub",
+            "--stub-script",
+            &script,
+            "--no-commit",
+            "--no-interactive",
+            "--max-failures",
+            "2",
+            "--retry-backoff",
+            "0s",
+        ],
+        repo.path(),
+        home.path(),
+    );
+    assert_ne!(out.code, 0);
+    assert!(out.stderr.contains("2 consecutive failure(s)"));
+}
+
+#[test]
+fn ralph_max_cost_stops_on_harness_reported_cost() {
+    let base = make_base_repo();
+    let repo = tempfile::tempdir().expect("work");
+    let home = tempfile::tempdir().expect("home");
+    let rust_path = assert_cmd::cargo::cargo_bin!("spool");
+
+    reset_repo(repo.path(), base.path());
+    let script = repo.path().join("stub.json");
+    write(
+        &script,
+        r#"[{"stdout": "{\"type\":\"result\",\"usage\":{\"input_tokens\":10,\"output_tokens\":5},\"total_cost_usd\":0.75}\n"}]"#,
+    );
+    let script = script.to_string_lossy().to_string();
+
+    let out = run_rust_candidate(
+        rust_path,
+        &[
+            "ralph",
+            "--change",
+            "000-01_test-change",
+            "--harness",
+            "stub",
+            "--stub-script",
+            &script,
+            "--no-commit",
+            "--no-interactive",
+            "--max-iterations",
+            "5",
+            "--max-cost",
+            "1",
+        ],
+        repo.path(),
+        home.path(),
+    );
+    assert_eq!(out.code, 0, "stderr={}", out.stderr);
+    assert!(out.stdout.contains("Tokens: 10 in / 5 out"));
+    assert!(out.stdout.contains("Cost limit reached ($1.5000 of $1.00)"));
+
+    let out = run_rust_candidate(
+        rust_path,
+        &[
+            "ralph",
+            "--change",
+            "000-01_test-change",
+            "--status",
+            "--no-interactive",
+        ],
+        repo.path(),
+        home.path(),
+    );
+    assert_eq!(out.code, 0, "stderr={}", out.stderr);
+    assert!(out.stdout.contains("Iteration: 2"));
+    assert!(out.stdout.contains("Total cost: $1.5000"));
+}
+
+#[test]
+fn ralph_max_cost_warns_once_when_the_harness_reports_no_usage() {
+    let base = make_base_repo();
+    let repo = tempfile::tempdir().expect("work");
+    let home = tempfile::tempdir().expect("home");
+    let rust_path = assert_cmd::cargo::cargo_bin!("spool");
+
+    reset_repo(repo.path(), base.path());
+    let script = repo.path().join("stub.json");
+    write(&script, r#"[{"stdout": "working\n"}]"#);
+    let script = script.to_string_lossy().to_string();
+
+    let out = run_rust_candidate(
+        rust_path,
+        &[
+            "ralph",
+            "--change",
+            "000-01_test-change",
+            "--harness",
+            "stub",
+            "--stub-script",
+            &script,
+            "--no-commit",
+            "--no-interactive",
+            "--max-iterations",
+            "3",
+            "--max-cost",
+            "1",
+        ],
+        repo.path(),
+        home.path(),
+    );
+    assert_eq!(out.code, 0, "stderr={}", out.stderr);
+    let warning = "--max-cost is set but harness 'stub' reported no token usage";
+    assert_eq!(out.stderr.matches(warning).count(), 1, "{}", out.stderr);
 }
@@ -0,0 +1,100 @@
+use spool_test_support::run_rust_candidate;
+
+fn init_planning(repo: &std::path::Path, home: &std::path::Path) {
+    std::fs::create_dir_all(repo.join(".spool")).unwrap();
+    let rust_path = assert_cmd::cargo::cargo_bin!("spool");
+    let out = run_rust_candidate(rust_path, &["plan", "init"], repo, home);
+    assert_eq!(out.code, 0, "stderr={}", out.stderr);
+}
+
+#[test]
+fn state_list_resolve_and_answer() {
+    let repo = tempfile::tempdir().expect("repo");
+    let home = tempfile::tempdir().expect("home");
+    init_planning(repo.path(), home.path());
+    let rust_path = assert_cmd::cargo::cargo_bin!("spool");
+
+    for args in [
+        ["state", "blocker", "waiting on API spec"],
+        ["state", "blocker", "no staging access"],
+        ["state", "question", "which database?"],
+    ] {
+        let out = run_rust_candidate(rust_path, &args, repo.path(), home.path());
+        assert_eq!(out.code, 0, "stderr={}", out.stderr);
+    }
+
+    let out = run_rust_candidate(
+        rust_path,
+        &["state", "resolve", "blocker", "1"],
+        repo.path(),
+        home.path(),
+    );
+    assert_eq!(out.code, 0, "stderr={}", out.stderr);
+
+    let out = run_rust_candidate(
+        rust_path,
+        &["state", "answer", "question", "1", "postgres"],
+        repo.path(),
+        home.path(),
+    );
+    assert_eq!(out.code, 0, "stderr={}", out.stderr);
+
+    let out = run_rust_candidate(
+        rust_path,
+        &["state", "list", "--type", "blocker", "--open", "--json"],
+        repo.path(),
+        home.path(),
+    );
+    assert_eq!(out.code, 0, "stderr={}", out.stderr);
+    let v: serde_json::Value = serde_json::from_str(&out.stdout).expect("json");
+    let entries = v["entries"].as_array().unwrap();
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0]["kind"], "blocker");
+    assert_eq!(entries[0]["resolved"], false);
+
+    let out = run_rust_candidate(
+        rust_path,
+        &["state", "list", "--type", "question"],
+        repo.path(),
+        home.path(),
+    );
+    assert_eq!(out.code, 0, "stderr={}", out.stderr);
+    assert!(out.stdout.contains("[answered]: which database?"));
+    assert!(out.stdout.contains("→ postgres"));
+
+    let out = run_rust_candidate(
+        rust_path,
+        &["state", "resolve", "blocker", "9"],
+        repo.path(),
+        home.path(),
+    );
+    assert_ne!(out.code, 0);
+    assert!(out.stderr.contains("No blocker #9"));
+}
+
+#[test]
+fn state_prune_removes_old_sessions() {
+    let repo = tempfile::tempdir().expect("repo");
+    let home = tempfile::tempdir().expect("home");
+    init_planning(repo.path(), home.path());
+    let rust_path = assert_cmd::cargo::cargo_bin!("spool");
+
+    let state_path = repo.path().join(".spool/planning/STATE.md");
+    let contents = std::fs::read_to_string(&state_path).unwrap();
+    let contents = contents.replace(
+        "## Session Notes\n",
+        "## Session Notes\n### 2000-01-01 Session\n- 10:00:00: ancient\n\n",
+    );
+    std::fs::write(&state_path, contents).unwrap();
+
+    let out = run_rust_candidate(
+        rust_path,
+        &["state", "prune", "--older-than", "30"],
+        repo.path(),
+        home.path(),
+    );
+    assert_eq!(out.code, 0, "stderr={}", out.stderr);
+    let contents = std::fs::read_to_string(&state_path).unwrap();
+    assert!(!contents.contains("ancient"));
+    assert!(contents.contains("Initial Setup"));
+}
@@ -263,3 +263,239 @@ fn tasks_next_supports_checkbox_compat_mode_and_shows_current_or_next() {
     assert!(out.stdout.contains("Next Task (compat)"));
     assert!(out.stdout.contains("Task 2: second"));
 }
+
+#[test]
+fn tasks_phase_runs_verify_and_enforces_red_green_refactor() {
+    let base = fixtures::make_empty_repo();
+    let repo = tempfile::tempdir().expect("work");
+    let home = tempfile::tempdir().expect("home");
+    let rust_path = assert_cmd::cargo::cargo_bin!("spool");
+
+    fixtures::reset_repo(repo.path(), base.path());
+    let change_dir = repo.path().join(".spool/changes/test-change");
+    std::fs::create_dir_all(&change_dir).unwrap();
+    fixtures::write(
+        change_dir.join("tasks.md"),
+        "## Wave 1\n- **Depends On**: None\n\n### Task 1.1: Add parser\n- **Files**: `src/parser.rs`\n- **Verify**: `test -f ok`\n- **Updated At**: 2026-01-28\n- **Status**: [ ] in-progress\n",
+    );
+    let phase = |p: &str| {
+        run_rust_candidate(
+            rust_path,
+            &["tasks", "phase", "test-change", "1.1", p],
+            repo.path(),
+            home.path(),
+        )
+    };
+
+    // Phases must start at red.
+    let out = phase("green");
+    assert_ne!(out.code, 0);
+    assert!(
+        out.stderr.contains("green must follow red"),
+        "{}",
+        out.stderr
+    );
+
+    // Red needs the verify command to fail.
+    fixtures::write(repo.path().join("ok"), "");
+    let out = phase("red");
+    assert_ne!(out.code, 0);
+    assert!(out.stderr.contains("`test -f ok` passed"), "{}", out.stderr);
+
+    std::fs::remove_file(repo.path().join("ok")).unwrap();
+    let out = phase("red");
+    assert_eq!(out.code, 0, "{}", out.stderr);
+    assert!(out.stderr.contains("moved to red"));
+    let md = std::fs::read_to_string(change_dir.join("tasks.md")).unwrap();
+    assert!(md.contains("- **TDD Phase**: red\n"));
+
+    // Green needs it to pass.
+    let out = phase("green");
+    assert_ne!(out.code, 0);
+    assert!(out.stderr.contains("`test -f ok` failed"), "{}", out.stderr);
+
+    fixtures::write(repo.path().join("ok"), "");
+    assert_eq!(phase("green").code, 0);
+    assert_eq!(phase("refactor").code, 0);
+    let md = std::fs::read_to_string(change_dir.join("tasks.md")).unwrap();
+    assert!(md.contains("- **TDD Phase**: refactor\n"));
+    assert_eq!(md.matches("TDD Phase").count(), 1);
+
+    let out = phase("green");
+    assert_ne!(out.code, 0);
+    assert!(out.stderr.contains("cannot move from refactor to green"));
+}
+
+#[test]
+fn tasks_span_every_tracking_file_listed_by_the_schema() {
+    let base = fixtures::make_empty_repo();
+    let repo = tempfile::tempdir().expect("work");
+    let home = tempfile::tempdir().expect("home");
+    let rust_path = assert_cmd::cargo::cargo_bin!("spool");
+
+    fixtures::reset_repo(repo.path(), base.path());
+    fixtures::write(
+        repo.path().join(".spool/schemas/tdd-tracked/schema.yaml"),
+        "name: tdd-tracked\nartifacts:\n  - id: tests\n    generates: tests.md\n    template: tests.md\n  - id: implementation\n    generates: implementation.md\n    template: implementation.md\n    requires: [tests]\napply:\n  requires: [tests, implementation]\n  tracks: [tests.md, implementation.md]\n",
+    );
+    let run = |args: &[&str]| run_rust_candidate(rust_path, args, repo.path(), home.path());
+
+    let out = run(&["create", "change", "demo", "--schema", "tdd-tracked"]);
+    assert_eq!(out.code, 0, "{}", out.stderr);
+    let change_dir = repo.path().join(".spool/changes/000-01_demo");
+    // Tracking comes from the schema; nothing is snapshotted into the metadata.
+    let meta = std::fs::read_to_string(change_dir.join(".spool.yaml")).unwrap();
+    assert!(!meta.contains("tracks"), "{meta}");
+
+    assert_eq!(run(&["tasks", "init", "000-01_demo"]).code, 0);
+    assert!(change_dir.join("tests.md").exists());
+    assert!(change_dir.join("implementation.md").exists());
+    assert!(!change_dir.join("tasks.md").exists());
+
+    let out = run(&["tasks", "status", "000-01_demo"]);
+    assert_eq!(out.code, 0, "{}", out.stderr);
+    assert!(out.stdout.contains("File: tests.md"), "{}", out.stdout);
+    assert!(out.stdout.contains("File: implementation.md"));
+
+    // Both files start with a task 1.1, so bare ids are ambiguous.
+    let out = run(&["tasks", "start", "000-01_demo", "1.1"]);
+    assert_ne!(out.code, 0);
+    assert!(
+        out.stderr.contains("is in several tracking files"),
+        "{}",
+        out.stderr
+    );
+    let out = run(&["tasks", "start", "000-01_demo", "tests.md:1.1"]);
+    assert_eq!(out.code, 0, "{}", out.stderr);
+    let tests_md = std::fs::read_to_string(change_dir.join("tests.md")).unwrap();
+    assert!(tests_md.contains("- **Status**: [ ] in-progress"));
+
+    let out = run(&[
+        "tasks",
+        "add",
+        "000-01_demo",
+        "Wire it up",
+        "--file",
+        "implementation.md",
+    ]);
+    assert_eq!(out.code, 0, "{}", out.stderr);
+    let impl_md = std::fs::read_to_string(change_dir.join("implementation.md")).unwrap();
+    assert!(impl_md.contains("Wire it up"));
+
+    let out = run(&["tasks", "ready", "000-01_demo", "--json"]);
+    assert_eq!(out.code, 0, "{}", out.stderr);
+    assert!(
+        out.stdout.contains("implementation.md:1.1"),
+        "{}",
+        out.stdout
+    );
+
+    let out = run(&["list", "--json"]);
+    assert_eq!(out.code, 0, "{}", out.stderr);
+    let v: serde_json::Value = serde_json::from_str(&out.stdout).unwrap();
+    let tracks = v["changes"][0]["tracks"].as_array().expect("tracks");
+    assert_eq!(tracks.len(), 2);
+    assert_eq!(tracks[0]["file"], "tests.md");
+
+    let out = run(&[
+        "agent",
+        "instruction",
+        "apply",
+        "--change",
+        "000-01_demo",
+        "--json",
+    ]);
+    assert_eq!(out.code, 0, "{}", out.stderr);
+    let v: serde_json::Value = serde_json::from_str(&out.stdout).unwrap();
+    assert_eq!(v["trackedFiles"].as_array().map(Vec::len), Some(2));
+}
+
+#[test]
+fn tasks_use_a_single_tracking_file_set_by_override() {
+    let base = fixtures::make_empty_repo();
+    let repo = tempfile::tempdir().expect("work");
+    let home = tempfile::tempdir().expect("home");
+    let rust_path = assert_cmd::cargo::cargo_bin!("spool");
+
+    fixtures::reset_repo(repo.path(), base.path());
+    let run = |args: &[&str]| run_rust_candidate(rust_path, args, repo.path(), home.path());
+
+    let out = run(&["create", "change", "demo", "--tracks", "todo.md"]);
+    assert_eq!(out.code, 0, "{}", out.stderr);
+    let change_dir = repo.path().join(".spool/changes/000-01_demo");
+    let meta = std::fs::read_to_string(change_dir.join(".spool.yaml")).unwrap();
+    assert!(meta.contains("tracks:\n- todo.md\n"), "{meta}");
+
+    let out = run(&["tasks", "status", "000-01_demo"]);
+    assert_eq!(out.code, 0, "{}", out.stderr);
+    assert!(out.stdout.contains("No todo.md found"), "{}", out.stdout);
+
+    assert_eq!(run(&["tasks", "init", "000-01_demo"]).code, 0);
+    assert!(change_dir.join("todo.md").exists());
+    assert!(!change_dir.join("tasks.md").exists());
+
+    let out = run(&["tasks", "status", "000-01_demo"]);
+    assert_eq!(out.code, 0, "{}", out.stderr);
+    assert!(
+        out.stdout.contains("Tasks for: 000-01_demo"),
+        "{}",
+        out.stdout
+    );
+
+    let out = run(&["tasks", "next", "000-01_demo"]);
+    assert_eq!(out.code, 0, "{}", out.stderr);
+
+    let out = run(&["tasks", "start", "000-01_demo", "9.9"]);
+    assert_ne!(out.code, 0);
+    assert!(
+        out.stderr.contains("Task \"9.9\" not found in todo.md"),
+        "{}",
+        out.stderr
+    );
+
+    let out = run(&["tasks", "show", "000-01_demo"]);
+    assert_eq!(out.code, 0, "{}", out.stderr);
+
+    let out = run(&[
+        "agent",
+        "instruction",
+        "apply",
+        "--change",
+        "000-01_demo",
+        "--json",
+    ]);
+    assert_eq!(out.code, 0, "{}", out.stderr);
+    let v: serde_json::Value = serde_json::from_str(&out.stdout).unwrap();
+    assert_eq!(v["tracksFile"], "todo.md");
+    assert_eq!(v["progress"]["total"].as_u64().map(|n| n > 0), Some(true));
+}
+
+#[test]
+fn tracking_files_outside_the_change_dir_are_rejected() {
+    let base = fixtures::make_empty_repo();
+    let repo = tempfile::tempdir().expect("work");
+    let home = tempfile::tempdir().expect("home");
+    let rust_path = assert_cmd::cargo::cargo_bin!("spool");
+
+    fixtures::reset_repo(repo.path(), base.path());
+    let run = |args: &[&str]| run_rust_candidate(rust_path, args, repo.path(), home.path());
+
+    let out = run(&["create", "change", "demo", "--tracks", "../escape.md"]);
+    assert_ne!(out.code, 0);
+    assert!(
+        out.stderr.contains("Invalid tracking file '../escape.md'"),
+        "{}",
+        out.stderr
+    );
+
+    // A hand-edited override that escapes the change dir is ignored.
+    assert_eq!(run(&["create", "change", "demo"]).code, 0);
+    let change_dir = repo.path().join(".spool/changes/000-01_demo");
+    fixtures::write(
+        change_dir.join(".spool.yaml"),
+        "schema: spec-driven\ntracks:\n- ../../escape.md\n",
+    );
+    assert_eq!(run(&["tasks", "init", "000-01_demo"]).code, 0);
+    assert!(change_dir.join("tasks.md").exists());
+    assert!(!repo.path().join(".spool/escape.md").exists());
+}
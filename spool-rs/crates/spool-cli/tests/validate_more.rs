@@ -134,3 +134,119 @@ fn validate_module_routes_and_error_paths() {
     assert_eq!(out.code, 0);
     assert!(out.stdout.contains("is valid"));
 }
+
+#[test]
+fn schema_validate_lints_project_and_builtin_schemas() {
+    let base = fixtures::make_repo_all_valid();
+    let repo = tempfile::tempdir().expect("work");
+    let home = tempfile::tempdir().expect("home");
+    let rust_path = assert_cmd::cargo::cargo_bin!("spool");
+
+    fixtures::reset_repo(repo.path(), base.path());
+    fixtures::write(
+        repo.path().join(".spool/schemas/team/schema.yaml"),
+        "name: team\nextends: spec-driven\nremove: [design]\n",
+    );
+
+    let out = run_rust_candidate(
+        rust_path,
+        &["schema", "validate", "spec-driven"],
+        repo.path(),
+        home.path(),
+    );
+    assert_eq!(out.code, 0, "stderr={}", out.stderr);
+    assert!(out.stdout.contains("Schema 'spec-driven' is valid"));
+
+    let out = run_rust_candidate(rust_path, &["schema", "validate"], repo.path(), home.path());
+    assert_ne!(out.code, 0);
+    assert!(out.stdout.contains("Schema 'tdd' is valid"));
+    assert!(out.stderr.contains("Schema 'team' has issues"));
+    assert!(
+        out.stderr
+            .contains("Artifact 'tasks' requires unknown artifact 'design'")
+    );
+
+    let out = run_rust_candidate(
+        rust_path,
+        &["schema", "validate", "team", "--json"],
+        repo.path(),
+        home.path(),
+    );
+    assert_ne!(out.code, 0);
+    let v: serde_json::Value = serde_json::from_str(&out.stdout).expect("json");
+    assert_eq!(v["items"][0]["type"], "schema");
+    assert_eq!(v["summary"]["totals"]["failed"], 1);
+
+    let out = run_rust_candidate(
+        rust_path,
+        &["schema", "validate", "nope"],
+        repo.path(),
+        home.path(),
+    );
+    assert_ne!(out.code, 0);
+    assert!(out.stderr.contains("Schema 'nope' not found"));
+}
+
+#[test]
+fn status_json_and_validate_report_artifacts_older_than_their_inputs() {
+    let base = fixtures::make_repo_all_valid();
+    let repo = tempfile::tempdir().expect("work");
+    let home = tempfile::tempdir().expect("home");
+    let rust_path = assert_cmd::cargo::cargo_bin!("spool");
+
+    fixtures::reset_repo(repo.path(), base.path());
+    let change_dir = repo.path().join(".spool/changes/000-01_test-change");
+    // Specs and tasks were written an hour before the proposal was last edited.
+    let an_hour_ago = std::time::SystemTime::now() - std::time::Duration::from_secs(3600);
+    for rel in ["specs/alpha/spec.md", "tasks.md"] {
+        std::fs::File::options()
+            .write(true)
+            .open(change_dir.join(rel))
+            .unwrap()
+            .set_modified(an_hour_ago)
+            .unwrap();
+    }
+
+    let out = run_rust_candidate(
+        rust_path,
+        &["status", "--change", "000-01_test-change", "--json"],
+        repo.path(),
+        home.path(),
+    );
+    assert_eq!(out.code, 0, "{}", out.stderr);
+    let v: serde_json::Value = serde_json::from_str(&out.stdout).unwrap();
+    let artifacts = v["artifacts"].as_array().unwrap();
+    let specs = artifacts.iter().find(|a| a["id"] == "specs").unwrap();
+    assert_eq!(specs["files"][0]["path"], "specs/alpha/spec.md");
+    assert!(specs["files"][0]["modified"].is_string());
+    assert_eq!(specs["stale"], true);
+    assert_eq!(specs["staleAgainst"], serde_json::json!(["proposal"]));
+    let proposal = artifacts.iter().find(|a| a["id"] == "proposal").unwrap();
+    assert_eq!(proposal["stale"], false);
+    assert!(proposal.get("staleAgainst").is_none());
+
+    let out = run_rust_candidate(
+        rust_path,
+        &["validate", "000-01_test-change", "--json"],
+        repo.path(),
+        home.path(),
+    );
+    assert_eq!(out.code, 0, "{}", out.stderr);
+    assert!(
+        out.stdout
+            .contains("Artifact 'specs' may be stale: proposal modified after it")
+    );
+    assert!(
+        out.stdout
+            .contains("Artifact 'tasks' may be stale: proposal modified after it")
+    );
+
+    // Warnings fail strict validation.
+    let out = run_rust_candidate(
+        rust_path,
+        &["validate", "000-01_test-change", "--strict"],
+        repo.path(),
+        home.path(),
+    );
+    assert_ne!(out.code, 0);
+}
@@ -1,5 +1,7 @@
+use std::collections::BTreeSet;
 use std::io::ErrorKind;
 use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
 
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
@@ -8,6 +10,9 @@ use spool_common::fs::{FileSystem, StdFs};
 pub mod defaults;
 pub mod schema;
 pub mod types;
+pub mod validate;
+
+use validate::{ConfigIssue, validate_config_value};
 
 const REPO_CONFIG_FILE_NAME: &str = "spool.json";
 const REPO_DOT_CONFIG_FILE_NAME: &str = ".spool.json";
@@ -230,6 +235,7 @@ pub fn load_cascading_project_config_fs<F: FileSystem>(
         let Some(v) = load_json_object_fs(fs, &path) else {
             continue;
         };
+        warn_config_issues(&path, &v);
         merge_json(&mut merged, v);
         loaded_from.push(path);
     }
@@ -240,6 +246,163 @@ pub fn load_cascading_project_config_fs<F: FileSystem>(
     }
 }
 
+/// Print schema problems in a loaded config file, once per file per process.
+fn warn_config_issues(path: &Path, v: &Value) {
+    static WARNED: OnceLock<Mutex<BTreeSet<PathBuf>>> = OnceLock::new();
+    let issues = validate_config_value(v);
+    if issues.is_empty() {
+        return;
+    }
+    let warned = WARNED.get_or_init(|| Mutex::new(BTreeSet::new()));
+    let Ok(mut warned) = warned.lock() else {
+        return;
+    };
+    if !warned.insert(path.to_path_buf()) {
+        return;
+    }
+    for issue in issues {
+        eprintln!("Warning: {}: {issue}", path.display());
+    }
+}
+
+/// Schema check result for one project config file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ConfigFileCheck {
+    pub path: PathBuf,
+    pub issues: Vec<ConfigIssue>,
+}
+
+/// Check every existing file in the project config cascade against the config schema.
+///
+/// Unlike loading, unreadable JSON is reported as an issue rather than skipped.
+pub fn check_project_config(
+    project_root: &Path,
+    spool_path: &Path,
+    ctx: &ConfigContext,
+) -> Vec<ConfigFileCheck> {
+    check_project_config_fs(&StdFs, project_root, spool_path, ctx)
+}
+
+pub fn check_project_config_fs<F: FileSystem>(
+    fs: &F,
+    project_root: &Path,
+    spool_path: &Path,
+    ctx: &ConfigContext,
+) -> Vec<ConfigFileCheck> {
+    let mut out = Vec::new();
+    for path in project_config_paths(project_root, spool_path, ctx) {
+        let Some(contents) = read_to_string_optional_fs(fs, &path) else {
+            continue;
+        };
+        let issues = match serde_json::from_str::<Value>(&contents) {
+            Ok(v) => validate_config_value(&v),
+            Err(e) => vec![ConfigIssue {
+                pointer: String::new(),
+                message: format!("invalid JSON: {e}"),
+            }],
+        };
+        out.push(ConfigFileCheck { path, issues });
+    }
+    out
+}
+
+/// A config file that `spool config` can read and write.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigScope {
+    /// `<config-dir>/config.json`
+    Global,
+    /// `<repo-root>/spool.json`
+    Repo,
+    /// `<repo-root>/.spool.json`
+    Project,
+    /// `<spoolDir>/config.json`
+    SpoolDir,
+}
+
+/// Path of the config file for a scope (`None` for global when no config dir is known).
+pub fn config_scope_path(
+    scope: ConfigScope,
+    project_root: &Path,
+    spool_path: &Path,
+    ctx: &ConfigContext,
+) -> Option<PathBuf> {
+    match scope {
+        ConfigScope::Global => global_config_path(ctx),
+        ConfigScope::Repo => Some(project_root.join(REPO_CONFIG_FILE_NAME)),
+        ConfigScope::Project => Some(project_root.join(REPO_DOT_CONFIG_FILE_NAME)),
+        ConfigScope::SpoolDir => Some(spool_path.join(SPOOL_DIR_CONFIG_FILE_NAME)),
+    }
+}
+
+/// A value for a key from one config source.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfigKeySource {
+    /// The file that set the value, or `None` for the built-in defaults.
+    pub path: Option<PathBuf>,
+    pub value: Value,
+}
+
+/// Where a project config key's effective value comes from.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfigKeyExplanation {
+    /// Value after merging all sources, if the key is set anywhere.
+    pub value: Option<Value>,
+    /// Every source that sets the key, lowest precedence first.
+    pub sources: Vec<ConfigKeySource>,
+}
+
+/// Explain a dot-separated key of the cascading project config.
+pub fn explain_project_config_key(
+    project_root: &Path,
+    spool_path: &Path,
+    ctx: &ConfigContext,
+    key: &str,
+) -> ConfigKeyExplanation {
+    explain_project_config_key_fs(&StdFs, project_root, spool_path, ctx, key)
+}
+
+pub fn explain_project_config_key_fs<F: FileSystem>(
+    fs: &F,
+    project_root: &Path,
+    spool_path: &Path,
+    ctx: &ConfigContext,
+    key: &str,
+) -> ConfigKeyExplanation {
+    let mut sources: Vec<ConfigKeySource> = Vec::new();
+    if let Some(v) = json_get_path(&defaults::default_config_json(), key) {
+        sources.push(ConfigKeySource {
+            path: None,
+            value: v.clone(),
+        });
+    }
+
+    let cfg = load_cascading_project_config_fs(fs, project_root, spool_path, ctx);
+    for path in &cfg.loaded_from {
+        let Some(v) = load_json_object_fs(fs, path) else {
+            continue;
+        };
+        if let Some(v) = json_get_path(&v, key) {
+            sources.push(ConfigKeySource {
+                path: Some(path.clone()),
+                value: v.clone(),
+            });
+        }
+    }
+
+    ConfigKeyExplanation {
+        value: json_get_path(&cfg.merged, key).cloned(),
+        sources,
+    }
+}
+
+fn json_get_path<'a>(root: &'a Value, key: &str) -> Option<&'a Value> {
+    let mut cur = root;
+    for part in key.split('.').map(str::trim).filter(|p| !p.is_empty()) {
+        cur = cur.as_object()?.get(part)?;
+    }
+    Some(cur)
+}
+
 pub fn global_config_path(ctx: &ConfigContext) -> Option<PathBuf> {
     spool_config_dir(ctx).map(|d| d.join("config.json"))
 }
@@ -396,6 +559,84 @@ mod tests {
         assert!(r.merged.get("$schema").is_none());
     }
 
+    #[test]
+    fn explain_project_config_key_lists_sources_in_precedence_order() {
+        let repo = tempfile::tempdir().unwrap();
+        std::fs::write(
+            repo.path().join("spool.json"),
+            "{\"defaults\":{\"testing\":{\"coverage\":{\"target_percent\":70}}}}",
+        )
+        .unwrap();
+        let ctx = ConfigContext::default();
+        let spool_path = crate::spool_dir::get_spool_path(repo.path(), &ctx);
+        std::fs::create_dir_all(&spool_path).unwrap();
+        std::fs::write(
+            spool_path.join("config.json"),
+            "{\"defaults\":{\"testing\":{\"coverage\":{\"target_percent\":90}}}}",
+        )
+        .unwrap();
+
+        let e = explain_project_config_key(
+            repo.path(),
+            &spool_path,
+            &ctx,
+            "defaults.testing.coverage.target_percent",
+        );
+        assert_eq!(e.value, Some(serde_json::json!(90)));
+        let paths: Vec<Option<PathBuf>> = e.sources.iter().map(|s| s.path.clone()).collect();
+        assert_eq!(
+            paths,
+            vec![
+                None,
+                Some(repo.path().join("spool.json")),
+                Some(spool_path.join("config.json")),
+            ]
+        );
+        assert_eq!(e.sources[1].value, serde_json::json!(70));
+
+        let e = explain_project_config_key(repo.path(), &spool_path, &ctx, "nope.missing");
+        assert_eq!(e.value, None);
+        assert!(e.sources.is_empty());
+
+        assert_eq!(
+            config_scope_path(ConfigScope::Project, repo.path(), &spool_path, &ctx),
+            Some(repo.path().join(".spool.json"))
+        );
+    }
+
+    #[test]
+    fn check_project_config_reports_each_cascade_file() {
+        let repo = tempfile::tempdir().unwrap();
+        std::fs::write(
+            repo.path().join("spool.json"),
+            "{\"cache\":{\"ttlHours\":1}}",
+        )
+        .unwrap();
+        std::fs::write(repo.path().join(".spool.json"), "{oops").unwrap();
+        let ctx = ConfigContext::default();
+        let spool_path = crate::spool_dir::get_spool_path(repo.path(), &ctx);
+        std::fs::create_dir_all(&spool_path).unwrap();
+        std::fs::write(
+            spool_path.join("config.json"),
+            "{\"projectPath\":\".spool\"}",
+        )
+        .unwrap();
+
+        let checks = check_project_config(repo.path(), &spool_path, &ctx);
+        let paths: Vec<&Path> = checks.iter().map(|c| c.path.as_path()).collect();
+        assert_eq!(
+            paths,
+            vec![
+                repo.path().join("spool.json").as_path(),
+                repo.path().join(".spool.json").as_path(),
+                spool_path.join("config.json").as_path(),
+            ]
+        );
+        assert_eq!(checks[0].issues[0].pointer, "/cache/ttlHours");
+        assert!(checks[1].issues[0].message.starts_with("invalid JSON"));
+        assert!(checks[2].issues.is_empty());
+    }
+
     #[test]
     fn global_config_path_prefers_xdg() {
         let ctx = ConfigContext {
@@ -26,6 +26,18 @@ pub struct SpoolConfig {
     #[serde(default)]
     #[schemars(default, description = "Global defaults for workflow and tooling")]
     pub defaults: DefaultsConfig,
+
+    #[serde(default)]
+    #[schemars(default, description = "Ralph loop settings")]
+    pub ralph: RalphConfig,
+
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    #[schemars(description = "Per-tool preferences, keyed by tool name")]
+    pub tools: BTreeMap<String, Value>,
+
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    #[schemars(description = "Per-agent preferences, keyed by agent name")]
+    pub agents: BTreeMap<String, Value>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -366,6 +378,18 @@ pub struct TestingDefaults {
     #[serde(default)]
     #[schemars(default, description = "Coverage defaults")]
     pub coverage: CoverageDefaults,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(description = "Mocking defaults")]
+    pub mocking: Option<MockingDefaults>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[schemars(description = "Mocking defaults")]
+pub struct MockingDefaults {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(description = "How tests should use mocks (e.g. avoid)")]
+    pub policy: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -402,6 +426,16 @@ pub struct CoverageDefaults {
         description = "Target coverage percentage"
     )]
     pub target_percent: u64,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(description = "Minimum acceptable coverage percentage")]
+    pub minimum_percent: Option<u64>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(
+        description = "LCOV or Cobertura report used by `spool coverage check`, relative to the project root"
+    )]
+    pub report: Option<String>,
 }
 
 impl CoverageDefaults {
@@ -414,6 +448,38 @@ impl Default for CoverageDefaults {
     fn default() -> Self {
         Self {
             target_percent: Self::default_target_percent(),
+            minimum_percent: None,
+            report: None,
         }
     }
 }
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[schemars(description = "Ralph loop settings")]
+pub struct RalphConfig {
+    #[serde(default)]
+    #[schemars(default, description = "Shell commands run around Ralph iterations")]
+    pub hooks: RalphHooksConfig,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[schemars(description = "Ralph hooks")]
+pub struct RalphHooksConfig {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(description = "Runs before each iteration; a non-zero exit skips the iteration")]
+    pub pre_iteration: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(
+        description = "Runs after each successful iteration, before it is committed; failures are reported in the next prompt"
+    )]
+    pub post_iteration: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(description = "Runs once the loop completes")]
+    pub on_complete: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(description = "Runs when the loop stops with an error")]
+    pub on_failure: Option<String>,
+}
@@ -0,0 +1,300 @@
+//! Check config files against the JSON schema generated from [`SpoolConfig`].
+//!
+//! Config files are partial overlays merged onto the defaults, so `required` is not
+//! enforced; the checks cover unknown keys, value types, enums and numeric bounds.
+//!
+//! [`SpoolConfig`]: super::types::SpoolConfig
+
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use super::schema::config_schema_json;
+
+/// One problem found in a config value.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ConfigIssue {
+    /// JSON pointer to the offending value (empty for the document root).
+    pub pointer: String,
+    pub message: String,
+}
+
+impl std::fmt::Display for ConfigIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.pointer.is_empty() {
+            write!(f, "{}", self.message)
+        } else {
+            write!(f, "{}: {}", self.pointer, self.message)
+        }
+    }
+}
+
+/// Check a config value (the contents of one config file) against the config schema.
+pub fn validate_config_value(value: &Value) -> Vec<ConfigIssue> {
+    let root = config_schema_json();
+    let mut out = Vec::new();
+    check(&root, &root, value, "", &mut out);
+    out
+}
+
+fn check(root: &Value, schema: &Value, value: &Value, pointer: &str, out: &mut Vec<ConfigIssue>) {
+    let schema = resolve_ref(root, schema);
+
+    if let Some(all) = schema.get("allOf").and_then(Value::as_array) {
+        for s in all {
+            check(root, s, value, pointer, out);
+        }
+    }
+    if let Some(any) = schema
+        .get("anyOf")
+        .or_else(|| schema.get("oneOf"))
+        .and_then(Value::as_array)
+    {
+        check_any_of(root, any, value, pointer, out);
+    }
+
+    if let Some(expected) = schema.get("type")
+        && !type_matches(expected, value)
+    {
+        out.push(issue(
+            pointer,
+            format!(
+                "expected {}, found {}",
+                type_names(expected).join(" or "),
+                value_type(value)
+            ),
+        ));
+        return;
+    }
+
+    if let Some(allowed) = schema.get("enum").and_then(Value::as_array)
+        && !allowed.contains(value)
+    {
+        let names: Vec<String> = allowed.iter().map(Value::to_string).collect();
+        out.push(issue(
+            pointer,
+            format!("expected one of {}, found {value}", names.join(", ")),
+        ));
+    }
+
+    if let Some(n) = value.as_f64() {
+        if let Some(min) = schema.get("minimum").and_then(Value::as_f64)
+            && n < min
+        {
+            out.push(issue(
+                pointer,
+                format!("must be at least {min}, found {value}"),
+            ));
+        }
+        if let Some(max) = schema.get("maximum").and_then(Value::as_f64)
+            && n > max
+        {
+            out.push(issue(
+                pointer,
+                format!("must be at most {max}, found {value}"),
+            ));
+        }
+    }
+
+    if let Value::Object(map) = value {
+        check_object(root, schema, map, pointer, out);
+    }
+    if let (Value::Array(items), Some(item_schema)) = (value, schema.get("items")) {
+        for (idx, item) in items.iter().enumerate() {
+            check(root, item_schema, item, &format!("{pointer}/{idx}"), out);
+        }
+    }
+}
+
+/// Use the first alternative whose type fits the value, so a typo inside an options
+/// object is reported as such rather than as "expected string".
+fn check_any_of(
+    root: &Value,
+    any: &[Value],
+    value: &Value,
+    pointer: &str,
+    out: &mut Vec<ConfigIssue>,
+) {
+    let mut best: Option<Vec<ConfigIssue>> = None;
+    let mut expected: Vec<String> = Vec::new();
+    for alt in any {
+        let alt = resolve_ref(root, alt);
+        if let Some(t) = alt.get("type") {
+            expected.extend(type_names(t));
+            if !type_matches(t, value) {
+                continue;
+            }
+        }
+        let mut issues = Vec::new();
+        check(root, alt, value, pointer, &mut issues);
+        if issues.is_empty() {
+            return;
+        }
+        if best.as_ref().is_none_or(|b| issues.len() < b.len()) {
+            best = Some(issues);
+        }
+    }
+    match best {
+        Some(issues) => out.extend(issues),
+        None => {
+            expected.dedup();
+            out.push(issue(
+                pointer,
+                format!(
+                    "expected {}, found {}",
+                    expected.join(" or "),
+                    value_type(value)
+                ),
+            ));
+        }
+    }
+}
+
+fn check_object(
+    root: &Value,
+    schema: &Value,
+    map: &Map<String, Value>,
+    pointer: &str,
+    out: &mut Vec<ConfigIssue>,
+) {
+    let props = schema.get("properties").and_then(Value::as_object);
+    let additional = schema.get("additionalProperties");
+    for (key, v) in map {
+        let child = format!("{pointer}/{}", escape_pointer(key));
+        if let Some(s) = props.and_then(|p| p.get(key)) {
+            check(root, s, v, &child, out);
+            continue;
+        }
+        match additional {
+            Some(Value::Bool(true)) => {}
+            Some(s @ Value::Object(_)) => check(root, s, v, &child, out),
+            // Objects that list their properties are closed unless they say otherwise.
+            Some(_) | None => {
+                let Some(props) = props else {
+                    continue;
+                };
+                let known: Vec<String> = props.keys().cloned().collect();
+                let suggestions = spool_common::match_::nearest_matches(key, &known, 1);
+                let message = match suggestions.first() {
+                    Some(s) if spool_common::match_::levenshtein(key, s) <= 3 => {
+                        format!("unknown key '{key}' (did you mean '{s}'?)")
+                    }
+                    _ => format!("unknown key '{key}'"),
+                };
+                out.push(issue(&child, message));
+            }
+        }
+    }
+}
+
+fn resolve_ref<'a>(root: &'a Value, schema: &'a Value) -> &'a Value {
+    let Some(r) = schema.get("$ref").and_then(Value::as_str) else {
+        return schema;
+    };
+    r.strip_prefix('#')
+        .and_then(|p| root.pointer(p))
+        .unwrap_or(schema)
+}
+
+fn type_names(expected: &Value) -> Vec<String> {
+    match expected {
+        Value::String(s) => vec![s.clone()],
+        Value::Array(items) => items
+            .iter()
+            .filter_map(Value::as_str)
+            .map(str::to_string)
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+fn type_matches(expected: &Value, value: &Value) -> bool {
+    type_names(expected).iter().any(|t| match t.as_str() {
+        "null" => value.is_null(),
+        "boolean" => value.is_boolean(),
+        "string" => value.is_string(),
+        "array" => value.is_array(),
+        "object" => value.is_object(),
+        "number" => value.is_number(),
+        "integer" => value.is_i64() || value.is_u64(),
+        _ => true,
+    })
+}
+
+fn value_type(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(n) if n.is_f64() => "number",
+        Value::Number(_) => "integer",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+fn escape_pointer(key: &str) -> String {
+    key.replace('~', "~0").replace('/', "~1")
+}
+
+fn issue(pointer: &str, message: String) -> ConfigIssue {
+    ConfigIssue {
+        pointer: pointer.to_string(),
+        message,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn defaults_and_shipped_project_config_are_valid() {
+        assert_eq!(
+            validate_config_value(&super::super::defaults::default_config_json()),
+            vec![]
+        );
+        let shipped = json!({
+            "tools": {},
+            "agents": {},
+            "defaults": {"testing": {
+                "tdd": {"workflow": "red-green-refactor"},
+                "coverage": {"target_percent": 100, "minimum_percent": 80},
+                "mocking": {"policy": "avoid"}
+            }}
+        });
+        assert_eq!(validate_config_value(&shipped), vec![]);
+    }
+
+    #[test]
+    fn reports_unknown_keys_and_type_mismatches_with_pointers() {
+        let v = json!({
+            "$schema": "./config.schema.json",
+            "cache": {"ttlHours": 12},
+            "defaults": {"testing": {"coverage": {"target_percent": "90"}}},
+            "harnesses": {"codex": {"agents": {
+                "spool-quick": "openai/gpt-5.1-codex-mini",
+                "spool-general": {"model": "x", "reasoningEffort": "extreme"}
+            }}},
+            "ralph": 3
+        });
+        let issues = validate_config_value(&v);
+        let pointers: Vec<&str> = issues.iter().map(|i| i.pointer.as_str()).collect();
+        assert_eq!(
+            pointers,
+            vec![
+                "/cache/ttlHours",
+                "/defaults/testing/coverage/target_percent",
+                "/harnesses/codex/agents/spool-general/reasoningEffort",
+                "/ralph",
+            ]
+        );
+        assert_eq!(
+            issues[0].message,
+            "unknown key 'ttlHours' (did you mean 'ttl_hours'?)"
+        );
+        assert_eq!(issues[1].message, "expected integer, found string");
+        assert!(issues[2].message.contains("\"xhigh\""));
+        assert_eq!(issues[3].message, "expected object, found integer");
+    }
+}
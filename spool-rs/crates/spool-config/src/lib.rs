@@ -7,4 +7,4 @@ mod context;
 pub use config::*;
 pub use context::SpoolContext;
 
-pub use config::{defaults, schema, types};
+pub use config::{defaults, schema, types, validate};
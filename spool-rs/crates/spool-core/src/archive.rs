@@ -1,10 +1,15 @@
+use std::collections::{BTreeMap, BTreeSet};
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::process::Command;
 
-use chrono::Utc;
+use chrono::{SecondsFormat, Utc};
 use miette::{Result, miette};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use spool_domain::modules::ModuleDocument;
 
-use crate::id::parse_change_id;
+use crate::id::{parse_change_id, parse_module_id};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum TaskStatus {
@@ -169,17 +174,10 @@ fn mark_change_complete_in_module(spool_path: &Path, change_name: &str) {
         return;
     };
 
-    let mut out = String::new();
-    for line in md.lines() {
-        if line.contains(change_name) {
-            out.push_str(&line.replace("- [ ]", "- [x]"));
-            out.push('\n');
-            continue;
-        }
-        out.push_str(line);
-        out.push('\n');
+    let mut doc = ModuleDocument::parse(&md);
+    if doc.mark_change_complete(change_name) {
+        let _ = crate::io::write_std(&resolved.module_md, doc.to_markdown());
     }
-    let _ = crate::io::write_std(&resolved.module_md, out);
 }
 
 pub fn move_to_archive(spool_path: &Path, change_name: &str, archive_name: &str) -> Result<()> {
@@ -202,3 +200,234 @@ pub fn move_to_archive(spool_path: &Path, change_name: &str, archive_name: &str)
         .map_err(|e| miette!("I/O error moving change to archive: {e}"))?;
     Ok(())
 }
+
+/// Order changes so each module's changes are archived after the modules it depends on.
+///
+/// Module dependencies come from the `## Depends On` section of each `module.md`. Within a
+/// module, changes are ordered by change number. Dependency cycles and unknown modules fall
+/// back to module id order rather than failing.
+pub fn order_changes_for_archive(spool_path: &Path, change_ids: &[String]) -> Vec<String> {
+    let mut by_module: BTreeMap<String, Vec<(u32, String)>> = BTreeMap::new();
+    for id in change_ids {
+        let (module, num) = match parse_change_id(id) {
+            Ok(p) => (
+                p.module_id.to_string(),
+                p.change_num.parse::<u32>().unwrap_or(u32::MAX),
+            ),
+            Err(_) => (String::new(), u32::MAX),
+        };
+        by_module.entry(module).or_default().push((num, id.clone()));
+    }
+
+    let mut deps: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+    for module in by_module.keys() {
+        let wanted: BTreeSet<String> = module_dependencies(spool_path, module)
+            .into_iter()
+            .filter(|d| d != module && by_module.contains_key(d))
+            .collect();
+        deps.insert(module.clone(), wanted);
+    }
+
+    let mut ordered_modules: Vec<String> = Vec::new();
+    let mut remaining: BTreeSet<String> = by_module.keys().cloned().collect();
+    while !remaining.is_empty() {
+        let next = remaining
+            .iter()
+            .find(|m| deps[*m].iter().all(|d| !remaining.contains(d)))
+            .or_else(|| remaining.iter().next())
+            .cloned();
+        let Some(next) = next else {
+            break;
+        };
+        remaining.remove(&next);
+        ordered_modules.push(next);
+    }
+
+    let mut out: Vec<String> = Vec::new();
+    for module in ordered_modules {
+        let Some(mut changes) = by_module.remove(&module) else {
+            continue;
+        };
+        changes.sort();
+        out.extend(changes.into_iter().map(|(_, id)| id));
+    }
+    out
+}
+
+fn module_dependencies(spool_path: &Path, module_id: &str) -> Vec<String> {
+    let Ok(Some(resolved)) = crate::validate::resolve_module(spool_path, module_id) else {
+        return Vec::new();
+    };
+    let md = crate::io::read_to_string_or_default(&resolved.module_md);
+    ModuleDocument::parse(&md)
+        .depends_on
+        .iter()
+        .filter_map(|item| parse_module_id(item).ok())
+        .map(|parsed| parsed.module_id.to_string())
+        .collect()
+}
+
+/// File name of the manifest written into an archived change directory.
+pub const ARCHIVE_MANIFEST_FILE: &str = "archive.json";
+
+/// Record of what archiving a change did to the main specs.
+///
+/// Written to `<archive>/archive.json` so later tooling (audit, unarchive, spec history)
+/// can reconstruct exactly which specs were created or updated.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArchiveManifest {
+    pub change_id: String,
+    pub archive_name: String,
+    /// RFC 3339 timestamp of when the archive happened.
+    pub archived_at: String,
+    pub spool_version: String,
+    /// `git rev-parse HEAD` at archive time, when the project is a git repository.
+    pub git_head: Option<String>,
+    pub validation_skipped: bool,
+    /// True when spec updates were not applied (`--skip-specs` or declined at the prompt).
+    pub specs_skipped: bool,
+    /// Specs that did not exist in the main specs directory before archiving.
+    pub new_specs: Vec<String>,
+    /// Specs that existed in the main specs directory and were overwritten.
+    pub updated_specs: Vec<String>,
+    pub specs: Vec<ArchivedSpec>,
+}
+
+/// Content hashes of a main spec before and after archiving.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArchivedSpec {
+    pub name: String,
+    /// `sha256:<hex>` of the main spec before archiving (`None` for new specs).
+    pub hash_before: Option<String>,
+    /// `sha256:<hex>` of the main spec after archiving.
+    pub hash_after: Option<String>,
+}
+
+/// Inputs for [`build_archive_manifest`] that are only known to the caller.
+#[derive(Debug, Clone)]
+pub struct ArchiveManifestInput<'a> {
+    pub change_id: &'a str,
+    pub archive_name: &'a str,
+    pub spool_version: &'a str,
+    pub validation_skipped: bool,
+    pub specs_skipped: bool,
+    /// Specs split by [`categorize_specs`] before anything was copied.
+    pub new_specs: &'a [String],
+    pub updated_specs: &'a [String],
+    /// Main spec hashes captured with [`snapshot_spec_hashes`] before copying.
+    pub hashes_before: &'a BTreeMap<String, Option<String>>,
+}
+
+/// Hash spec content in the `sha256:<hex>` format used by archive manifests.
+pub fn content_hash(contents: &[u8]) -> String {
+    let digest = Sha256::digest(contents);
+    format!("sha256:{}", hex::encode(digest))
+}
+
+/// Capture the current content hash of each named main spec (`None` if it does not exist).
+pub fn snapshot_spec_hashes(
+    spool_path: &Path,
+    spec_names: &[String],
+) -> Result<BTreeMap<String, Option<String>>> {
+    let mut out = BTreeMap::new();
+    for spec in spec_names {
+        let path = crate::paths::spec_markdown_path(spool_path, spec);
+        let hash = match fs::read(&path) {
+            Ok(bytes) => Some(content_hash(&bytes)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => None,
+            Err(e) => {
+                return Err(miette!("I/O error reading {p}: {e}", p = path.display()));
+            }
+        };
+        out.insert(spec.clone(), hash);
+    }
+    Ok(out)
+}
+
+/// Resolve the current git `HEAD` commit for the repository containing `dir`.
+pub fn git_head(dir: &Path) -> Option<String> {
+    let out = Command::new("git")
+        .arg("-C")
+        .arg(dir)
+        .args(["rev-parse", "HEAD"])
+        .output()
+        .ok()?;
+    if !out.status.success() {
+        return None;
+    }
+    let head = String::from_utf8_lossy(&out.stdout).trim().to_string();
+    if head.is_empty() { None } else { Some(head) }
+}
+
+/// Build the manifest for an archive, hashing the main specs as they are now.
+///
+/// Call this after specs have been copied so `hash_after` reflects the result.
+pub fn build_archive_manifest(
+    spool_path: &Path,
+    input: ArchiveManifestInput<'_>,
+) -> Result<ArchiveManifest> {
+    let mut names: Vec<String> = Vec::new();
+    names.extend(input.new_specs.iter().cloned());
+    names.extend(input.updated_specs.iter().cloned());
+    names.sort();
+
+    let hashes_after = snapshot_spec_hashes(spool_path, &names)?;
+    let mut specs: Vec<ArchivedSpec> = Vec::new();
+    for name in names {
+        specs.push(ArchivedSpec {
+            hash_before: input.hashes_before.get(&name).cloned().flatten(),
+            hash_after: hashes_after.get(&name).cloned().flatten(),
+            name,
+        });
+    }
+
+    let project_root = spool_path.parent().unwrap_or(spool_path);
+    Ok(ArchiveManifest {
+        change_id: input.change_id.to_string(),
+        archive_name: input.archive_name.to_string(),
+        archived_at: Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true),
+        spool_version: input.spool_version.to_string(),
+        git_head: git_head(project_root),
+        validation_skipped: input.validation_skipped,
+        specs_skipped: input.specs_skipped,
+        new_specs: input.new_specs.to_vec(),
+        updated_specs: input.updated_specs.to_vec(),
+        specs,
+    })
+}
+
+pub fn archive_manifest_path(spool_path: &Path, archive_name: &str) -> PathBuf {
+    crate::paths::changes_archive_dir(spool_path)
+        .join(archive_name)
+        .join(ARCHIVE_MANIFEST_FILE)
+}
+
+/// Write `archive.json` into an already-archived change directory.
+pub fn write_archive_manifest(
+    spool_path: &Path,
+    archive_name: &str,
+    manifest: &ArchiveManifest,
+) -> Result<PathBuf> {
+    let path = archive_manifest_path(spool_path, archive_name);
+    let mut raw = serde_json::to_string_pretty(manifest)
+        .map_err(|e| miette!("JSON error serializing archive manifest: {e}"))?;
+    raw.push('\n');
+    crate::io::write(&path, raw)?;
+    Ok(path)
+}
+
+/// Read `archive.json` from an archived change, if present.
+pub fn read_archive_manifest(
+    spool_path: &Path,
+    archive_name: &str,
+) -> Result<Option<ArchiveManifest>> {
+    let path = archive_manifest_path(spool_path, archive_name);
+    let Some(raw) = crate::io::read_to_string_optional(&path)? else {
+        return Ok(None);
+    };
+    let manifest = serde_json::from_str(&raw)
+        .map_err(|e| miette!("JSON error parsing {p}: {e}", p = path.display()))?;
+    Ok(Some(manifest))
+}
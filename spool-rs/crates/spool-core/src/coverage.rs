@@ -0,0 +1,514 @@
+//! Coverage gate for `defaults.testing.coverage.target_percent`.
+//!
+//! Reads an LCOV (`lcov.info`) or Cobertura (`coverage.xml`) report and compares line
+//! coverage with the configured target, both across the whole report and for the files
+//! named in a change's task `Files:` entries.
+
+use crate::config::{ConfigContext, load_cascading_project_config};
+use miette::{Result, miette};
+use serde::Serialize;
+use spool_config::types::CoverageDefaults;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// Report locations tried, relative to the project root, when none is configured.
+pub const DEFAULT_REPORT_PATHS: &[&str] = &[
+    "lcov.info",
+    "coverage/lcov.info",
+    "target/llvm-cov/lcov.info",
+    "coverage.xml",
+    "cobertura.xml",
+    "coverage/cobertura.xml",
+    "coverage/cobertura-coverage.xml",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CoverageFormat {
+    Lcov,
+    Cobertura,
+}
+
+/// Covered and coverable line counts.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize)]
+pub struct LineCoverage {
+    pub covered: u64,
+    pub total: u64,
+    pub percent: f64,
+}
+
+impl LineCoverage {
+    pub fn new(covered: u64, total: u64) -> Self {
+        let percent = if total == 0 {
+            100.0
+        } else {
+            covered as f64 * 100.0 / total as f64
+        };
+        Self {
+            covered,
+            total,
+            percent,
+        }
+    }
+
+    fn sum<'a>(items: impl IntoIterator<Item = &'a LineCoverage>) -> Self {
+        let (covered, total) = items
+            .into_iter()
+            .fold((0, 0), |(c, t), l| (c + l.covered, t + l.total));
+        Self::new(covered, total)
+    }
+}
+
+/// Per-file line coverage from a report, keyed by path relative to the project root
+/// where possible.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CoverageReport {
+    pub format: CoverageFormat,
+    pub files: BTreeMap<String, LineCoverage>,
+}
+
+impl CoverageReport {
+    pub fn overall(&self) -> LineCoverage {
+        LineCoverage::sum(self.files.values())
+    }
+}
+
+/// Hit counts by line number, merged across records for the same file.
+#[derive(Debug, Default)]
+struct FileLines {
+    lines: BTreeMap<u64, u64>,
+    /// `LF`/`LH` totals, used only when a record has no `DA` lines.
+    found: u64,
+    hit: u64,
+}
+
+impl FileLines {
+    fn record(&mut self, line: u64, hits: u64) {
+        let entry = self.lines.entry(line).or_default();
+        *entry = (*entry).max(hits);
+    }
+
+    fn coverage(&self) -> LineCoverage {
+        if self.lines.is_empty() {
+            return LineCoverage::new(self.hit.min(self.found), self.found);
+        }
+        let covered = self.lines.values().filter(|h| **h > 0).count() as u64;
+        LineCoverage::new(covered, self.lines.len() as u64)
+    }
+}
+
+/// Read a report, detecting its format from the contents.
+pub fn load_coverage_report(path: &Path, project_root: &Path) -> Result<CoverageReport> {
+    let text = crate::io::read_to_string(path)?;
+    parse_coverage_report(&text, project_root)
+        .map_err(|e| miette!("Invalid coverage report {}: {e}", path.display()))
+}
+
+pub fn parse_coverage_report(text: &str, project_root: &Path) -> Result<CoverageReport> {
+    let trimmed = text.trim_start();
+    let (format, files) = if trimmed.starts_with('<') {
+        (
+            CoverageFormat::Cobertura,
+            parse_cobertura(text, project_root)?,
+        )
+    } else {
+        (CoverageFormat::Lcov, parse_lcov(text)?)
+    };
+    let files = files
+        .into_iter()
+        .map(|(path, lines)| (normalize_path(&path, project_root), lines.coverage()))
+        .collect();
+    Ok(CoverageReport { format, files })
+}
+
+fn parse_lcov(text: &str) -> Result<BTreeMap<String, FileLines>> {
+    let mut files: BTreeMap<String, FileLines> = BTreeMap::new();
+    let mut current: Option<String> = None;
+    for (idx, line) in text.lines().enumerate() {
+        let line = line.trim();
+        let (tag, value) = line.split_once(':').unwrap_or((line, ""));
+        let bad = || miette!("line {}: malformed `{line}`", idx + 1);
+        match tag {
+            "SF" => {
+                current = Some(value.to_string());
+                files.entry(value.to_string()).or_default();
+            }
+            "end_of_record" => current = None,
+            "DA" | "LF" | "LH" => {
+                let Some(file) = current.as_ref().and_then(|f| files.get_mut(f)) else {
+                    return Err(miette!("line {}: `{tag}` outside a record", idx + 1));
+                };
+                let mut parts = value.split(',');
+                let first: u64 = parts
+                    .next()
+                    .unwrap_or("")
+                    .trim()
+                    .parse()
+                    .map_err(|_| bad())?;
+                match tag {
+                    "DA" => {
+                        let hits: u64 = parts
+                            .next()
+                            .and_then(|h| h.trim().parse::<f64>().ok())
+                            .ok_or_else(bad)? as u64;
+                        file.record(first, hits);
+                    }
+                    "LF" => file.found += first,
+                    _ => file.hit += first,
+                }
+            }
+            _ => {}
+        }
+    }
+    if files.is_empty() {
+        return Err(miette!("no `SF:` records found"));
+    }
+    Ok(files)
+}
+
+fn parse_cobertura(text: &str, project_root: &Path) -> Result<BTreeMap<String, FileLines>> {
+    if !text.contains("<coverage") {
+        return Err(miette!("expected a Cobertura <coverage> document"));
+    }
+    let mut sources: Vec<String> = Vec::new();
+    let mut classes: Vec<(String, FileLines)> = Vec::new();
+    let mut current: Option<(String, FileLines)> = None;
+    for piece in text.split('<') {
+        let (tag, rest) = piece.split_once('>').unwrap_or((piece, ""));
+        let name = tag.split_whitespace().next().unwrap_or("");
+        match name {
+            "source" => sources.push(xml_unescape(rest.trim())),
+            "class" => {
+                let filename = xml_attr(tag, "filename")
+                    .ok_or_else(|| miette!("<class> without a filename attribute"))?;
+                current = Some((filename, FileLines::default()));
+            }
+            "/class" => classes.extend(current.take()),
+            "line" => {
+                if let Some((_, lines)) = current.as_mut() {
+                    let number = xml_attr(tag, "number").and_then(|n| n.parse().ok());
+                    let hits = xml_attr(tag, "hits").and_then(|h| h.parse().ok());
+                    let (Some(number), Some(hits)) = (number, hits) else {
+                        return Err(miette!("<line> without number and hits attributes"));
+                    };
+                    lines.record(number, hits);
+                }
+            }
+            _ => {}
+        }
+    }
+    classes.extend(current.take());
+
+    let mut files: BTreeMap<String, FileLines> = BTreeMap::new();
+    for (filename, lines) in classes {
+        let path = cobertura_path(&filename, &sources, project_root);
+        let file = files.entry(path).or_default();
+        for (line, hits) in lines.lines {
+            file.record(line, hits);
+        }
+    }
+    Ok(files)
+}
+
+/// Cobertura filenames are relative to one of the report's `<source>` directories.
+fn cobertura_path(filename: &str, sources: &[String], project_root: &Path) -> String {
+    if Path::new(filename).is_absolute() || sources.is_empty() {
+        return filename.to_string();
+    }
+    let candidates: Vec<PathBuf> = sources
+        .iter()
+        .map(|s| Path::new(s).join(filename))
+        .collect();
+    let existing = candidates.iter().find(|p| {
+        if p.is_absolute() {
+            p.exists()
+        } else {
+            project_root.join(p).exists()
+        }
+    });
+    existing
+        .unwrap_or(&candidates[0])
+        .to_string_lossy()
+        .to_string()
+}
+
+fn xml_attr(tag: &str, name: &str) -> Option<String> {
+    let start = tag.find(&format!(" {name}=\""))? + name.len() + 3;
+    let len = tag[start..].find('"')?;
+    Some(xml_unescape(&tag[start..start + len]))
+}
+
+fn xml_unescape(s: &str) -> String {
+    s.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+fn normalize_path(path: &str, project_root: &Path) -> String {
+    let p = Path::new(path);
+    let p = p.strip_prefix(project_root).unwrap_or(p);
+    let s = p.to_string_lossy().replace('\\', "/");
+    s.trim_start_matches("./").to_string()
+}
+
+/// Whether a report path belongs to a task `Files:` entry (a file or a directory), both
+/// relative to the project root.
+fn entry_matches(entry: &str, path: &str) -> bool {
+    path == entry || path.starts_with(&format!("{entry}/"))
+}
+
+/// Whether the paths could name the same file written relative to different roots.
+fn suffix_matches(entry: &str, path: &str) -> bool {
+    path.ends_with(&format!("/{entry}")) || entry.ends_with(&format!("/{path}"))
+}
+
+/// Report files covered by a task `Files:` entry.
+///
+/// Paths are matched exactly or by directory. Only when nothing matches is the entry
+/// matched by path suffix, and then only if that finds a single file: `src/lib.rs` in a
+/// workspace must not pick up every crate's `src/lib.rs`.
+fn report_files_for_entry<'a>(
+    report: &'a CoverageReport,
+    entry: &str,
+) -> Vec<(&'a String, &'a LineCoverage)> {
+    let hits: Vec<_> = report
+        .files
+        .iter()
+        .filter(|(path, _)| entry_matches(entry, path))
+        .collect();
+    if !hits.is_empty() {
+        return hits;
+    }
+    let suffix_hits: Vec<_> = report
+        .files
+        .iter()
+        .filter(|(path, _)| suffix_matches(entry, path))
+        .collect();
+    if suffix_hits.len() == 1 {
+        suffix_hits
+    } else {
+        Vec::new()
+    }
+}
+
+/// Read `defaults.testing.coverage` from the merged project config.
+pub fn load_coverage_defaults(
+    project_root: &Path,
+    spool_path: &Path,
+    ctx: &ConfigContext,
+) -> Result<CoverageDefaults> {
+    let cfg = load_cascading_project_config(project_root, spool_path, ctx);
+    let Some(coverage) = cfg
+        .merged
+        .get("defaults")
+        .and_then(|d| d.get("testing"))
+        .and_then(|t| t.get("coverage"))
+    else {
+        return Ok(CoverageDefaults::default());
+    };
+    serde_json::from_value(coverage.clone())
+        .map_err(|e| miette!("Invalid defaults.testing.coverage config: {e}"))
+}
+
+/// Locate the report: the configured path, or the first of [`DEFAULT_REPORT_PATHS`] that exists.
+pub fn find_coverage_report(project_root: &Path, configured: Option<&str>) -> Result<PathBuf> {
+    if let Some(configured) = configured {
+        let path = project_root.join(configured);
+        if !path.is_file() {
+            return Err(miette!("Coverage report not found: {}", path.display()));
+        }
+        return Ok(path);
+    }
+    DEFAULT_REPORT_PATHS
+        .iter()
+        .map(|p| project_root.join(p))
+        .find(|p| p.is_file())
+        .ok_or_else(|| {
+            miette!(
+                "No coverage report found (looked for {}). Pass --report or set defaults.testing.coverage.report",
+                DEFAULT_REPORT_PATHS.join(", ")
+            )
+        })
+}
+
+/// Coverage of one task `Files:` entry.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct TaskFileCoverage {
+    pub path: String,
+    /// `None` when the report has no data for the entry (e.g. docs or config files).
+    pub coverage: Option<LineCoverage>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CoverageCheck {
+    pub change_id: String,
+    pub report: PathBuf,
+    pub format: CoverageFormat,
+    pub target_percent: u64,
+    pub overall: LineCoverage,
+    /// Combined coverage of the change's files that appear in the report.
+    pub change: Option<LineCoverage>,
+    pub files: Vec<TaskFileCoverage>,
+    pub passed: bool,
+}
+
+impl CoverageCheck {
+    /// Why the check failed, one line per reason.
+    pub fn failures(&self) -> Vec<String> {
+        let target = self.target_percent as f64;
+        let mut out = Vec::new();
+        if self.overall.percent < target {
+            out.push(format!(
+                "Overall line coverage {:.1}% is below the {}% target",
+                self.overall.percent, self.target_percent
+            ));
+        }
+        if let Some(change) = &self.change
+            && change.percent < target
+        {
+            out.push(format!(
+                "Line coverage of the change's files {:.1}% is below the {}% target",
+                change.percent, self.target_percent
+            ));
+            for f in &self.files {
+                if let Some(c) = &f.coverage
+                    && c.percent < target
+                {
+                    out.push(format!(
+                        "  {}: {:.1}% ({}/{} lines)",
+                        f.path, c.percent, c.covered, c.total
+                    ));
+                }
+            }
+        }
+        out
+    }
+}
+
+/// Compare a coverage report with `target_percent`, overall and for the change's task files.
+pub fn check_change_coverage(
+    spool_path: &Path,
+    change_id: &str,
+    report_path: &Path,
+    target_percent: u64,
+    ctx: &ConfigContext,
+) -> Result<CoverageCheck> {
+    let project_root = spool_path.parent().unwrap_or(spool_path);
+    let report = load_coverage_report(report_path, project_root)?;
+
+    let mut entries: Vec<String> = Vec::new();
+    for task in crate::workflow::task_repository(spool_path, ctx).get_tasks(change_id)? {
+        for file in task.files {
+            let file = normalize_path(file.trim_end_matches('/'), project_root);
+            if !entries.contains(&file) {
+                entries.push(file);
+            }
+        }
+    }
+
+    let mut matched: BTreeMap<&str, LineCoverage> = BTreeMap::new();
+    let files: Vec<TaskFileCoverage> = entries
+        .into_iter()
+        .map(|entry| {
+            let hits = report_files_for_entry(&report, &entry);
+            for (path, c) in &hits {
+                matched.insert(path.as_str(), **c);
+            }
+            let coverage = (!hits.is_empty()).then(|| LineCoverage::sum(hits.iter().map(|h| h.1)));
+            TaskFileCoverage {
+                path: entry,
+                coverage,
+            }
+        })
+        .collect();
+
+    let overall = report.overall();
+    let change = (!matched.is_empty()).then(|| LineCoverage::sum(matched.values()));
+    let target = target_percent as f64;
+    let passed = overall.percent >= target && change.is_none_or(|c| c.percent >= target);
+    Ok(CoverageCheck {
+        change_id: change_id.to_string(),
+        report: report_path.to_path_buf(),
+        format: report.format,
+        target_percent,
+        overall,
+        change,
+        files,
+        passed,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lcov_records_are_merged_per_file() {
+        let text = "TN:\nSF:/repo/src/a.rs\nDA:1,3\nDA:2,0\nLF:2\nLH:1\nend_of_record\n\
+                    SF:/repo/src/a.rs\nDA:2,1\nDA:3,0\nend_of_record\n\
+                    SF:src/b.rs\nLF:4\nLH:4\nend_of_record\n";
+        let report = parse_coverage_report(text, Path::new("/repo")).unwrap();
+        assert_eq!(report.format, CoverageFormat::Lcov);
+        assert_eq!(report.files["src/a.rs"], LineCoverage::new(2, 3));
+        assert_eq!(report.files["src/b.rs"], LineCoverage::new(4, 4));
+        assert_eq!(report.overall(), LineCoverage::new(6, 7));
+
+        assert!(parse_coverage_report("DA:1,1\n", Path::new("/repo")).is_err());
+    }
+
+    #[test]
+    fn cobertura_classes_resolve_against_sources() {
+        let text = r#"<?xml version="1.0" ?>
+<coverage line-rate="0.5">
+  <sources><source>/repo/src</source></sources>
+  <packages><package name="p"><classes>
+    <class name="a" filename="a.py" line-rate="0.5">
+      <methods><method name="f"><lines><line number="1" hits="1"/></lines></method></methods>
+      <lines>
+        <line number="1" hits="1"/>
+        <line number="2" hits="0" branch="false"/>
+      </lines>
+    </class>
+  </classes></package></packages>
+</coverage>
+"#;
+        let report = parse_coverage_report(text, Path::new("/repo")).unwrap();
+        assert_eq!(report.format, CoverageFormat::Cobertura);
+        assert_eq!(report.files.keys().collect::<Vec<_>>(), vec!["src/a.py"]);
+        assert_eq!(report.files["src/a.py"], LineCoverage::new(1, 2));
+    }
+
+    #[test]
+    fn task_entries_match_files_and_directories() {
+        assert!(entry_matches("src/a.rs", "src/a.rs"));
+        assert!(entry_matches("src", "src/a.rs"));
+        assert!(!entry_matches("crates/x/src/a.rs", "src/a.rs"));
+        assert!(!entry_matches("src/a", "src/ab.rs"));
+        assert!(suffix_matches("crates/x/src/a.rs", "src/a.rs"));
+    }
+
+    #[test]
+    fn suffix_matches_are_only_used_when_unambiguous() {
+        let text = "SF:crates/a/src/lib.rs\nLF:10\nLH:5\nend_of_record\n\
+                    SF:crates/b/src/lib.rs\nLF:10\nLH:10\nend_of_record\n\
+                    SF:crates/b/src/main.rs\nLF:4\nLH:1\nend_of_record\n";
+        let report = parse_coverage_report(text, Path::new("/repo")).unwrap();
+        let paths = |entry: &str| -> Vec<String> {
+            report_files_for_entry(&report, entry)
+                .into_iter()
+                .map(|(p, _)| p.clone())
+                .collect()
+        };
+
+        assert_eq!(paths("crates/a/src/lib.rs"), vec!["crates/a/src/lib.rs"]);
+        assert_eq!(
+            paths("crates/b"),
+            vec!["crates/b/src/lib.rs", "crates/b/src/main.rs"]
+        );
+        // Both crates have a `src/lib.rs`; guessing would mix unrelated code into the change.
+        assert!(paths("src/lib.rs").is_empty());
+        assert_eq!(paths("src/main.rs"), vec!["crates/b/src/main.rs"]);
+    }
+}
@@ -1,6 +1,7 @@
 use crate::id::{parse_change_id, parse_module_id};
 use chrono::{SecondsFormat, Utc};
 use serde::{Deserialize, Serialize};
+use spool_domain::modules::ModuleDocument;
 use std::collections::HashMap;
 use std::fs;
 use std::io;
@@ -17,6 +18,9 @@ pub enum CreateError {
     #[error("{0}")]
     InvalidChangeName(String),
 
+    #[error("Invalid tracking file '{0}': must be a relative path inside the change directory")]
+    InvalidTrackedFile(String),
+
     #[error("Module '{0}' not found")]
     ModuleNotFound(String),
 
@@ -28,6 +32,9 @@ pub enum CreateError {
 
     #[error("JSON error: {0}")]
     Json(#[from] serde_json::Error),
+
+    #[error("YAML error: {0}")]
+    Yaml(#[from] serde_yaml::Error),
 }
 
 #[derive(Debug, Clone)]
@@ -90,15 +97,9 @@ pub fn create_module(
     crate::io::create_dir_all_std(&module_dir)?;
 
     let title = to_title_case(name);
-    let md = generate_module_content(
-        &title,
-        Some("<!-- Describe the purpose of this module/epic -->"),
-        &scope,
-        &depends_on,
-        &[],
-    );
+    let doc = ModuleDocument::new(title, None, scope, depends_on);
     let module_md = module_dir.join("module.md");
-    crate::io::write_std(&module_md, md)?;
+    crate::io::write_std(&module_md, doc.to_markdown())?;
 
     Ok(CreateModuleResult {
         module_id: next_id,
@@ -110,15 +111,26 @@ pub fn create_module(
     })
 }
 
+/// Create a change directory and its `.spool.yaml` metadata.
+///
+/// `tracks` overrides the task tracking files named by the schema's `apply.tracks`; it is
+/// recorded in the metadata only when non-empty.
 pub fn create_change(
     spool_path: &Path,
     name: &str,
     schema: &str,
+    tracks: &[String],
     module: Option<&str>,
     description: Option<&str>,
 ) -> Result<CreateChangeResult, CreateError> {
     let name = name.trim();
     validate_change_name(name)?;
+    if let Some(file) = tracks
+        .iter()
+        .find(|f| !spool_domain::tasks::is_valid_tracked_file(f.trim()))
+    {
+        return Err(CreateError::InvalidTrackedFile(file.clone()));
+    }
 
     let modules_dir = crate::paths::modules_dir(spool_path);
     let module_id = module
@@ -148,7 +160,7 @@ pub fn create_change(
     }
     crate::io::create_dir_all_std(&change_dir)?;
 
-    write_change_metadata(&change_dir, schema)?;
+    write_change_metadata(&change_dir, schema, tracks)?;
 
     if let Some(desc) = description {
         // Match TS: README header uses the change id, not the raw name.
@@ -164,9 +176,21 @@ pub fn create_change(
     })
 }
 
-fn write_change_metadata(change_dir: &Path, schema: &str) -> Result<(), CreateError> {
+#[derive(Serialize)]
+struct TracksOverride<'a> {
+    tracks: &'a [String],
+}
+
+fn write_change_metadata(
+    change_dir: &Path,
+    schema: &str,
+    tracks: &[String],
+) -> Result<(), CreateError> {
     let created = Utc::now().format("%Y-%m-%d").to_string();
-    let content = format!("schema: {schema}\ncreated: {created}\n");
+    let mut content = format!("schema: {schema}\ncreated: {created}\n");
+    if !tracks.is_empty() {
+        content.push_str(&serde_yaml::to_string(&TracksOverride { tracks })?);
+    }
     crate::io::write_std(&change_dir.join(".spool.yaml"), content)?;
     Ok(())
 }
@@ -453,13 +477,6 @@ fn to_title_case(kebab: &str) -> String {
         .join(" ")
 }
 
-#[derive(Debug, Clone)]
-struct ModuleChange {
-    id: String,
-    completed: bool,
-    planned: bool,
-}
-
 fn add_change_to_module(
     spool_path: &Path,
     module_id: &str,
@@ -471,26 +488,19 @@ fn add_change_to_module(
     let module_md = modules_dir.join(&module_folder).join("module.md");
     let existing = crate::io::read_to_string_std(&module_md)?;
 
-    let title = extract_title(&existing)
-        .or_else(|| module_folder.split('_').nth(1).map(to_title_case))
-        .unwrap_or_else(|| "Module".to_string());
-    let purpose = extract_section(&existing, "Purpose")
-        .map(|s| s.trim().to_string())
-        .filter(|s| !s.is_empty());
-    let scope = parse_bullets(&extract_section(&existing, "Scope").unwrap_or_default());
-    let depends_on = parse_bullets(&extract_section(&existing, "Depends On").unwrap_or_default());
-    let mut changes = parse_changes(&extract_section(&existing, "Changes").unwrap_or_default());
-
-    if !changes.iter().any(|c| c.id == change_id) {
-        changes.push(ModuleChange {
-            id: change_id.to_string(),
-            completed: false,
-            planned: false,
-        });
+    let mut doc = ModuleDocument::parse(&existing);
+    if doc.title.is_empty() {
+        doc.title = module_folder
+            .split('_')
+            .nth(1)
+            .map(to_title_case)
+            .unwrap_or_else(|| "Module".to_string());
+    }
+    if !doc.add_change(change_id) {
+        return Ok(());
     }
 
-    let md = generate_module_content(&title, purpose.as_deref(), &scope, &depends_on, &changes);
-    crate::io::write_std(&module_md, md)?;
+    crate::io::write_std(&module_md, doc.to_markdown())?;
     Ok(())
 }
 
@@ -528,173 +538,17 @@ fn max_change_num_in_module_md(spool_path: &Path, module_id: &str) -> Result<u32
     Ok(max_seen)
 }
 
-fn extract_title(markdown: &str) -> Option<String> {
-    for line in markdown.lines() {
-        let line = line.trim();
-        if let Some(rest) = line.strip_prefix("# ") {
-            return Some(rest.trim().to_string());
-        }
-    }
-    None
-}
-
-fn extract_section(markdown: &str, header: &str) -> Option<String> {
-    let needle = format!("## {header}");
-    let mut in_section = false;
-    let mut out: Vec<&str> = Vec::new();
-    for line in markdown.lines() {
-        if line.trim() == needle {
-            in_section = true;
-            continue;
-        }
-        if in_section {
-            if line.trim_start().starts_with("## ") {
-                break;
-            }
-            out.push(line);
-        }
-    }
-    if !in_section {
-        return None;
-    }
-    Some(out.join("\n"))
-}
-
-fn parse_bullets(section: &str) -> Vec<String> {
-    let mut items = Vec::new();
-    for line in section.lines() {
-        let t = line.trim();
-        if let Some(rest) = t.strip_prefix("- ").or_else(|| t.strip_prefix("* ")) {
-            let s = rest.trim();
-            if !s.is_empty() {
-                items.push(s.to_string());
-            }
-        }
-    }
-    items
-}
-
-fn parse_changes(section: &str) -> Vec<ModuleChange> {
-    let mut out = Vec::new();
-    for line in section.lines() {
-        let t = line.trim();
-        if let Some(rest) = t.strip_prefix("- [") {
-            // - [x] id (planned)
-            if rest.len() < 3 {
-                continue;
-            }
-            let checked = rest.chars().next().unwrap_or(' ');
-            let completed = checked == 'x' || checked == 'X';
-            let after = rest[3..].trim();
-            let mut parts = after.split_whitespace();
-            let Some(id) = parts.next() else {
-                continue;
-            };
-            let planned = after.contains("(planned)");
-            out.push(ModuleChange {
-                id: id.to_string(),
-                completed,
-                planned,
-            });
-            continue;
-        }
-        if let Some(rest) = t.strip_prefix("- ").or_else(|| t.strip_prefix("* ")) {
-            let rest = rest.trim();
-            if rest.is_empty() {
-                continue;
-            }
-            let id = rest.split_whitespace().next().unwrap_or("");
-            if id.is_empty() {
-                continue;
-            }
-            let planned = rest.contains("(planned)");
-            out.push(ModuleChange {
-                id: id.to_string(),
-                completed: false,
-                planned,
-            });
-        }
-    }
-    out
-}
-
-fn generate_module_content<T: AsRef<str>>(
-    title: &str,
-    purpose: Option<&str>,
-    scope: &[T],
-    depends_on: &[T],
-    changes: &[ModuleChange],
-) -> String {
-    let purpose = purpose
-        .map(|s| s.to_string())
-        .unwrap_or_else(|| "<!-- Describe the purpose of this module/epic -->".to_string());
-    let scope_section = if scope.is_empty() {
-        "<!-- List the scope of this module -->".to_string()
-    } else {
-        scope
-            .iter()
-            .map(|s| format!("- {}", s.as_ref()))
-            .collect::<Vec<_>>()
-            .join("\n")
-    };
-    let changes_section = if changes.is_empty() {
-        "<!-- Changes will be listed here as they are created -->".to_string()
-    } else {
-        changes
-            .iter()
-            .map(|c| {
-                let check = if c.completed { "x" } else { " " };
-                let planned = if c.planned { " (planned)" } else { "" };
-                format!("- [{check}] {}{planned}", c.id)
-            })
-            .collect::<Vec<_>>()
-            .join("\n")
-    };
-
-    // Match TS formatting (generateModuleContent):
-    // - No blank line between section header and content
-    // - Omit "Depends On" section when empty
-    let mut out = String::new();
-    out.push_str(&format!("# {title}\n\n"));
-
-    out.push_str("## Purpose\n");
-    out.push_str(&purpose);
-    out.push_str("\n\n");
-
-    out.push_str("## Scope\n");
-    out.push_str(&scope_section);
-    out.push_str("\n\n");
-
-    if !depends_on.is_empty() {
-        let depends_section = depends_on
-            .iter()
-            .map(|s| format!("- {}", s.as_ref()))
-            .collect::<Vec<_>>()
-            .join("\n");
-        out.push_str("## Depends On\n");
-        out.push_str(&depends_section);
-        out.push_str("\n\n");
-    }
-
-    out.push_str("## Changes\n");
-    out.push_str(&changes_section);
-    out.push('\n');
-    out
-}
-
 fn create_ungrouped_module(spool_path: &Path) -> Result<(), CreateError> {
     let modules_dir = crate::paths::modules_dir(spool_path);
     crate::io::create_dir_all_std(&modules_dir)?;
     let dir = modules_dir.join("000_ungrouped");
     crate::io::create_dir_all_std(&dir)?;
-    let empty: [&str; 0] = [];
-    let md = generate_module_content(
+    let doc = ModuleDocument::new(
         "Ungrouped",
-        Some("Changes that do not belong to a specific module."),
-        &["*"],
-        &empty,
-        &[],
+        Some("Changes that do not belong to a specific module.".to_string()),
+        vec!["*".to_string()],
+        Vec::new(),
     );
-    crate::io::write_std(&dir.join("module.md"), md)?;
+    crate::io::write_std(&dir.join("module.md"), doc.to_markdown())?;
     Ok(())
 }
@@ -1,4 +1,5 @@
 pub mod archive;
+pub mod coverage;
 pub mod create;
 pub mod distribution;
 
@@ -43,9 +44,12 @@ pub mod spool_dir {
 
 pub mod config {
     pub use spool_config::{
-        CascadingProjectConfig, ConfigContext, GlobalConfig, ProjectConfig, ResolvedConfig,
-        global_config_path, load_cascading_project_config, load_cascading_project_config_fs,
-        load_global_config, load_global_config_fs, load_project_config, load_project_config_fs,
+        CascadingProjectConfig, ConfigContext, ConfigFileCheck, ConfigKeyExplanation,
+        ConfigKeySource, ConfigScope, GlobalConfig, ProjectConfig, ResolvedConfig,
+        check_project_config, check_project_config_fs, config_scope_path,
+        explain_project_config_key, explain_project_config_key_fs, global_config_path,
+        load_cascading_project_config, load_cascading_project_config_fs, load_global_config,
+        load_global_config_fs, load_project_config, load_project_config_fs,
         load_repo_project_path_override, load_repo_project_path_override_fs, project_config_paths,
         spool_config_dir,
     };
@@ -58,6 +62,10 @@ pub mod config {
         pub use spool_config::schema::*;
     }
 
+    pub mod validate {
+        pub use spool_config::validate::*;
+    }
+
     pub mod types {
         pub use spool_config::types::*;
     }
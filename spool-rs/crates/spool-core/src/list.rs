@@ -35,6 +35,18 @@ pub struct ChangeListItem {
     pub work_status: String,
     /// True when no remaining work (complete or paused)
     pub completed: bool,
+    /// Per-file progress when the change tracks more than one file
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub tracks: Vec<TrackListItem>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct TrackListItem {
+    pub file: String,
+    #[serde(rename = "completedTasks")]
+    pub completed_tasks: u32,
+    #[serde(rename = "totalTasks")]
+    pub total_tasks: u32,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
@@ -0,0 +1,36 @@
+//! Pricing Ralph iterations from reported token usage.
+
+use spool_harness::TokenUsage;
+use spool_models::{Model, ModelRegistry};
+
+/// Find a model by id, accepting ids with or without the `provider/` prefix.
+pub fn find_model<'a>(models: &'a [Model], id: &str) -> Option<&'a Model> {
+    models.iter().find(|m| m.id == id).or_else(|| {
+        let bare = id.rsplit('/').next().unwrap_or(id);
+        models
+            .iter()
+            .find(|m| m.id.rsplit('/').next().unwrap_or(&m.id) == bare)
+    })
+}
+
+/// Pricing for `model_id` from the local model registry cache (no network access).
+pub fn lookup_pricing(model_id: &str) -> Option<Model> {
+    let load = ModelRegistry::default().load_cached()?;
+    find_model(&load.models, model_id).cloned()
+}
+
+/// Cost of one harness run in USD.
+///
+/// Registry pricing wins; the harness's own reported cost is the fallback.
+pub fn iteration_cost(pricing: Option<&Model>, usage: &TokenUsage) -> Option<f64> {
+    pricing
+        .and_then(|m| {
+            m.cost(
+                usage.input_tokens,
+                usage.output_tokens,
+                usage.cache_read_tokens,
+                usage.cache_write_tokens,
+            )
+        })
+        .or(usage.reported_cost)
+}
@@ -0,0 +1,175 @@
+//! Objective completion gates for Ralph loops.
+//!
+//! The completion promise only says the agent *thinks* it is done. Gates check the change
+//! itself (remaining tasks, strict validation, an optional verify command, line coverage of
+//! a report the verify command can produce) and the loop only ends once both the promise
+//! and every configured gate pass.
+
+use crate::config::ConfigContext;
+use miette::{Result, miette};
+use std::path::Path;
+use std::process::Command;
+
+/// Heading of the gate feedback section, so it can be carried over into later feedback.
+pub const GATE_FEEDBACK_HEADING: &str = "## Completion Gates Failed";
+
+/// Maximum number of output lines from the verify command kept in gate feedback.
+const VERIFY_OUTPUT_TAIL_LINES: usize = 40;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CompletionGates {
+    /// Require every task in the change's tracking files to be complete (shelved tasks count as done).
+    pub tasks: bool,
+    /// Require `spool validate <change> --strict` to be clean.
+    pub validate: bool,
+    /// Shell command that must exit 0 (e.g. `cargo test`).
+    pub command: Option<String>,
+    /// Require the coverage report to meet the target (checked after `command`).
+    pub coverage: Option<CoverageGate>,
+}
+
+impl CompletionGates {
+    pub fn is_empty(&self) -> bool {
+        !self.tasks && !self.validate && self.command.is_none() && self.coverage.is_none()
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CoverageGate {
+    /// Report path relative to the loop's working directory; `None` searches the default
+    /// locations.
+    pub report: Option<String>,
+    pub target_percent: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GateFailure {
+    pub gate: &'static str,
+    pub detail: String,
+}
+
+/// Run every configured gate and return the ones that failed.
+///
+/// `spool_path` is where the change's files are read from (the worktree copy when Ralph runs
+/// in a worktree); the verify command runs in `cwd`.
+pub fn check_gates(
+    spool_path: &Path,
+    change_id: &str,
+    gates: &CompletionGates,
+    cwd: &Path,
+    ctx: &ConfigContext,
+) -> Result<Vec<GateFailure>> {
+    let mut failures = Vec::new();
+
+    if gates.tasks {
+        let progress = crate::workflow::task_repository(spool_path, ctx).get_progress(change_id)?;
+        let done = progress.complete + progress.shelved;
+        if progress.total == 0 {
+            failures.push(GateFailure {
+                gate: "tasks",
+                detail: format!(
+                    "No tasks found in {}",
+                    crate::workflow::change_tracked_files(spool_path, change_id, ctx).join(", ")
+                ),
+            });
+        } else if done < progress.total {
+            failures.push(GateFailure {
+                gate: "tasks",
+                detail: format!(
+                    "{remaining} of {total} tasks are not complete",
+                    remaining = progress.total - done,
+                    total = progress.total
+                ),
+            });
+        }
+    }
+
+    if gates.validate {
+        let report = crate::validate::validate_change_all(spool_path, change_id, true, ctx)?;
+        if !report.valid {
+            let lines: Vec<String> = report
+                .issues
+                .iter()
+                .map(|i| format!("- [{}] {}: {}", i.level, i.path, i.message))
+                .collect();
+            failures.push(GateFailure {
+                gate: "validate",
+                detail: format!(
+                    "`spool validate {change_id} --strict` reported issues:\n{}",
+                    lines.join("\n")
+                ),
+            });
+        }
+    }
+
+    if let Some(command) = gates.command.as_deref() {
+        let out = Command::new("sh")
+            .args(["-c", command])
+            .current_dir(cwd)
+            .output()
+            .map_err(|e| miette!("Failed to run verify command `{command}`: {e}"))?;
+        if !out.status.success() {
+            let mut combined = String::from_utf8_lossy(&out.stdout).to_string();
+            combined.push_str(&String::from_utf8_lossy(&out.stderr));
+            let code = out
+                .status
+                .code()
+                .map(|c| c.to_string())
+                .unwrap_or_else(|| "signal".to_string());
+            failures.push(GateFailure {
+                gate: "command",
+                detail: format!(
+                    "`{command}` exited with {code}:\n{tail}",
+                    tail = tail_lines(&combined, VERIFY_OUTPUT_TAIL_LINES)
+                ),
+            });
+        }
+    }
+
+    if let Some(gate) = &gates.coverage {
+        let check =
+            crate::coverage::find_coverage_report(cwd, gate.report.as_deref()).and_then(|report| {
+                crate::coverage::check_change_coverage(
+                    spool_path,
+                    change_id,
+                    &report,
+                    gate.target_percent,
+                    ctx,
+                )
+            });
+        match check {
+            Ok(check) if check.passed => {}
+            Ok(check) => failures.push(GateFailure {
+                gate: "coverage",
+                detail: check.failures().join("\n"),
+            }),
+            Err(e) => failures.push(GateFailure {
+                gate: "coverage",
+                detail: e.to_string(),
+            }),
+        }
+    }
+
+    Ok(failures)
+}
+
+/// Render gate failures as a prompt section for the next iteration.
+pub fn format_gate_feedback(failures: &[GateFailure]) -> String {
+    let mut out = format!(
+        "{GATE_FEEDBACK_HEADING}\n\nThe previous iteration did not pass these completion checks. Fix them before claiming completion.\n"
+    );
+    for f in failures {
+        out.push_str(&format!(
+            "\n### {gate}\n\n{detail}\n",
+            gate = f.gate,
+            detail = f.detail.trim_end()
+        ));
+    }
+    out
+}
+
+fn tail_lines(text: &str, n: usize) -> String {
+    let lines: Vec<&str> = text.trim_end().lines().collect();
+    let start = lines.len().saturating_sub(n);
+    lines[start..].join("\n")
+}
@@ -0,0 +1,105 @@
+//! Project-configured shell hooks run around Ralph iterations.
+//!
+//! Hooks live under `ralph.hooks` in the project config (`spool.json`, `.spool.json` or
+//! `.spool/config.json`) and run through `sh -c` in the loop's working directory. Each hook
+//! gets `SPOOL_RALPH_HOOK`, `SPOOL_CHANGE_ID`, `SPOOL_MODULE_ID` and `SPOOL_RALPH_ITERATION`,
+//! plus `SPOOL_RALPH_EXIT_CODE`, `SPOOL_RALPH_PROMISE_FOUND` and `SPOOL_RALPH_ERROR` when known.
+
+use crate::config::{ConfigContext, load_cascading_project_config};
+use miette::{Result, miette};
+use spool_config::types::RalphHooksConfig;
+use std::path::Path;
+use std::process::Command;
+
+/// Heading of the prompt section reporting a failed `post_iteration` hook.
+pub const POST_HOOK_FEEDBACK_HEADING: &str = "## Post-Iteration Hook Failed";
+
+/// Maximum number of hook output lines quoted back to the agent.
+const HOOK_OUTPUT_TAIL_LINES: usize = 40;
+
+/// Read `ralph.hooks` from the merged project config.
+pub fn load_ralph_hooks(
+    project_root: &Path,
+    spool_path: &Path,
+    ctx: &ConfigContext,
+) -> Result<RalphHooksConfig> {
+    let cfg = load_cascading_project_config(project_root, spool_path, ctx);
+    let Some(hooks) = cfg.merged.get("ralph").and_then(|r| r.get("hooks")) else {
+        return Ok(RalphHooksConfig::default());
+    };
+    serde_json::from_value(hooks.clone()).map_err(|e| miette!("Invalid ralph.hooks config: {e}"))
+}
+
+/// What a hook is told about the loop.
+#[derive(Debug, Clone, Default)]
+pub struct HookEnv<'a> {
+    pub change_id: &'a str,
+    pub module_id: &'a str,
+    pub iteration: u32,
+    pub exit_code: Option<i32>,
+    pub promise_found: Option<bool>,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct HookOutcome {
+    pub success: bool,
+    /// Exit code, or `None` when the hook was killed by a signal.
+    pub code: Option<i32>,
+    /// Combined stdout and stderr.
+    pub output: String,
+}
+
+/// Run one hook and echo its output.
+pub fn run_hook(name: &str, command: &str, env: &HookEnv<'_>, cwd: &Path) -> Result<HookOutcome> {
+    println!("Running {name} hook: {command}");
+    let mut cmd = Command::new("sh");
+    cmd.args(["-c", command])
+        .current_dir(cwd)
+        .env("SPOOL_RALPH_HOOK", name)
+        .env("SPOOL_CHANGE_ID", env.change_id)
+        .env("SPOOL_MODULE_ID", env.module_id)
+        .env("SPOOL_RALPH_ITERATION", env.iteration.to_string());
+    if let Some(code) = env.exit_code {
+        cmd.env("SPOOL_RALPH_EXIT_CODE", code.to_string());
+    }
+    if let Some(found) = env.promise_found {
+        cmd.env("SPOOL_RALPH_PROMISE_FOUND", found.to_string());
+    }
+    if let Some(error) = &env.error {
+        cmd.env("SPOOL_RALPH_ERROR", error);
+    }
+
+    let out = cmd
+        .output()
+        .map_err(|e| miette!("Failed to run {name} hook `{command}`: {e}"))?;
+    let mut output = String::from_utf8_lossy(&out.stdout).to_string();
+    output.push_str(&String::from_utf8_lossy(&out.stderr));
+    if !output.trim().is_empty() {
+        println!("{}", output.trim_end());
+    }
+    Ok(HookOutcome {
+        success: out.status.success(),
+        code: out.status.code(),
+        output,
+    })
+}
+
+/// Describe a hook's exit status for log lines.
+pub fn describe_exit(outcome: &HookOutcome) -> String {
+    outcome
+        .code
+        .map(|c| format!("exit code {c}"))
+        .unwrap_or_else(|| "killed by signal".to_string())
+}
+
+/// Render a failed `post_iteration` hook as a prompt section for the next iteration.
+pub fn format_post_hook_feedback(command: &str, outcome: &HookOutcome) -> String {
+    let lines: Vec<&str> = outcome.output.trim_end().lines().collect();
+    let start = lines.len().saturating_sub(HOOK_OUTPUT_TAIL_LINES);
+    format!(
+        "{POST_HOOK_FEEDBACK_HEADING}\n\nThe post-iteration hook `{command}` failed with {status} after the previous iteration. Fix what it reports.\n\n```\n{tail}\n```\n",
+        status = describe_exit(outcome),
+        tail = lines[start..].join("\n")
+    )
+}
@@ -1,7 +1,15 @@
+pub mod cost;
 pub mod duration;
+pub mod gates;
+pub mod hooks;
+pub mod parallel;
 pub mod prompt;
 pub mod runner;
 pub mod state;
+pub mod worktree;
 
 pub use duration::{format_duration, parse_duration};
+pub use gates::{CompletionGates, CoverageGate};
+pub use hooks::load_ralph_hooks;
+pub use parallel::{ParallelOptions, ParallelSummary, run_parallel};
 pub use runner::{RalphOptions, run_ralph};
@@ -0,0 +1,309 @@
+//! Run several Ralph loops side by side.
+//!
+//! Each ready change in a module gets its own `spool ralph --change <id> --worktree` child
+//! process. Child output is multiplexed onto this process with a `[<change>]` prefix on every
+//! line, and a combined summary is written to `.state/ralph/` once every loop has finished.
+
+use crate::config::ConfigContext;
+use crate::ralph::state::load_state;
+use crate::ralph::worktree::{ensure_worktree, repo_root};
+use miette::{Result, miette};
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::io::{BufRead, BufReader, Read};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::Mutex;
+use std::time::Instant;
+
+#[derive(Debug, Clone)]
+pub struct ParallelOptions {
+    pub module_id: String,
+    /// Maximum number of loops running at once.
+    pub parallel: usize,
+    /// The `spool` executable used to run each loop.
+    pub program: PathBuf,
+    /// Extra `spool ralph` arguments forwarded to every loop (harness, iterations, prompt...).
+    pub args: Vec<String>,
+    /// Config context the changes' schemas are resolved with.
+    pub ctx: ConfigContext,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LoopOutcome {
+    /// The loop ended on the completion promise with its gates passing.
+    Complete,
+    /// The loop stopped at an iteration or budget limit without completing.
+    Incomplete,
+    /// The loop exited with an error.
+    Failed,
+}
+
+impl LoopOutcome {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            LoopOutcome::Complete => "complete",
+            LoopOutcome::Incomplete => "incomplete",
+            LoopOutcome::Failed => "failed",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ParallelLoopResult {
+    pub change_id: String,
+    pub outcome: LoopOutcome,
+    pub exit_code: Option<i32>,
+    /// Iterations recorded by this run (not counting earlier runs).
+    pub iterations: u32,
+    pub duration: i64,
+    pub branch: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ParallelSummary {
+    pub module_id: String,
+    pub parallel: usize,
+    pub duration: i64,
+    pub loops: Vec<ParallelLoopResult>,
+}
+
+impl ParallelSummary {
+    pub fn failed(&self) -> usize {
+        self.loops
+            .iter()
+            .filter(|l| l.outcome == LoopOutcome::Failed)
+            .count()
+    }
+}
+
+pub fn parallel_summary_path(spool_path: &Path, module_id: &str) -> PathBuf {
+    spool_path
+        .join(".state")
+        .join("ralph")
+        .join(format!("parallel-{module_id}.json"))
+}
+
+/// Changes in a module that are ready for implementation, sorted by id.
+pub fn ready_changes_for_module(
+    spool_path: &Path,
+    module_id: &str,
+    ctx: &ConfigContext,
+) -> Result<Vec<String>> {
+    let repo = crate::workflow::change_repository(spool_path, ctx);
+    let mut out: Vec<String> = repo
+        .list_by_module(module_id)?
+        .into_iter()
+        .filter(|c| c.is_ready())
+        .map(|c| c.id)
+        .collect();
+    out.sort();
+    Ok(out)
+}
+
+pub fn run_parallel(spool_path: &Path, opts: &ParallelOptions) -> Result<ParallelSummary> {
+    if opts.parallel == 0 {
+        return Err(miette!("--parallel must be >= 1"));
+    }
+    let changes = ready_changes_for_module(spool_path, &opts.module_id, &opts.ctx)?;
+    if changes.is_empty() {
+        return Err(miette!(
+            "No ready changes found for module {module}",
+            module = opts.module_id
+        ));
+    }
+
+    // Create worktrees up front: concurrent `git worktree add` calls contend on git's locks.
+    let cwd = std::env::current_dir().map_err(|e| miette!("Failed to read cwd: {e}"))?;
+    let root = repo_root(&cwd)?;
+    let mut branches = std::collections::BTreeMap::new();
+    for change in &changes {
+        let wt = ensure_worktree(&root, change)?;
+        branches.insert(change.clone(), wt.branch);
+    }
+
+    let workers = opts.parallel.min(changes.len());
+    println!(
+        "\n=== Running {n} Ralph loops for module {module} ({workers} at a time) ===\n",
+        n = changes.len(),
+        module = opts.module_id
+    );
+
+    let started = Instant::now();
+    let queue = Mutex::new(changes.iter().cloned().collect::<VecDeque<String>>());
+    let results = Mutex::new(Vec::new());
+    std::thread::scope(|scope| {
+        for _ in 0..workers {
+            scope.spawn(|| {
+                loop {
+                    let Some(change) = queue.lock().unwrap().pop_front() else {
+                        break;
+                    };
+                    let branch = branches.get(&change).cloned().unwrap_or_default();
+                    let result = run_one(spool_path, opts, &change, branch);
+                    results.lock().unwrap().push(result);
+                }
+            });
+        }
+    });
+
+    let mut loops = results.into_inner().unwrap();
+    loops.sort_by(|a, b| a.change_id.cmp(&b.change_id));
+    let summary = ParallelSummary {
+        module_id: opts.module_id.clone(),
+        parallel: workers,
+        duration: started.elapsed().as_millis() as i64,
+        loops,
+    };
+
+    let path = parallel_summary_path(spool_path, &opts.module_id);
+    if let Some(parent) = path.parent() {
+        crate::io::create_dir_all(parent)?;
+    }
+    let raw = serde_json::to_string_pretty(&summary)
+        .map_err(|e| miette!("JSON error serializing summary: {e}"))?;
+    crate::io::write(&path, raw)?;
+
+    print_summary(&summary);
+    println!("Summary written to {}", path.display());
+    Ok(summary)
+}
+
+fn run_one(
+    spool_path: &Path,
+    opts: &ParallelOptions,
+    change_id: &str,
+    branch: String,
+) -> ParallelLoopResult {
+    let before = iterations_so_far(spool_path, change_id);
+    let started = Instant::now();
+    let exit_code = spawn_loop(opts, change_id);
+    let after = load_state(spool_path, change_id).ok().flatten();
+
+    let iterations = after
+        .as_ref()
+        .map(|s| s.iteration.saturating_sub(before))
+        .unwrap_or(0);
+    // The child records whether it ended the loop; a promise alone may have been held back by
+    // failing gates or `--min-iterations`.
+    let complete = after.as_ref().is_some_and(|s| s.complete);
+    let outcome = match exit_code {
+        Some(0) if iterations > 0 && complete => LoopOutcome::Complete,
+        Some(0) => LoopOutcome::Incomplete,
+        _ => LoopOutcome::Failed,
+    };
+
+    ParallelLoopResult {
+        change_id: change_id.to_string(),
+        outcome,
+        exit_code,
+        iterations,
+        duration: started.elapsed().as_millis() as i64,
+        branch,
+    }
+}
+
+fn iterations_so_far(spool_path: &Path, change_id: &str) -> u32 {
+    load_state(spool_path, change_id)
+        .ok()
+        .flatten()
+        .map(|s| s.iteration)
+        .unwrap_or(0)
+}
+
+/// Run one child loop, forwarding its output with a per-change prefix.
+fn spawn_loop(opts: &ParallelOptions, change_id: &str) -> Option<i32> {
+    let prefix = format!("[{change_id}]");
+    let mut cmd = Command::new(&opts.program);
+    // Flags go before the forwarded args: a trailing prompt swallows everything after it.
+    cmd.args([
+        "ralph",
+        "--change",
+        change_id,
+        "--worktree",
+        "--no-interactive",
+    ]);
+    cmd.args(&opts.args);
+    cmd.stdin(Stdio::null());
+    cmd.stdout(Stdio::piped());
+    cmd.stderr(Stdio::piped());
+
+    let mut child = match cmd.spawn() {
+        Ok(child) => child,
+        Err(e) => {
+            eprintln!("{prefix} Failed to start loop: {e}");
+            return None;
+        }
+    };
+
+    let stdout = child.stdout.take();
+    let stderr = child.stderr.take();
+    std::thread::scope(|scope| {
+        if let Some(out) = stdout {
+            scope.spawn(|| forward_lines(out, &prefix, false));
+        }
+        if let Some(err) = stderr {
+            scope.spawn(|| forward_lines(err, &prefix, true));
+        }
+    });
+
+    match child.wait() {
+        Ok(status) => status.code(),
+        Err(e) => {
+            eprintln!("{prefix} Failed to wait for loop: {e}");
+            None
+        }
+    }
+}
+
+fn forward_lines(pipe: impl Read, prefix: &str, is_stderr: bool) {
+    for line in BufReader::new(pipe).lines() {
+        let Ok(line) = line else {
+            break;
+        };
+        if is_stderr {
+            eprintln!("{prefix} {line}");
+        } else {
+            println!("{prefix} {line}");
+        }
+    }
+}
+
+fn print_summary(summary: &ParallelSummary) {
+    println!(
+        "\n=== Ralph Summary for module {module} ===\n",
+        module = summary.module_id
+    );
+    let width = summary
+        .loops
+        .iter()
+        .map(|l| l.change_id.len())
+        .max()
+        .unwrap_or(0);
+    for l in &summary.loops {
+        let exit = l
+            .exit_code
+            .map(|c| c.to_string())
+            .unwrap_or_else(|| "-".to_string());
+        println!(
+            "  {id:<width$}  {outcome:<10}  iterations={iters}  exit={exit}  branch={branch}",
+            id = l.change_id,
+            outcome = l.outcome.as_str(),
+            iters = l.iterations,
+            branch = l.branch
+        );
+    }
+    let complete = summary
+        .loops
+        .iter()
+        .filter(|l| l.outcome == LoopOutcome::Complete)
+        .count();
+    println!(
+        "\n{complete}/{total} complete, {failed} failed",
+        total = summary.loops.len(),
+        failed = summary.failed()
+    );
+}
@@ -1,6 +1,9 @@
-use crate::validate;
+use crate::config::ConfigContext;
+use crate::{validate, workflow};
 use miette::{Result, miette};
+use serde::Serialize;
 use std::path::Path;
+use std::process::Command;
 
 pub struct BuildPromptOptions {
     pub change_id: Option<String>,
@@ -10,42 +13,106 @@ pub struct BuildPromptOptions {
     pub min_iterations: u32,
     pub completion_promise: String,
     pub context_content: Option<String>,
+    /// Feedback from the previous iteration (e.g. failed completion gates).
+    pub feedback: Option<String>,
+    /// Harness name, used to pick a `preamble.<harness>.md.j2` template variant.
+    pub harness: Option<String>,
+    /// Summary of what the previous iteration changed (see [`recent_git_history`]).
+    pub recent_history: Option<String>,
+    /// Approximate token budget for the context sections; defaults to
+    /// [`DEFAULT_CONTEXT_TOKEN_BUDGET`].
+    pub context_budget: Option<usize>,
+    /// Config context the change's schema is resolved with.
+    pub ctx: ConfigContext,
 }
 
-pub fn build_prompt_preamble(
+/// Default token budget for the change, module and history context in a Ralph prompt.
+pub const DEFAULT_CONTEXT_TOKEN_BUDGET: usize = 12_000;
+
+/// Sections shrunk below this many tokens are dropped rather than kept as a stub.
+const MIN_SECTION_TOKENS: usize = 200;
+
+const TRUNCATION_MARKER: &str = "[... truncated to fit the Ralph context budget]";
+
+/// A block of prompt context. Lower `priority` values are kept longest when the
+/// context is over budget.
+struct ContextSection {
+    priority: u8,
+    text: String,
+}
+
+/// Project-level directory (under the spool dir) for Ralph template overrides.
+const PROJECT_TEMPLATE_DIR: &str = "ralph";
+/// Preamble template name, both embedded and as a project override.
+const PREAMBLE_TEMPLATE: &str = "preamble.md.j2";
+
+#[derive(Debug, Clone, Serialize)]
+struct TaskProgressContext {
+    total: usize,
+    complete: usize,
+    shelved: usize,
+    in_progress: usize,
+    pending: usize,
+    remaining: usize,
+}
+
+/// Variables available to the preamble template.
+#[derive(Debug, Clone, Serialize)]
+struct PreambleContext<'a> {
+    change_id: Option<&'a str>,
+    module_id: Option<&'a str>,
+    harness: Option<&'a str>,
     iteration: u32,
     max_iterations: Option<u32>,
     min_iterations: u32,
-    completion_promise: &str,
-    context_content: Option<&str>,
-    task: &str,
-) -> String {
-    let has_finite_max = max_iterations.is_some_and(|v| v > 0);
-    let normalized_context = context_content.unwrap_or("").trim();
-    let context_section = if normalized_context.is_empty() {
-        String::new()
-    } else {
-        format!(
-            "\n## Additional Context (added by user mid-loop)\n\n{c}\n\n---\n",
-            c = normalized_context
-        )
-    };
+    completion_promise: &'a str,
+    context_content: Option<&'a str>,
+    feedback: Option<&'a str>,
+    user_prompt: &'a str,
+    task: &'a str,
+    tasks: Option<TaskProgressContext>,
+}
 
-    let max_str = if has_finite_max {
-        format!(" / {}", max_iterations.unwrap())
-    } else {
-        " (unlimited)".to_string()
-    };
+/// Find the preamble template: a project override (preferring a `preamble.<harness>.md.j2`
+/// variant), else the embedded default. Returns the template's name and source.
+fn load_preamble_template(spool_path: &Path, harness: Option<&str>) -> Result<(String, String)> {
+    let mut names: Vec<String> = Vec::new();
+    if let Some(h) = harness {
+        names.push(format!("preamble.{h}.md.j2"));
+    }
+    names.push(PREAMBLE_TEMPLATE.to_string());
+
+    let project_dir = spool_path.join(PROJECT_TEMPLATE_DIR);
+    for name in &names {
+        let path = project_dir.join(name);
+        if path.is_file() {
+            let source = crate::io::read_to_string(&path)?;
+            return Ok((path.display().to_string(), source));
+        }
+    }
+    let embedded = format!("ralph/{PREAMBLE_TEMPLATE}");
+    let source = spool_templates::instructions::get_instruction_template(&embedded)
+        .ok_or_else(|| miette!("Ralph preamble template not found"))?;
+    Ok((embedded, source.to_string()))
+}
 
-    format!(
-        "# Ralph Wiggum Loop - Iteration {iteration}\n\nYou are in an iterative development loop. Work on the task below until you can genuinely complete it.\n{context_section}## Your Task\n\n{task}\n\n## Instructions\n\n1. Read the current state of files to understand what's been done\n2. **Update your todo list** - Use the TodoWrite tool to track progress and plan remaining work\n3. Make progress on the task\n4. Run tests/verification if applicable\n5. When the task is GENUINELY COMPLETE, output:\n   <promise>{completion_promise}</promise>\n\n## Critical Rules\n\n- ONLY output <promise>{completion_promise}</promise> when the task is truly done\n- Do NOT lie or output false promises to exit the loop\n- If stuck, try a different approach\n- Check your work before claiming completion\n- The loop will continue until you succeed\n- **IMPORTANT**: Update your todo list at the start of each iteration to show progress\n\n## AUTONOMY REQUIREMENTS (CRITICAL)\n\n- **DO NOT ASK QUESTIONS** - This is an autonomous loop with no human interaction\n- **DO NOT USE THE QUESTION TOOL** - Work independently without prompting for input\n- Make reasonable assumptions when information is missing\n- Use your best judgment to resolve ambiguities\n- If multiple approaches exist, choose the most reasonable one and proceed\n- The orchestrator cannot respond to questions - you must be self-sufficient\n- Trust your training and make decisions autonomously\n\n## Current Iteration: {iteration}{max_str} (min: {min_iterations})\n\nNow, work on the task autonomously. Good luck!",
-        iteration = iteration,
-        context_section = context_section,
-        task = task,
-        completion_promise = completion_promise,
-        max_str = max_str,
-        min_iterations = min_iterations,
-    )
+fn task_progress(
+    spool_path: &Path,
+    change_id: Option<&str>,
+    ctx: &ConfigContext,
+) -> Option<TaskProgressContext> {
+    let change_id = change_id?;
+    let p = workflow::task_repository(spool_path, ctx)
+        .get_progress(change_id)
+        .ok()?;
+    Some(TaskProgressContext {
+        total: p.total,
+        complete: p.complete,
+        shelved: p.shelved,
+        in_progress: p.in_progress,
+        pending: p.pending,
+        remaining: p.remaining,
+    })
 }
 
 pub fn build_ralph_prompt(
@@ -53,57 +120,244 @@ pub fn build_ralph_prompt(
     user_prompt: &str,
     options: BuildPromptOptions,
 ) -> Result<String> {
-    let mut sections: Vec<String> = Vec::new();
+    let mut context: Vec<ContextSection> = Vec::new();
 
-    if let Some(change_id) = options.change_id.as_deref()
-        && let Some(ctx) = load_change_context(spool_path, change_id)?
-    {
-        sections.push(ctx);
+    if let Some(change_id) = options.change_id.as_deref() {
+        context.extend(load_change_context(spool_path, change_id, &options.ctx)?);
     }
 
     if let Some(module_id) = options.module_id.as_deref()
         && let Some(ctx) = load_module_context(spool_path, module_id)?
     {
-        sections.push(ctx);
+        context.push(ContextSection {
+            priority: 6,
+            text: ctx,
+        });
+    }
+
+    if let Some(history) = options
+        .recent_history
+        .as_deref()
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+    {
+        context.push(ContextSection {
+            priority: 5,
+            text: history.to_string(),
+        });
     }
 
+    fit_to_budget(
+        &mut context,
+        options
+            .context_budget
+            .unwrap_or(DEFAULT_CONTEXT_TOKEN_BUDGET),
+    );
+    let mut sections: Vec<String> = context.into_iter().map(|c| c.text).collect();
     sections.push(user_prompt.to_string());
     let task = sections.join("\n\n---\n\n");
+    let non_empty = |v: &Option<String>| {
+        v.as_deref()
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+            .map(str::to_string)
+    };
+    let feedback = non_empty(&options.feedback);
+    let context_content = non_empty(&options.context_content);
+
+    let Some(iteration) = options.iteration else {
+        return Ok(match feedback {
+            Some(f) => format!("{task}\n\n---\n\n{f}"),
+            None => task,
+        });
+    };
+
+    let ctx = PreambleContext {
+        change_id: options.change_id.as_deref(),
+        module_id: options.module_id.as_deref(),
+        harness: options.harness.as_deref(),
+        iteration,
+        max_iterations: options.max_iterations.filter(|v| *v > 0),
+        min_iterations: options.min_iterations,
+        completion_promise: &options.completion_promise,
+        context_content: context_content.as_deref(),
+        feedback: feedback.as_deref(),
+        user_prompt,
+        task: &task,
+        tasks: task_progress(spool_path, options.change_id.as_deref(), &options.ctx),
+    };
+    let (name, template) = load_preamble_template(spool_path, options.harness.as_deref())?;
+    let rendered = spool_templates::instructions::render_template_str(&template, &ctx)
+        .map_err(|e| miette!("Failed to render Ralph preamble {name}: {e}"))?;
+    Ok(rendered.trim().to_string())
+}
+
+/// Summarise the last commit and any uncommitted changes in `cwd` for the next prompt.
+///
+/// Returns `None` outside a git repository or when there is nothing to report.
+pub fn recent_git_history(cwd: &Path) -> Option<String> {
+    let git = |args: &[&str]| -> Option<String> {
+        let out = Command::new("git")
+            .args(args)
+            .current_dir(cwd)
+            .output()
+            .ok()?;
+        if !out.status.success() {
+            return None;
+        }
+        let text = String::from_utf8_lossy(&out.stdout).trim_end().to_string();
+        (!text.is_empty()).then_some(text)
+    };
+
+    let log = git(&["log", "-n", "5", "--oneline"])?;
+    let mut out = format!("## Recent Git History\n\nRecent commits:\n\n```\n{log}\n```\n");
+    if let Some(stat) = git(&["show", "--stat", "--format=", "HEAD"]) {
+        out.push_str(&format!(
+            "\nFiles changed by the last commit:\n\n```\n{stat}\n```\n"
+        ));
+    }
+    if let Some(stat) = git(&["diff", "--stat", "HEAD"]) {
+        out.push_str(&format!("\nUncommitted changes:\n\n```\n{stat}\n```\n"));
+    }
+    Some(out)
+}
+
+/// Rough token estimate (about four characters per token).
+fn estimate_tokens(text: &str) -> usize {
+    text.chars().count().div_ceil(4)
+}
 
-    if let Some(iteration) = options.iteration {
-        Ok(build_prompt_preamble(
-            iteration,
-            options.max_iterations,
-            options.min_iterations,
-            &options.completion_promise,
-            options.context_content.as_deref(),
-            &task,
-        )
-        .trim()
-        .to_string())
-    } else {
-        Ok(task)
+/// Shrink the lowest-priority sections first until the context fits in `budget` tokens.
+fn fit_to_budget(sections: &mut Vec<ContextSection>, budget: usize) {
+    let mut total: usize = sections.iter().map(|s| estimate_tokens(&s.text)).sum();
+    if total <= budget {
+        return;
     }
+
+    let mut order: Vec<usize> = (0..sections.len()).collect();
+    order.sort_by_key(|&i| std::cmp::Reverse((sections[i].priority, i)));
+    let mut dropped: Vec<usize> = Vec::new();
+    for i in order {
+        if total <= budget {
+            break;
+        }
+        let tokens = estimate_tokens(&sections[i].text);
+        let excess = total - budget;
+        if tokens >= excess + MIN_SECTION_TOKENS {
+            let text = truncate_to_tokens(&sections[i].text, tokens - excess);
+            total = total - tokens + estimate_tokens(&text);
+            sections[i].text = text;
+        } else {
+            total -= tokens;
+            dropped.push(i);
+        }
+    }
+
+    let mut index = 0;
+    sections.retain(|_| {
+        let keep = !dropped.contains(&index);
+        index += 1;
+        keep
+    });
+}
+
+/// Cut `text` to about `tokens` tokens (marker included), preferring a line boundary.
+fn truncate_to_tokens(text: &str, tokens: usize) -> String {
+    let max_chars = (tokens * 4).saturating_sub(TRUNCATION_MARKER.len() + 2);
+    let cut = text
+        .char_indices()
+        .nth(max_chars)
+        .map(|(i, _)| i)
+        .unwrap_or(text.len());
+    let head = &text[..cut];
+    let head = head.rfind('\n').map(|i| &head[..i]).unwrap_or(head);
+    format!("{}\n\n{TRUNCATION_MARKER}", head.trim_end())
 }
 
-fn load_change_context(spool_path: &Path, change_id: &str) -> Result<Option<String>> {
+/// Collect the change's artifacts in schema build order.
+///
+/// The proposal comes first and is kept longest; specs, design and the remaining
+/// artifacts are truncated first when over budget.
+fn load_change_context(
+    spool_path: &Path,
+    change_id: &str,
+    ctx: &ConfigContext,
+) -> Result<Vec<ContextSection>> {
     let changes_dir = crate::paths::changes_dir(spool_path);
     let resolved = resolve_change_id(&changes_dir, change_id)?;
     let Some(resolved) = resolved else {
-        return Ok(None);
+        return Ok(Vec::new());
     };
+    let change_dir = changes_dir.join(&resolved);
 
-    let proposal_path = changes_dir.join(&resolved).join("proposal.md");
-    if !proposal_path.exists() {
-        return Ok(None);
+    let schema_name = workflow::read_change_schema(spool_path, &resolved);
+    let artifacts: Vec<(String, String)> =
+        match workflow::resolve_schema(spool_path, Some(&schema_name), ctx) {
+            Ok(schema) => workflow::build_order(&schema.schema)
+                .into_iter()
+                .filter_map(|id| {
+                    schema
+                        .schema
+                        .artifacts
+                        .iter()
+                        .find(|a| a.id == id)
+                        .map(|a| (a.id.clone(), a.generates.clone()))
+                })
+                .collect(),
+            // Without a schema we still know where the proposal lives.
+            Err(_) => vec![("proposal".to_string(), "proposal.md".to_string())],
+        };
+
+    let mut out = Vec::new();
+    for (id, generates) in artifacts {
+        let files = workflow::artifact_files(&change_dir, &generates);
+        if files.is_empty() {
+            continue;
+        }
+        let mut text = format!("## Change {title} ({resolved})", title = title_case(&id));
+        for file in &files {
+            let content = crate::io::read_to_string(file)?;
+            if files.len() == 1 && !generates.contains('*') {
+                text.push_str(&format!("\n\n{content}"));
+            } else {
+                let rel = file.strip_prefix(&change_dir).unwrap_or(file);
+                text.push_str(&format!(
+                    "\n\n### {path}\n\n{content}",
+                    path = rel.display(),
+                    content = content.trim_end()
+                ));
+            }
+        }
+        out.push(ContextSection {
+            priority: artifact_priority(&id),
+            text,
+        });
     }
+    Ok(out)
+}
 
-    let proposal = crate::io::read_to_string(&proposal_path)?;
-    Ok(Some(format!(
-        "## Change Proposal ({id})\n\n{proposal}",
-        id = resolved,
-        proposal = proposal
-    )))
+fn artifact_priority(id: &str) -> u8 {
+    match id {
+        "proposal" => 0,
+        "tasks" => 1,
+        "specs" => 2,
+        "design" => 3,
+        _ => 4,
+    }
+}
+
+fn title_case(id: &str) -> String {
+    id.split(['-', '_'])
+        .filter(|w| !w.is_empty())
+        .map(|w| {
+            let mut chars = w.chars();
+            match chars.next() {
+                Some(c) => c.to_uppercase().chain(chars).collect::<String>(),
+                None => String::new(),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
 }
 
 fn resolve_change_id(changes_dir: &Path, input: &str) -> Result<Option<String>> {
@@ -1,10 +1,21 @@
+use crate::config::ConfigContext;
+use crate::ralph::cost::iteration_cost;
 use crate::ralph::duration::format_duration;
-use crate::ralph::prompt::{BuildPromptOptions, build_ralph_prompt};
+use crate::ralph::gates::{
+    CompletionGates, GATE_FEEDBACK_HEADING, check_gates, format_gate_feedback,
+};
+use crate::ralph::hooks::{HookEnv, describe_exit, format_post_hook_feedback, run_hook};
+use crate::ralph::prompt::{BuildPromptOptions, build_ralph_prompt, recent_git_history};
 use crate::ralph::state::{
-    RalphHistoryEntry, RalphState, append_context, clear_context, load_context, load_state,
-    save_state,
+    IterationMeta, IterationTranscript, RalphHistoryEntry, RalphState, append_context,
+    clear_context, clear_feedback, load_context, load_feedback, load_iteration_transcript,
+    load_state, save_feedback, save_iteration_transcript, save_state,
+};
+use crate::ralph::worktree::{
+    discard_worktree, ensure_worktree, find_worktree, merge_worktree, repo_root,
 };
 use miette::{Result, miette};
+use spool_config::types::RalphHooksConfig;
 use spool_harness::{Harness, HarnessName};
 use std::path::{Path, PathBuf};
 use std::process::Command;
@@ -28,11 +39,50 @@ pub struct RalphOptions {
     pub verbose: bool,
     /// Inactivity timeout - restart iteration if no output for this duration.
     pub inactivity_timeout: Option<Duration>,
+    /// Replay the recorded transcript of this iteration instead of running the loop.
+    pub log: Option<u32>,
+    /// Run iterations on a dedicated branch checked out in a separate git worktree.
+    pub worktree: bool,
+    /// Merge the change's Ralph worktree branch and remove the worktree.
+    pub merge: bool,
+    /// Remove the change's Ralph worktree and branch without merging.
+    pub discard: bool,
+    /// Checks that must pass, alongside the completion promise, before the loop ends.
+    pub gates: CompletionGates,
+    /// Give up after this many consecutive failed or timed-out attempts (minimum 1).
+    pub max_failures: u32,
+    /// Delay before the first retry; doubled for each further consecutive failure.
+    pub retry_backoff: Duration,
+    /// Model pricing used to cost each iteration's token usage.
+    pub pricing: Option<spool_models::Model>,
+    /// Stop once this run has spent at least this much (USD).
+    pub max_cost: Option<f64>,
+    /// Stop once this run has been going for at least this long.
+    pub max_duration: Option<Duration>,
+    /// Approximate token budget for change, module and git context in each prompt.
+    pub context_budget: Option<usize>,
+    /// Shell commands run around iterations (from `ralph.hooks` in the project config).
+    pub hooks: RalphHooksConfig,
+    /// Config context the change's schema is resolved with.
+    pub ctx: ConfigContext,
 }
 
-pub fn run_ralph(spool_path: &Path, opts: RalphOptions, harness: &mut dyn Harness) -> Result<()> {
-    let (change_id, module_id) =
-        resolve_target(spool_path, opts.change_id, opts.module_id, opts.interactive)?;
+/// Upper bound for the delay between retries.
+const MAX_RETRY_BACKOFF: Duration = Duration::from_secs(300);
+/// Number of stderr lines quoted back to the agent after a failed attempt.
+const FAILURE_OUTPUT_TAIL_LINES: usize = 20;
+
+pub fn run_ralph(
+    spool_path: &Path,
+    mut opts: RalphOptions,
+    harness: &mut dyn Harness,
+) -> Result<()> {
+    let (change_id, module_id) = resolve_target(
+        spool_path,
+        opts.change_id.take(),
+        opts.module_id.take(),
+        opts.interactive,
+    )?;
 
     if opts.status {
         let state = load_state(spool_path, &change_id)?;
@@ -40,13 +90,23 @@ pub fn run_ralph(spool_path: &Path, opts: RalphOptions, harness: &mut dyn Harnes
             println!("\n=== Ralph Status for {id} ===\n", id = state.change_id);
             println!("Iteration: {iter}", iter = state.iteration);
             println!("History entries: {n}", n = state.history.len());
+            let costs: Vec<f64> = state.history.iter().filter_map(|h| h.cost).collect();
+            if !costs.is_empty() {
+                println!("Total cost: ${:.4}", costs.iter().sum::<f64>());
+            }
             if !state.history.is_empty() {
                 println!("\nRecent iterations:");
                 let n = state.history.len();
                 let start = n.saturating_sub(5);
                 for (i, h) in state.history.iter().enumerate().skip(start) {
+                    let gates = match h.gates_passed {
+                        Some(true) => ", gates=passed",
+                        Some(false) => ", gates=failed",
+                        None => "",
+                    };
+                    let failed = if h.failed { ", failed" } else { "" };
                     println!(
-                        "  {idx}: duration={dur}ms, changes={chg}, promise={p}",
+                        "  {idx}: duration={dur}ms, changes={chg}, promise={p}{gates}{failed}",
                         idx = i + 1,
                         dur = h.duration,
                         chg = h.file_changes_count,
@@ -61,6 +121,29 @@ pub fn run_ralph(spool_path: &Path, opts: RalphOptions, harness: &mut dyn Harnes
         return Ok(());
     }
 
+    if let Some(iteration) = opts.log {
+        return print_iteration_log(spool_path, &change_id, iteration);
+    }
+
+    let invocation_dir = std::env::current_dir().unwrap_or_else(|_| PathBuf::from("."));
+    if opts.merge || opts.discard {
+        let root = repo_root(&invocation_dir)?;
+        if opts.merge {
+            let wt = merge_worktree(&root, &change_id)?;
+            println!(
+                "Merged {branch} and removed its worktree",
+                branch = wt.branch
+            );
+        } else {
+            let wt = discard_worktree(&root, &change_id)?;
+            println!(
+                "Discarded {branch} and removed its worktree",
+                branch = wt.branch
+            );
+        }
+        return Ok(());
+    }
+
     if let Some(text) = opts.add_context.as_deref() {
         append_context(spool_path, &change_id, text)?;
         println!("Added context to {id}", id = change_id);
@@ -87,13 +170,38 @@ pub fn run_ralph(spool_path: &Path, opts: RalphOptions, harness: &mut dyn Harnes
         iteration: 0,
         history: vec![],
         context_file,
+        complete: false,
     });
 
-    let max_iters = opts.max_iterations.unwrap_or(u32::MAX);
-    if max_iters == 0 {
+    if opts.max_iterations == Some(0) {
         return Err(miette!("--max-iterations must be >= 1"));
     }
 
+    let worktree = if opts.worktree {
+        let root = repo_root(&invocation_dir)?;
+        let reused = find_worktree(&root, &change_id)?.is_some();
+        let wt = ensure_worktree(&root, &change_id)?;
+        println!(
+            "{verb} worktree {path} (branch {branch})",
+            verb = if reused { "Reusing" } else { "Created" },
+            path = wt.path.display(),
+            branch = wt.branch
+        );
+        Some(wt)
+    } else {
+        None
+    };
+    let cwd = worktree
+        .as_ref()
+        .map(|wt| wt.path.clone())
+        .unwrap_or_else(|| invocation_dir.clone());
+    // Build prompts from the worktree's copy of the change when it has one.
+    let prompt_spool_path = worktree
+        .as_ref()
+        .and_then(|wt| wt.translate(&absolute(spool_path, &invocation_dir)))
+        .filter(|p| p.exists())
+        .unwrap_or_else(|| spool_path.to_path_buf());
+
     // Print startup message so user knows something is happening
     println!(
         "\n=== Starting Ralph for {change} (harness: {harness}) ===",
@@ -114,23 +222,170 @@ pub fn run_ralph(spool_path: &Path, opts: RalphOptions, harness: &mut dyn Harnes
     }
     println!();
 
-    for _ in 0..max_iters {
+    // Feedback only describes the previous iteration of this run; never carry it into a new one.
+    clear_feedback(spool_path, &change_id)?;
+    // Likewise, only this run can mark the loop complete.
+    state.complete = false;
+    save_state(spool_path, &change_id, &state)?;
+
+    let target = LoopTarget {
+        spool_path,
+        prompt_spool_path: &prompt_spool_path,
+        cwd: &cwd,
+        change_id: &change_id,
+        module_id: &module_id,
+    };
+    let mut last_exit_code: Option<i32> = None;
+    // The completion/failure hooks run after the loop, however it ended.
+    let outcome = run_iterations(&target, &opts, harness, &mut state, &mut last_exit_code);
+    if let Ok(true) = outcome {
+        state.complete = true;
+        save_state(spool_path, &change_id, &state)?;
+    }
+
+    let hook_env = |promise_found: Option<bool>, error: Option<String>| HookEnv {
+        change_id: &change_id,
+        module_id: &module_id,
+        iteration: state.iteration,
+        exit_code: last_exit_code,
+        promise_found,
+        error,
+    };
+    let final_hook = match &outcome {
+        Ok(true) => opts
+            .hooks
+            .on_complete
+            .as_deref()
+            .map(|c| ("on_complete", c, hook_env(Some(true), None))),
+        Err(e) => opts
+            .hooks
+            .on_failure
+            .as_deref()
+            .map(|c| ("on_failure", c, hook_env(None, Some(e.to_string())))),
+        Ok(false) => None,
+    };
+    if let Some((name, command, env)) = final_hook {
+        // The loop's own result matters more than the hook's; only report hook problems.
+        match run_hook(name, command, &env, &cwd) {
+            Ok(hook) if !hook.success => {
+                eprintln!("Warning: {name} hook failed ({})", describe_exit(&hook));
+            }
+            Ok(_) => {}
+            Err(e) => eprintln!("Warning: {e}"),
+        }
+    }
+    outcome?;
+
+    if let Some(wt) = &worktree {
+        println!(
+            "Ralph worked on branch {branch} in {path}.",
+            branch = wt.branch,
+            path = wt.path.display()
+        );
+        println!(
+            "Run `spool ralph --change {change_id} --merge` to merge it, or `--discard` to drop it."
+        );
+    }
+
+    Ok(())
+}
+
+/// Where a loop's iterations run and which change they work on.
+#[derive(Clone, Copy)]
+struct LoopTarget<'a> {
+    spool_path: &'a Path,
+    /// Spool dir prompts are built from (the worktree's copy when there is one).
+    prompt_spool_path: &'a Path,
+    cwd: &'a Path,
+    change_id: &'a str,
+    module_id: &'a str,
+}
+
+/// Run loop iterations until the completion promise (with passing gates) ends the loop,
+/// returning `Ok(true)`, or until an iteration or budget limit stops it, returning `Ok(false)`.
+///
+/// `last_exit_code` records the harness's most recent exit code for the final hooks.
+fn run_iterations(
+    target: &LoopTarget<'_>,
+    opts: &RalphOptions,
+    harness: &mut dyn Harness,
+    state: &mut RalphState,
+    last_exit_code: &mut Option<i32>,
+) -> Result<bool> {
+    let LoopTarget {
+        spool_path,
+        prompt_spool_path,
+        cwd,
+        change_id,
+        module_id,
+    } = *target;
+    let max_iters = opts.max_iterations.unwrap_or(u32::MAX);
+    let max_failures = opts.max_failures.max(1);
+    let mut completed: u32 = 0;
+    let mut consecutive_failures: u32 = 0;
+    let loop_started = std::time::Instant::now();
+    let mut spent = 0.0f64;
+    let mut warned_unpriced = false;
+
+    while completed < max_iters {
         let iteration = state.iteration.saturating_add(1);
 
         println!("\n=== Ralph Loop Iteration {i} ===\n", i = iteration);
 
-        let context_content = load_context(spool_path, &change_id)?;
+        if let Some(command) = &opts.hooks.pre_iteration {
+            let hook = run_hook(
+                "pre_iteration",
+                command,
+                &HookEnv {
+                    change_id,
+                    module_id,
+                    iteration,
+                    ..HookEnv::default()
+                },
+                cwd,
+            )?;
+            if !hook.success {
+                consecutive_failures += 1;
+                if consecutive_failures >= max_failures {
+                    return Err(miette!(
+                        "pre_iteration hook failed {n} time(s) in a row ({status})",
+                        n = consecutive_failures,
+                        status = describe_exit(&hook)
+                    ));
+                }
+                let delay = retry_delay(opts.retry_backoff, consecutive_failures);
+                println!(
+                    "\n=== pre_iteration hook failed ({status}); skipping iteration {iteration}, retrying in {delay} (failure {n}/{max}) ===\n",
+                    status = describe_exit(&hook),
+                    delay = format_duration(delay),
+                    n = consecutive_failures,
+                    max = max_failures
+                );
+                std::thread::sleep(delay);
+                continue;
+            }
+        }
+
+        let context_content = load_context(spool_path, change_id)?;
+        let feedback = load_feedback(spool_path, change_id)?;
         let prompt = build_ralph_prompt(
-            spool_path,
+            prompt_spool_path,
             &opts.prompt,
             BuildPromptOptions {
-                change_id: Some(change_id.clone()),
-                module_id: Some(module_id.clone()),
+                change_id: Some(change_id.to_string()),
+                module_id: Some(module_id.to_string()),
                 iteration: Some(iteration),
                 max_iterations: opts.max_iterations,
                 min_iterations: opts.min_iterations,
                 completion_promise: opts.completion_promise.clone(),
                 context_content: Some(context_content),
+                feedback: Some(feedback.clone()),
+                harness: Some(harness.name().0.to_string()),
+                recent_history: (state.iteration > 0)
+                    .then(|| recent_git_history(cwd))
+                    .flatten(),
+                context_budget: opts.context_budget,
+                ctx: opts.ctx.clone(),
             },
         )?;
 
@@ -142,13 +397,14 @@ pub fn run_ralph(spool_path: &Path, opts: RalphOptions, harness: &mut dyn Harnes
 
         let started = std::time::Instant::now();
         let run = harness.run(&spool_harness::HarnessRunConfig {
-            prompt,
+            prompt: prompt.clone(),
             model: opts.model.clone(),
-            cwd: std::env::current_dir().unwrap_or_else(|_| PathBuf::from(".")),
+            cwd: cwd.to_path_buf(),
             env: std::collections::BTreeMap::new(),
             interactive: opts.interactive && !opts.allow_all,
             inactivity_timeout: opts.inactivity_timeout,
         })?;
+        *last_exit_code = Some(run.exit_code);
 
         // Pass through output if harness didn't already stream it
         if !harness.streams_output() {
@@ -164,29 +420,177 @@ pub fn run_ralph(spool_path: &Path, opts: RalphOptions, harness: &mut dyn Harnes
         let needle = format!("<promise>{}</promise>", opts.completion_promise);
         let completion_found = run.stdout.contains(&needle);
 
+        let cost = run
+            .usage
+            .as_ref()
+            .and_then(|u| iteration_cost(opts.pricing.as_ref(), u));
+        spent += cost.unwrap_or(0.0);
+        if opts.max_cost.is_some() && cost.is_none() && !warned_unpriced {
+            warned_unpriced = true;
+            let reason = if run.usage.is_none() {
+                format!(
+                    "harness '{name}' reported no token usage",
+                    name = harness.name().0
+                )
+            } else {
+                "no pricing is known for the model".to_string()
+            };
+            eprintln!("Warning: --max-cost is set but {reason}; the cost limit is not enforced");
+        }
+        if let Some(usage) = &run.usage {
+            let cost_str = cost
+                .map(|c| format!("${c:.4} (run total ${spent:.4})"))
+                .unwrap_or_else(|| "unknown".to_string());
+            println!(
+                "Tokens: {input} in / {output} out / {cached} cached, cost {cost_str}",
+                input = usage.input_tokens,
+                output = usage.output_tokens,
+                cached = usage.cache_read_tokens
+            );
+        }
+        let limit_reached = budget_exceeded(
+            opts.max_cost,
+            opts.max_duration,
+            spent,
+            loop_started.elapsed(),
+        );
+
         let file_changes_count = if harness.name() == HarnessName::OPENCODE {
-            count_git_changes()? as u32
+            count_git_changes(cwd)? as u32
         } else {
             0
         };
 
-        // Handle timeout - log and continue to next iteration
-        if run.timed_out {
-            println!("\n=== Inactivity timeout reached. Restarting iteration... ===\n");
-            // Don't update state for timed out iterations, just retry
-            continue;
+        let transcript_dir = save_iteration_transcript(
+            spool_path,
+            change_id,
+            &IterationTranscript {
+                meta: IterationMeta {
+                    iteration,
+                    timestamp: now_ms()?,
+                    duration: started.elapsed().as_millis() as i64,
+                    exit_code: run.exit_code,
+                    timed_out: run.timed_out,
+                    completion_promise_found: completion_found,
+                },
+                prompt,
+                stdout: run.stdout.clone(),
+                stderr: run.stderr.clone(),
+                git_diff_stat: git_diff_stat(cwd),
+                previous_attempts: 0,
+            },
+        )?;
+        if opts.verbose {
+            println!("Transcript saved to {}", transcript_dir.display());
         }
 
-        if run.exit_code != 0 {
-            return Err(miette!(
-                "Harness '{name}' exited with code {code}",
-                name = harness.name().0,
-                code = run.exit_code
-            ));
+        if run.timed_out || run.exit_code != 0 {
+            consecutive_failures += 1;
+            state.history.push(RalphHistoryEntry {
+                timestamp: now_ms()?,
+                duration: started.elapsed().as_millis() as i64,
+                completion_promise_found: completion_found,
+                file_changes_count,
+                gates_passed: None,
+                tokens: run.usage.clone(),
+                cost,
+                failed: true,
+            });
+            save_state(spool_path, change_id, state)?;
+            let mut text = format_run_failure(&run);
+            // Keep unresolved gate failures (e.g. failing verify output) in view.
+            if let Some(idx) = feedback.find(GATE_FEEDBACK_HEADING) {
+                text.push('\n');
+                text.push_str(&feedback[idx..]);
+            }
+            save_feedback(spool_path, change_id, &text)?;
+            let reason = if run.timed_out {
+                "inactivity timeout reached".to_string()
+            } else {
+                format!("exit code {code}", code = run.exit_code)
+            };
+            if let Some(reason) = &limit_reached {
+                println!("\n=== {reason}. Stopping. ===\n");
+                break;
+            }
+            if consecutive_failures >= max_failures {
+                return Err(if run.timed_out {
+                    miette!(
+                        "Harness '{name}' timed out {n} time(s) in a row",
+                        name = harness.name().0,
+                        n = consecutive_failures
+                    )
+                } else {
+                    miette!(
+                        "Harness '{name}' exited with code {code} ({n} consecutive failure(s))",
+                        name = harness.name().0,
+                        code = run.exit_code,
+                        n = consecutive_failures
+                    )
+                });
+            }
+            let delay = retry_delay(opts.retry_backoff, consecutive_failures);
+            println!(
+                "\n=== Iteration {iteration} failed ({reason}); retrying in {delay} (failure {n}/{max}) ===\n",
+                delay = format_duration(delay),
+                n = consecutive_failures,
+                max = max_failures
+            );
+            // Failed attempts don't advance the iteration; the next attempt reuses its number.
+            std::thread::sleep(delay);
+            continue;
+        }
+        consecutive_failures = 0;
+
+        // Feedback for the next iteration; cleared when nothing needs reporting.
+        let mut next_feedback: Vec<String> = Vec::new();
+        if let Some(command) = &opts.hooks.post_iteration {
+            let hook = run_hook(
+                "post_iteration",
+                command,
+                &HookEnv {
+                    change_id,
+                    module_id,
+                    iteration,
+                    exit_code: Some(run.exit_code),
+                    promise_found: Some(completion_found),
+                    error: None,
+                },
+                cwd,
+            )?;
+            if !hook.success {
+                println!(
+                    "\n=== post_iteration hook failed ({status}); reporting it to the next iteration ===",
+                    status = describe_exit(&hook)
+                );
+                next_feedback.push(format_post_hook_feedback(command, &hook));
+            }
         }
 
         if !opts.no_commit {
-            commit_iteration(iteration)?;
+            commit_iteration(cwd, iteration)?;
+        }
+
+        let gates_passed = if opts.gates.is_empty() {
+            None
+        } else {
+            let failures = check_gates(prompt_spool_path, change_id, &opts.gates, cwd, &opts.ctx)?;
+            if failures.is_empty() {
+                println!("\n=== Completion gates passed ===");
+                Some(true)
+            } else {
+                println!("\n=== Completion gates failed ===");
+                for f in &failures {
+                    println!("- {gate}: {detail}", gate = f.gate, detail = f.detail);
+                }
+                next_feedback.insert(0, format_gate_feedback(&failures));
+                Some(false)
+            }
+        };
+        if next_feedback.is_empty() {
+            clear_feedback(spool_path, change_id)?;
+        } else {
+            save_feedback(spool_path, change_id, &next_feedback.join("\n"))?;
         }
 
         let timestamp = now_ms()?;
@@ -196,19 +600,143 @@ pub fn run_ralph(spool_path: &Path, opts: RalphOptions, harness: &mut dyn Harnes
             duration,
             completion_promise_found: completion_found,
             file_changes_count,
+            gates_passed,
+            tokens: run.usage.clone(),
+            cost,
+            failed: false,
         });
         state.iteration = iteration;
-        save_state(spool_path, &change_id, &state)?;
+        save_state(spool_path, change_id, state)?;
+        completed += 1;
 
-        if completion_found && iteration >= opts.min_iterations {
+        if completion_found && gates_passed == Some(false) {
+            println!("Completion promise ignored until the completion gates pass.");
+        } else if completion_found && iteration >= opts.min_iterations {
             println!(
                 "\n=== Completion promise \"{p}\" detected. Loop complete. ===\n",
                 p = opts.completion_promise
             );
-            return Ok(());
+            return Ok(true);
+        }
+        if let Some(reason) = &limit_reached {
+            println!("\n=== {reason}. Stopping. ===\n");
+            break;
+        }
+    }
+    Ok(false)
+}
+
+/// Describe the first `--max-cost` / `--max-duration` limit this run has hit, if any.
+fn budget_exceeded(
+    max_cost: Option<f64>,
+    max_duration: Option<Duration>,
+    spent: f64,
+    elapsed: Duration,
+) -> Option<String> {
+    if let Some(max) = max_cost
+        && spent >= max
+    {
+        return Some(format!("Cost limit reached (${spent:.4} of ${max:.2})"));
+    }
+    if let Some(max) = max_duration
+        && elapsed >= max
+    {
+        return Some(format!(
+            "Duration limit reached ({} of {})",
+            format_duration(elapsed),
+            format_duration(max)
+        ));
+    }
+    None
+}
+
+/// Delay before the next attempt after `failures` consecutive failures.
+fn retry_delay(base: Duration, failures: u32) -> Duration {
+    let factor = 1u32 << failures.saturating_sub(1).min(16);
+    base.saturating_mul(factor).min(MAX_RETRY_BACKOFF)
+}
+
+/// Prompt section describing a failed attempt so the agent can correct course.
+fn format_run_failure(run: &spool_harness::HarnessRunResult) -> String {
+    let mut out = String::from("## Previous Iteration Failed\n\n");
+    if run.timed_out {
+        out.push_str(
+            "The previous attempt was stopped after producing no output for too long. Avoid long-running or interactive commands.\n",
+        );
+    } else {
+        out.push_str(&format!(
+            "The previous attempt exited with code {code}.\n",
+            code = run.exit_code
+        ));
+    }
+    let (label, output) = if run.stderr.trim().is_empty() {
+        ("stdout", run.stdout.as_str())
+    } else {
+        ("stderr", run.stderr.as_str())
+    };
+    let lines: Vec<&str> = output.trim_end().lines().collect();
+    if !lines.is_empty() {
+        let tail = &lines[lines.len().saturating_sub(FAILURE_OUTPUT_TAIL_LINES)..];
+        out.push_str(&format!(
+            "\nLast {n} line(s) of {label}:\n\n```\n{tail}\n```\n",
+            n = tail.len(),
+            tail = tail.join("\n")
+        ));
+    }
+    out
+}
+
+fn absolute(path: &Path, base: &Path) -> PathBuf {
+    let joined = if path.is_absolute() {
+        path.to_path_buf()
+    } else {
+        base.join(path)
+    };
+    joined.canonicalize().unwrap_or(joined)
+}
+
+fn print_iteration_log(spool_path: &Path, change_id: &str, iteration: u32) -> Result<()> {
+    let Some(t) = load_iteration_transcript(spool_path, change_id, iteration)? else {
+        return Err(miette!(
+            "No transcript recorded for iteration {iteration} of {change_id}"
+        ));
+    };
+
+    println!("\n=== Ralph Iteration {iteration} for {change_id} ===\n");
+    println!("Exit code: {}", t.meta.exit_code);
+    println!("Timed out: {}", if t.meta.timed_out { "yes" } else { "no" });
+    println!(
+        "Duration: {}",
+        format_duration(Duration::from_millis(t.meta.duration.max(0) as u64))
+    );
+    println!(
+        "Completion promise: {}",
+        if t.meta.completion_promise_found {
+            "found"
+        } else {
+            "not found"
         }
+    );
+    if t.previous_attempts > 0 {
+        println!(
+            "Earlier attempts (failed or timed out): {n}",
+            n = t.previous_attempts
+        );
     }
 
+    let sections = [
+        ("Prompt", Some(t.prompt.as_str())),
+        ("Stdout", Some(t.stdout.as_str())),
+        ("Stderr", Some(t.stderr.as_str())),
+        ("Git diff stat", t.git_diff_stat.as_deref()),
+    ];
+    for (title, body) in sections {
+        println!("\n--- {title} ---");
+        match body {
+            Some(b) if !b.trim().is_empty() => println!("{}", b.trim_end()),
+            _ => println!("(empty)"),
+        }
+    }
     Ok(())
 }
 
@@ -242,7 +770,8 @@ fn resolve_target(
             ));
         }
         return Err(miette!(
-            "Interactive selection is not yet implemented in Rust. Use --change to specify."
+            "Multiple changes found for module {module}. Use --change to specify.",
+            module = module
         ));
     }
 
@@ -253,7 +782,7 @@ fn resolve_target(
     }
 
     Err(miette!(
-        "Interactive selection is not yet implemented in Rust. Use --change to specify."
+        "Change selection requires an interactive terminal. Use --change to specify."
     ))
 }
 
@@ -278,9 +807,10 @@ fn now_ms() -> Result<i64> {
     Ok(dur.as_millis() as i64)
 }
 
-fn count_git_changes() -> Result<usize> {
+fn count_git_changes(cwd: &Path) -> Result<usize> {
     let out = Command::new("git")
         .args(["status", "--porcelain"])
+        .current_dir(cwd)
         .output()
         .map_err(|e| miette!("Failed to run git status: {e}"))?;
     if !out.status.success() {
@@ -295,9 +825,31 @@ fn count_git_changes() -> Result<usize> {
     Ok(s.lines().filter(|l| !l.trim().is_empty()).count())
 }
 
-fn commit_iteration(iteration: u32) -> Result<()> {
+/// `git diff --stat HEAD` for the iteration's transcript; `None` outside a git work tree.
+fn git_diff_stat(cwd: &Path) -> Option<String> {
+    let inside = Command::new("git")
+        .args(["rev-parse", "--is-inside-work-tree"])
+        .current_dir(cwd)
+        .output()
+        .ok()?;
+    if !inside.status.success() {
+        return None;
+    }
+    let out = Command::new("git")
+        .args(["diff", "--stat", "HEAD"])
+        .current_dir(cwd)
+        .output()
+        .ok()?;
+    if !out.status.success() {
+        return None;
+    }
+    Some(String::from_utf8_lossy(&out.stdout).to_string())
+}
+
+fn commit_iteration(cwd: &Path, iteration: u32) -> Result<()> {
     let status = Command::new("git")
         .args(["add", "-A"])
+        .current_dir(cwd)
         .status()
         .map_err(|e| miette!("Failed to run git add: {e}"))?;
     if !status.success() {
@@ -307,9 +859,46 @@ fn commit_iteration(iteration: u32) -> Result<()> {
     let msg = format!("Ralph loop iteration {iteration}");
     let status = Command::new("git")
         .args(["commit", "-m", &msg])
+        .current_dir(cwd)
         .status()
         .map_err(|e| miette!("Failed to run git commit: {e}"))?;
     // TS ignores commit failures due to no changes; mimic by allowing non-zero.
     let _ = status;
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn git(repo: &Path, args: &[&str]) {
+        let status = Command::new("git")
+            .args(args)
+            .current_dir(repo)
+            .status()
+            .unwrap();
+        assert!(status.success(), "git {args:?} failed");
+    }
+
+    #[test]
+    fn git_diff_stat_is_none_outside_a_work_tree() {
+        let td = tempfile::tempdir().unwrap();
+        assert_eq!(git_diff_stat(td.path()), None);
+    }
+
+    #[test]
+    fn git_diff_stat_reports_uncommitted_changes() {
+        let td = tempfile::tempdir().unwrap();
+        let repo = td.path();
+        git(repo, &["init", "-q"]);
+        git(repo, &["config", "user.name", "Test"]);
+        git(repo, &["config", "user.email", "test@example.com"]);
+        std::fs::write(repo.join("a.txt"), "one\n").unwrap();
+        git(repo, &["add", "-A"]);
+        git(repo, &["commit", "-q", "-m", "initial"]);
+        std::fs::write(repo.join("a.txt"), "two\n").unwrap();
+
+        let stat = git_diff_stat(repo).unwrap();
+        assert!(stat.contains("a.txt"), "{stat}");
+    }
+}
@@ -1,5 +1,6 @@
 use miette::{Result, miette};
 use serde::{Deserialize, Serialize};
+use spool_harness::TokenUsage;
 use std::path::{Path, PathBuf};
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -9,6 +10,19 @@ pub struct RalphHistoryEntry {
     pub duration: i64,
     pub completion_promise_found: bool,
     pub file_changes_count: u32,
+    /// Whether the configured completion gates passed (absent when no gates are configured).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gates_passed: Option<bool>,
+    /// Token usage reported by the harness for this iteration.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tokens: Option<TokenUsage>,
+    /// Cost of this iteration in USD, when it could be priced.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cost: Option<f64>,
+    /// A failed or timed-out attempt that was retried; it doesn't count as an iteration but
+    /// its cost still counts towards the total.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub failed: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -18,6 +32,10 @@ pub struct RalphState {
     pub iteration: u32,
     pub history: Vec<RalphHistoryEntry>,
     pub context_file: String,
+    /// Whether the most recent run ended the loop: completion promise found, gates passed and
+    /// `--min-iterations` reached.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub complete: bool,
 }
 
 pub fn ralph_state_dir(spool_path: &Path, change_id: &str) -> PathBuf {
@@ -88,3 +106,148 @@ pub fn clear_context(spool_path: &Path, change_id: &str) -> Result<()> {
     crate::io::write(&p, "")?;
     Ok(())
 }
+
+pub fn ralph_feedback_path(spool_path: &Path, change_id: &str) -> PathBuf {
+    ralph_state_dir(spool_path, change_id).join("feedback.md")
+}
+
+/// Feedback about the previous iteration (a failed run or failed completion gates) that is
+/// injected into the next prompt. Empty when the previous iteration went cleanly.
+pub fn load_feedback(spool_path: &Path, change_id: &str) -> Result<String> {
+    let p = ralph_feedback_path(spool_path, change_id);
+    Ok(crate::io::read_to_string_optional(&p)?.unwrap_or_default())
+}
+
+/// Replace the feedback for the next iteration.
+pub fn save_feedback(spool_path: &Path, change_id: &str, text: &str) -> Result<()> {
+    let dir = ralph_state_dir(spool_path, change_id);
+    crate::io::create_dir_all(&dir)?;
+    let p = ralph_feedback_path(spool_path, change_id);
+    crate::io::write(&p, text)?;
+    Ok(())
+}
+
+pub fn clear_feedback(spool_path: &Path, change_id: &str) -> Result<()> {
+    let p = ralph_feedback_path(spool_path, change_id);
+    if p.exists() {
+        crate::io::write(&p, "")?;
+    }
+    Ok(())
+}
+
+/// Outcome of one harness run, stored as `meta.json` next to the iteration transcript.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct IterationMeta {
+    pub iteration: u32,
+    pub timestamp: i64,
+    pub duration: i64,
+    pub exit_code: i32,
+    pub timed_out: bool,
+    pub completion_promise_found: bool,
+}
+
+/// Everything a single Ralph iteration sent to and received from the harness.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IterationTranscript {
+    pub meta: IterationMeta,
+    pub prompt: String,
+    pub stdout: String,
+    pub stderr: String,
+    /// `git diff --stat` for the working tree after the run, when available.
+    pub git_diff_stat: Option<String>,
+    /// Earlier attempts of the same iteration that failed or timed out and were retried.
+    pub previous_attempts: u32,
+}
+
+const ITERATION_META_FILE: &str = "meta.json";
+const ITERATION_PROMPT_FILE: &str = "prompt.md";
+const ITERATION_STDOUT_FILE: &str = "stdout.log";
+const ITERATION_STDERR_FILE: &str = "stderr.log";
+const ITERATION_DIFF_STAT_FILE: &str = "diff-stat.txt";
+const ITERATION_FILES: [&str; 5] = [
+    ITERATION_META_FILE,
+    ITERATION_PROMPT_FILE,
+    ITERATION_STDOUT_FILE,
+    ITERATION_STDERR_FILE,
+    ITERATION_DIFF_STAT_FILE,
+];
+
+pub fn ralph_iterations_dir(spool_path: &Path, change_id: &str) -> PathBuf {
+    ralph_state_dir(spool_path, change_id).join("iterations")
+}
+
+pub fn ralph_iteration_dir(spool_path: &Path, change_id: &str, iteration: u32) -> PathBuf {
+    ralph_iterations_dir(spool_path, change_id).join(iteration.to_string())
+}
+
+fn count_previous_attempts(dir: &Path) -> u32 {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return 0;
+    };
+    entries
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_name().to_string_lossy().starts_with("attempt-"))
+        .count() as u32
+}
+
+/// Persist an iteration transcript under `.state/ralph/<change>/iterations/<n>/`.
+///
+/// If a transcript for the same iteration already exists (a timed-out attempt that was
+/// retried), it is moved into `attempt-<k>/` inside the iteration directory first.
+pub fn save_iteration_transcript(
+    spool_path: &Path,
+    change_id: &str,
+    transcript: &IterationTranscript,
+) -> Result<PathBuf> {
+    let dir = ralph_iteration_dir(spool_path, change_id, transcript.meta.iteration);
+    crate::io::create_dir_all(&dir)?;
+
+    if dir.join(ITERATION_META_FILE).exists() {
+        let attempt = dir.join(format!("attempt-{}", count_previous_attempts(&dir) + 1));
+        crate::io::create_dir_all(&attempt)?;
+        for name in ITERATION_FILES {
+            let src = dir.join(name);
+            if src.exists() {
+                std::fs::rename(&src, attempt.join(name))
+                    .map_err(|e| miette!("I/O error moving {p}: {e}", p = src.display()))?;
+            }
+        }
+    }
+
+    let meta = serde_json::to_string_pretty(&transcript.meta)
+        .map_err(|e| miette!("JSON error serializing iteration meta: {e}"))?;
+    crate::io::write(&dir.join(ITERATION_META_FILE), meta)?;
+    crate::io::write(&dir.join(ITERATION_PROMPT_FILE), &transcript.prompt)?;
+    crate::io::write(&dir.join(ITERATION_STDOUT_FILE), &transcript.stdout)?;
+    crate::io::write(&dir.join(ITERATION_STDERR_FILE), &transcript.stderr)?;
+    if let Some(stat) = &transcript.git_diff_stat {
+        crate::io::write(&dir.join(ITERATION_DIFF_STAT_FILE), stat)?;
+    }
+    Ok(dir)
+}
+
+/// Load the latest attempt of an iteration transcript, if one was recorded.
+pub fn load_iteration_transcript(
+    spool_path: &Path,
+    change_id: &str,
+    iteration: u32,
+) -> Result<Option<IterationTranscript>> {
+    let dir = ralph_iteration_dir(spool_path, change_id, iteration);
+    let meta_path = dir.join(ITERATION_META_FILE);
+    if !meta_path.exists() {
+        return Ok(None);
+    }
+    let raw = crate::io::read_to_string(&meta_path)?;
+    let meta = serde_json::from_str(&raw)
+        .map_err(|e| miette!("JSON error parsing {p}: {e}", p = meta_path.display()))?;
+    let read = |name: &str| crate::io::read_to_string_optional(&dir.join(name));
+    Ok(Some(IterationTranscript {
+        meta,
+        prompt: read(ITERATION_PROMPT_FILE)?.unwrap_or_default(),
+        stdout: read(ITERATION_STDOUT_FILE)?.unwrap_or_default(),
+        stderr: read(ITERATION_STDERR_FILE)?.unwrap_or_default(),
+        git_diff_stat: read(ITERATION_DIFF_STAT_FILE)?,
+        previous_attempts: count_previous_attempts(&dir),
+    }))
+}
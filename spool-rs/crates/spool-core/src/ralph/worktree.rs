@@ -0,0 +1,165 @@
+//! Dedicated git worktrees for Ralph loops.
+//!
+//! With `--worktree`, each change gets its own branch (`spool/ralph/<change>`) checked out
+//! in a worktree stored under the repository's git directory, so the loop can commit freely
+//! without touching the user's checkout. When the loop is done the branch is merged back or
+//! discarded.
+
+use miette::{Result, miette};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// A change's Ralph branch and the worktree it is checked out in.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RalphWorktree {
+    pub repo_root: PathBuf,
+    pub branch: String,
+    pub path: PathBuf,
+}
+
+impl RalphWorktree {
+    /// Map a path inside the main checkout to the same path inside the worktree.
+    pub fn translate(&self, path: &Path) -> Option<PathBuf> {
+        let rel = path.strip_prefix(&self.repo_root).ok()?;
+        Some(self.path.join(rel))
+    }
+}
+
+pub fn branch_name(change_id: &str) -> String {
+    format!("spool/ralph/{change_id}")
+}
+
+fn git(cwd: &Path, args: &[&str]) -> Result<String> {
+    let out = Command::new("git")
+        .args(args)
+        .current_dir(cwd)
+        .output()
+        .map_err(|e| miette!("Failed to run git {}: {e}", args.join(" ")))?;
+    if !out.status.success() {
+        let err = String::from_utf8_lossy(&out.stderr);
+        return Err(miette!("git {} failed: {}", args.join(" "), err.trim()));
+    }
+    Ok(String::from_utf8_lossy(&out.stdout).trim().to_string())
+}
+
+/// Top-level directory of the git repository containing `cwd`.
+pub fn repo_root(cwd: &Path) -> Result<PathBuf> {
+    let root = git(cwd, &["rev-parse", "--show-toplevel"])
+        .map_err(|_| miette!("--worktree requires a git repository ({})", cwd.display()))?;
+    let root = PathBuf::from(root);
+    Ok(root.canonicalize().unwrap_or(root))
+}
+
+fn worktree_path(repo_root: &Path, change_id: &str) -> Result<PathBuf> {
+    let common = PathBuf::from(git(repo_root, &["rev-parse", "--git-common-dir"])?);
+    let common = if common.is_absolute() {
+        common
+    } else {
+        repo_root.join(common)
+    };
+    Ok(common.join("spool-worktrees").join(change_id))
+}
+
+fn branch_exists(repo_root: &Path, branch: &str) -> bool {
+    git(
+        repo_root,
+        &[
+            "rev-parse",
+            "--verify",
+            "--quiet",
+            &format!("refs/heads/{branch}"),
+        ],
+    )
+    .is_ok()
+}
+
+/// Look up the worktree for a change without creating it.
+pub fn find_worktree(repo_root: &Path, change_id: &str) -> Result<Option<RalphWorktree>> {
+    let path = worktree_path(repo_root, change_id)?;
+    if !path.exists() {
+        return Ok(None);
+    }
+    Ok(Some(RalphWorktree {
+        repo_root: repo_root.to_path_buf(),
+        branch: branch_name(change_id),
+        path,
+    }))
+}
+
+/// Create (or reuse) the branch and worktree for a change.
+pub fn ensure_worktree(repo_root: &Path, change_id: &str) -> Result<RalphWorktree> {
+    if let Some(existing) = find_worktree(repo_root, change_id)? {
+        return Ok(existing);
+    }
+
+    let branch = branch_name(change_id);
+    let path = worktree_path(repo_root, change_id)?;
+    if let Some(parent) = path.parent() {
+        crate::io::create_dir_all(parent)?;
+    }
+    let path_str = path.to_string_lossy().to_string();
+    if branch_exists(repo_root, &branch) {
+        git(repo_root, &["worktree", "add", &path_str, &branch])?;
+    } else {
+        git(
+            repo_root,
+            &["worktree", "add", "-b", &branch, &path_str, "HEAD"],
+        )?;
+    }
+
+    Ok(RalphWorktree {
+        repo_root: repo_root.to_path_buf(),
+        branch,
+        path,
+    })
+}
+
+/// Refuse to drop a worktree that still holds uncommitted work (e.g. from a loop run with
+/// `--no-commit` or interrupted before its commit).
+fn ensure_clean(wt: &RalphWorktree) -> Result<()> {
+    let status = git(&wt.path, &["status", "--porcelain"])?;
+    if status.is_empty() {
+        return Ok(());
+    }
+    Err(miette!(
+        "Ralph worktree {} has uncommitted changes; commit or remove them there first",
+        wt.path.display()
+    ))
+}
+
+/// Remove the worktree, then its branch: `-d` once merged so git double-checks nothing is
+/// lost, `-D` when discarding the branch on purpose.
+fn remove_worktree(wt: &RalphWorktree, merged: bool) -> Result<()> {
+    let path_str = wt.path.to_string_lossy().to_string();
+    git(&wt.repo_root, &["worktree", "remove", &path_str])?;
+    let delete = if merged { "-d" } else { "-D" };
+    git(&wt.repo_root, &["branch", delete, &wt.branch])?;
+    Ok(())
+}
+
+/// Merge the change's Ralph branch into the current branch of the main checkout, then
+/// remove the worktree and branch. Fails if the worktree has uncommitted changes.
+pub fn merge_worktree(repo_root: &Path, change_id: &str) -> Result<RalphWorktree> {
+    let Some(wt) = find_worktree(repo_root, change_id)? else {
+        return Err(miette!("No Ralph worktree found for {change_id}"));
+    };
+    ensure_clean(&wt)?;
+    let msg = format!("Merge Ralph loop for {change_id}");
+    git(
+        repo_root,
+        &["merge", "--no-ff", "--no-edit", "-m", &msg, &wt.branch],
+    )?;
+    remove_worktree(&wt, true)?;
+    Ok(wt)
+}
+
+/// Remove the change's Ralph worktree and branch without merging. Fails if the worktree
+/// has uncommitted changes.
+pub fn discard_worktree(repo_root: &Path, change_id: &str) -> Result<RalphWorktree> {
+    let Some(wt) = find_worktree(repo_root, change_id)? else {
+        return Err(miette!("No Ralph worktree found for {change_id}"));
+    };
+    ensure_clean(&wt)?;
+    remove_worktree(&wt, false)?;
+    Ok(wt)
+}
@@ -0,0 +1,117 @@
+use std::path::Path;
+
+use miette::Result;
+use spool_domain::tasks as domain_tasks;
+
+use crate::config::ConfigContext;
+use crate::workflow;
+
+use super::{
+    ValidationIssue, ValidationReport, error, validate_change, warning, with_line, with_metadata,
+};
+
+/// Everything `spool validate <change>` checks for one change: its delta specs, the repo
+/// integrity of its directory, its task tracking files and the staleness of its artifacts.
+pub fn validate_change_all(
+    spool_path: &Path,
+    change_id: &str,
+    strict: bool,
+    ctx: &ConfigContext,
+) -> Result<ValidationReport> {
+    let mut issues = validate_change(spool_path, change_id, strict)?.issues;
+    let repo_integrity = super::validate_change_dirs_repo_integrity(spool_path).unwrap_or_default();
+    if let Some(extra) = repo_integrity.get(change_id) {
+        issues.extend(extra.clone());
+    }
+    issues.extend(validate_tracking_files(spool_path, change_id, ctx));
+    issues.extend(validate_artifact_staleness(spool_path, change_id, ctx));
+    Ok(ValidationReport::new(issues, strict))
+}
+
+fn validate_tracking_files(
+    spool_path: &Path,
+    change_id: &str,
+    ctx: &ConfigContext,
+) -> Vec<ValidationIssue> {
+    workflow::change_tracked_files(spool_path, change_id, ctx)
+        .iter()
+        .flat_map(|file| validate_tracking_file(spool_path, change_id, file))
+        .collect()
+}
+
+fn validate_tracking_file(spool_path: &Path, change_id: &str, file: &str) -> Vec<ValidationIssue> {
+    let path = domain_tasks::tracked_file_path(spool_path, change_id, file);
+    if !path.exists() {
+        return Vec::new();
+    }
+
+    let report_path = format!(".spool/changes/{change_id}/{file}");
+
+    let contents = match crate::io::read_to_string(&path) {
+        Ok(c) => c,
+        Err(e) => {
+            return vec![error(
+                &report_path,
+                format!("Failed to read {report_path}: {e}"),
+            )];
+        }
+    };
+
+    let parsed = domain_tasks::parse_tasks_tracking_file(&contents);
+    let mut issues = Vec::new();
+    for d in parsed.diagnostics {
+        let mut issue = match d.level {
+            domain_tasks::DiagnosticLevel::Error => error(&report_path, d.message),
+            domain_tasks::DiagnosticLevel::Warning => warning(&report_path, d.message),
+        };
+        if let Some(line) = d.line {
+            issue = with_line(issue, line as u32);
+        }
+
+        if let Some(task_id) = d.task_id {
+            issue = with_metadata(
+                issue,
+                serde_json::json!({
+                    "taskId": task_id,
+                }),
+            );
+        }
+
+        issues.push(issue);
+    }
+    issues
+}
+
+/// Warn about artifacts whose upstream artifacts were edited after them.
+fn validate_artifact_staleness(
+    spool_path: &Path,
+    change_id: &str,
+    ctx: &ConfigContext,
+) -> Vec<ValidationIssue> {
+    let Ok(status) = workflow::compute_change_status(spool_path, change_id, None, ctx) else {
+        return Vec::new();
+    };
+    let mut issues = Vec::new();
+    for a in status.artifacts.iter().filter(|a| a.stale) {
+        let path = match a.files.as_slice() {
+            [only] => format!(".spool/changes/{change_id}/{}", only.path),
+            _ => format!(".spool/changes/{change_id}/{}", a.output_path),
+        };
+        let issue = warning(
+            &path,
+            format!(
+                "Artifact '{}' may be stale: {} modified after it",
+                a.id,
+                a.stale_against.join(", ")
+            ),
+        );
+        issues.push(with_metadata(
+            issue,
+            serde_json::json!({
+                "artifactId": a.id,
+                "staleAgainst": a.stale_against,
+            }),
+        ));
+    }
+    issues
+}
@@ -7,13 +7,17 @@ use crate::show::{
     DeltaSpecFile, load_delta_spec_file, parse_change_show_json, parse_spec_show_json,
 };
 
+mod change;
 mod issue;
 mod repo_integrity;
 mod report;
+mod schema;
 
+pub use change::validate_change_all;
 pub use issue::{error, info, issue, warning, with_line, with_loc, with_metadata};
 pub use repo_integrity::validate_change_dirs_repo_integrity;
 pub use report::{ReportBuilder, report};
+pub use schema::validate_schema;
 
 pub type ValidationLevel = &'static str;
 
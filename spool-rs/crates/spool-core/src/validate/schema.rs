@@ -0,0 +1,252 @@
+//! Lint artifact schemas (`spool schema validate`).
+//!
+//! Issues point at the `schema.yaml` that declares the offending artifact, with the line of
+//! its `id:` entry, so problems in an inherited layer are reported against that layer's file.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+
+use miette::{Result, miette};
+
+use super::{ValidationReport, error, report, warning, with_line, with_loc};
+use crate::config::ConfigContext;
+use crate::workflow::{self, ResolvedSchema, SchemaLayerYaml};
+
+/// Check a schema (and the layers it extends) for structural problems.
+pub fn validate_schema(
+    spool_path: &Path,
+    schema_name: &str,
+    ctx: &ConfigContext,
+    strict: bool,
+) -> Result<ValidationReport> {
+    let Some((schema_dir, _source)) = workflow::find_schema_dir(spool_path, schema_name, ctx)
+    else {
+        return Err(miette!("Schema '{schema_name}' not found"));
+    };
+    let file = schema_dir.join("schema.yaml");
+    let path = display_path(spool_path, &file);
+    let text = crate::io::read_to_string(&file)?;
+    let mut r = report(strict);
+
+    let layer: SchemaLayerYaml = match serde_yaml::from_str(&text) {
+        Ok(layer) => layer,
+        Err(e) => {
+            let issue = error(&path, format!("Invalid schema YAML: {e}"));
+            r.push(match e.location() {
+                Some(loc) => with_loc(issue, loc.line() as u32, loc.column() as u32),
+                None => issue,
+            });
+            return Ok(r.finish());
+        }
+    };
+
+    // Checks on the layer as written; flattening would merge or reject these silently.
+    let mut layer_errors = false;
+    let mut seen: BTreeMap<&str, usize> = BTreeMap::new();
+    for a in &layer.artifacts {
+        let n = seen.entry(a.id.as_str()).or_default();
+        // Which occurrence of this id the entry is, to point at its own `id:` line.
+        let idx = *n;
+        *n += 1;
+        if idx >= 1 {
+            let line = artifact_lines(&text, &a.id).get(idx).copied();
+            r.push(at_line(
+                error(&path, format!("Duplicate artifact id '{}'", a.id)),
+                line,
+            ));
+            layer_errors = true;
+        }
+        if layer.extends.is_none() && (a.generates.is_none() || a.template.is_none()) {
+            let line = artifact_lines(&text, &a.id).get(idx).copied();
+            r.push(at_line(
+                error(
+                    &path,
+                    format!("Artifact '{}' needs `generates` and `template`", a.id),
+                ),
+                line,
+            ));
+            layer_errors = true;
+        }
+    }
+
+    let resolved = match workflow::resolve_schema_layers(spool_path, Some(schema_name), ctx) {
+        Ok(resolved) => resolved,
+        Err(e) => {
+            if !layer_errors {
+                r.push(error(&path, e.to_string()));
+            }
+            return Ok(r.finish());
+        }
+    };
+
+    let mut files = LayerFiles::default();
+    let schema = &resolved.schema;
+    let ids: BTreeSet<&str> = schema.artifacts.iter().map(|a| a.id.as_str()).collect();
+
+    for a in &schema.artifacts {
+        let (apath, line) = files.locate(spool_path, &resolved, &a.id);
+        for req in &a.requires {
+            if !ids.contains(req.as_str()) {
+                r.push(at_line(
+                    error(
+                        &apath,
+                        format!("Artifact '{}' requires unknown artifact '{req}'", a.id),
+                    ),
+                    line,
+                ));
+            }
+        }
+        if !workflow::generates_pattern_supported(&a.generates) {
+            r.push(at_line(
+                error(
+                    &apath,
+                    format!(
+                        "Artifact '{}' has unsupported generates pattern '{}'",
+                        a.id, a.generates
+                    ),
+                ),
+                line,
+            ));
+        }
+        let template = resolved.template_path(a);
+        if !template.is_file() {
+            r.push(at_line(
+                error(
+                    &apath,
+                    format!(
+                        "Template for artifact '{}' not found: {}",
+                        a.id,
+                        display_path(spool_path, &template)
+                    ),
+                ),
+                line,
+            ));
+        }
+    }
+
+    // Unknown requires are reported above; leave them out so they don't look like cycles.
+    let mut known = schema.clone();
+    for a in &mut known.artifacts {
+        a.requires.retain(|req| ids.contains(req.as_str()));
+    }
+    let ordered: BTreeSet<String> = workflow::build_order(&known).into_iter().collect();
+    let cyclic: Vec<&str> = known
+        .artifacts
+        .iter()
+        .map(|a| a.id.as_str())
+        .filter(|id| !ordered.contains(*id))
+        .collect();
+    if !cyclic.is_empty() {
+        let (cpath, line) = files.locate(spool_path, &resolved, cyclic[0]);
+        r.push(at_line(
+            error(
+                &cpath,
+                format!("Dependency cycle between artifacts: {}", cyclic.join(", ")),
+            ),
+            line,
+        ));
+    }
+
+    if let Some(apply) = &schema.apply {
+        let line = find_line(&text, |l| l.trim_end() == "apply:");
+        for req in apply.requires.iter().flatten() {
+            if !ids.contains(req.as_str()) {
+                r.push(at_line(
+                    error(
+                        &path,
+                        format!("apply.requires names unknown artifact '{req}'"),
+                    ),
+                    line,
+                ));
+            }
+        }
+        for tracks in &apply.tracks_files() {
+            if tracks.contains('*')
+                || !spool_domain::tasks::is_valid_tracked_file(tracks)
+                || !workflow::generates_pattern_supported(tracks)
+            {
+                r.push(at_line(
+                    error(
+                        &path,
+                        format!("apply.tracks must be a relative file path, got '{tracks}'"),
+                    ),
+                    line,
+                ));
+            } else if !schema.artifacts.iter().any(|a| &a.generates == tracks) {
+                r.push(at_line(
+                    warning(
+                        &path,
+                        format!("apply.tracks '{tracks}' is not generated by any artifact"),
+                    ),
+                    line,
+                ));
+            }
+        }
+    }
+
+    Ok(r.finish())
+}
+
+/// Cached `schema.yaml` contents of the layers a resolved schema was built from.
+#[derive(Default)]
+struct LayerFiles {
+    texts: BTreeMap<PathBuf, String>,
+}
+
+impl LayerFiles {
+    /// File and `id:` line of the layer that last defined or overrode an artifact.
+    fn locate(
+        &mut self,
+        spool_path: &Path,
+        resolved: &ResolvedSchema,
+        artifact_id: &str,
+    ) -> (String, Option<u32>) {
+        let dir = resolved
+            .artifact_origins
+            .get(artifact_id)
+            .map(|o| o.schema_dir.clone())
+            .unwrap_or_else(|| resolved.schema_dir.clone());
+        let file = dir.join("schema.yaml");
+        let text = self
+            .texts
+            .entry(file.clone())
+            .or_insert_with(|| crate::io::read_to_string_std(&file).unwrap_or_default());
+        let line = artifact_lines(text, artifact_id).first().copied();
+        (display_path(spool_path, &file), line)
+    }
+}
+
+fn at_line(issue: super::ValidationIssue, line: Option<u32>) -> super::ValidationIssue {
+    match line {
+        Some(line) => with_line(issue, line),
+        None => issue,
+    }
+}
+
+/// 1-based lines holding `id: <artifact_id>` (optionally as a `- id:` list item).
+fn artifact_lines(text: &str, artifact_id: &str) -> Vec<u32> {
+    text.lines()
+        .enumerate()
+        .filter(|(_, l)| {
+            l.trim_start()
+                .trim_start_matches('-')
+                .trim_start()
+                .strip_prefix("id:")
+                .is_some_and(|v| v.trim().trim_matches(['"', '\'']) == artifact_id)
+        })
+        .map(|(idx, _)| idx as u32 + 1)
+        .collect()
+}
+
+fn find_line(text: &str, pred: impl Fn(&str) -> bool) -> Option<u32> {
+    text.lines().position(pred).map(|idx| idx as u32 + 1)
+}
+
+/// Show project files relative to the project root; others in full.
+fn display_path(spool_path: &Path, file: &Path) -> String {
+    let root = spool_path.parent().unwrap_or(spool_path);
+    file.strip_prefix(root)
+        .unwrap_or(file)
+        .to_string_lossy()
+        .to_string()
+}
@@ -0,0 +1,226 @@
+//! Schema inheritance (`extends`).
+//!
+//! A schema layer may extend another schema by name and then adjust its artifacts:
+//!
+//! ```yaml
+//! name: team
+//! extends: spec-driven
+//! remove: [design]
+//! order: [proposal, specs, tasks, runbook]
+//! artifacts:
+//!   - id: tasks                # override individual fields
+//!     instruction: |
+//!       ...
+//!   - id: runbook              # add a new artifact
+//!     generates: runbook.md
+//!     template: runbook.md
+//!     requires: [tasks]
+//! apply:                       # replaces the parent's apply block
+//!   requires: [tasks]
+//! ```
+//!
+//! Layers are flattened into a plain [`SchemaYaml`]; each artifact remembers which layers
+//! defined or overrode it and which schema directory holds its template.
+
+use super::{ApplyYaml, ArtifactYaml, SchemaYaml, WorkflowError};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// Where a flattened artifact came from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArtifactOrigin {
+    /// Schemas that defined the artifact and then overrode it, base first.
+    pub layers: Vec<String>,
+    /// Schema directory whose `templates/` holds the artifact's template.
+    pub template_dir: PathBuf,
+    /// Schema directory of the last layer that defined or overrode the artifact.
+    pub schema_dir: PathBuf,
+}
+
+/// One `schema.yaml` as written, before inheritance is applied.
+#[derive(Debug, Clone, Deserialize)]
+pub(crate) struct SchemaLayerYaml {
+    pub name: String,
+    #[serde(default)]
+    pub version: Option<u32>,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub extends: Option<String>,
+    #[serde(default)]
+    pub artifacts: Vec<ArtifactLayerYaml>,
+    /// Parent artifacts to drop.
+    #[serde(default)]
+    pub remove: Vec<String>,
+    /// Artifact ids to list first, in this order; unlisted artifacts keep their order after them.
+    #[serde(default)]
+    pub order: Vec<String>,
+    #[serde(default)]
+    pub apply: Option<ApplyYaml>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub(crate) struct ArtifactLayerYaml {
+    pub id: String,
+    #[serde(default)]
+    pub generates: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub template: Option<String>,
+    #[serde(default)]
+    pub instruction: Option<String>,
+    #[serde(default)]
+    pub requires: Option<Vec<String>>,
+}
+
+pub(crate) fn load_schema_layer(schema_dir: &Path) -> Result<SchemaLayerYaml, WorkflowError> {
+    let s = crate::io::read_to_string_std(&schema_dir.join("schema.yaml"))?;
+    Ok(serde_yaml::from_str(&s)?)
+}
+
+fn invalid(name: &str, message: impl Into<String>) -> WorkflowError {
+    WorkflowError::InvalidSchema {
+        name: name.to_string(),
+        message: message.into(),
+    }
+}
+
+/// Turn a layer without `extends` into a schema.
+pub(crate) fn flatten_base(
+    layer: SchemaLayerYaml,
+    schema_dir: &Path,
+) -> Result<(SchemaYaml, BTreeMap<String, ArtifactOrigin>), WorkflowError> {
+    let empty = SchemaYaml {
+        name: layer.name.clone(),
+        version: None,
+        description: None,
+        extends: None,
+        artifacts: Vec::new(),
+        apply: None,
+    };
+    let (mut schema, origins) = apply_layer(empty, BTreeMap::new(), layer, schema_dir)?;
+    schema.extends = None;
+    Ok((schema, origins))
+}
+
+/// Apply a layer on top of an already flattened parent schema.
+pub(crate) fn apply_layer(
+    parent: SchemaYaml,
+    mut origins: BTreeMap<String, ArtifactOrigin>,
+    layer: SchemaLayerYaml,
+    schema_dir: &Path,
+) -> Result<(SchemaYaml, BTreeMap<String, ArtifactOrigin>), WorkflowError> {
+    let name = layer.name.clone();
+    let mut artifacts = parent.artifacts;
+
+    for id in &layer.remove {
+        let before = artifacts.len();
+        artifacts.retain(|a| &a.id != id);
+        if artifacts.len() == before {
+            return Err(invalid(
+                &name,
+                format!("cannot remove unknown artifact '{id}'"),
+            ));
+        }
+        origins.remove(id);
+    }
+
+    for a in layer.artifacts {
+        if let Some(existing) = artifacts.iter_mut().find(|e| e.id == a.id) {
+            let origin = origins
+                .entry(a.id.clone())
+                .or_insert_with(|| ArtifactOrigin {
+                    layers: Vec::new(),
+                    template_dir: schema_dir.to_path_buf(),
+                    schema_dir: schema_dir.to_path_buf(),
+                });
+            if let Some(v) = a.generates {
+                existing.generates = v;
+            }
+            if let Some(v) = a.description {
+                existing.description = Some(v);
+            }
+            if let Some(v) = a.template {
+                existing.template = v;
+                origin.template_dir = schema_dir.to_path_buf();
+            }
+            if let Some(v) = a.instruction {
+                existing.instruction = Some(v);
+            }
+            if let Some(v) = a.requires {
+                existing.requires = v;
+            }
+            origin.layers.push(name.clone());
+            origin.schema_dir = schema_dir.to_path_buf();
+            continue;
+        }
+
+        let (Some(generates), Some(template)) = (a.generates, a.template) else {
+            return Err(invalid(
+                &name,
+                format!(
+                    "artifact '{id}' needs `generates` and `template`",
+                    id = a.id
+                ),
+            ));
+        };
+        origins.insert(
+            a.id.clone(),
+            ArtifactOrigin {
+                layers: vec![name.clone()],
+                template_dir: schema_dir.to_path_buf(),
+                schema_dir: schema_dir.to_path_buf(),
+            },
+        );
+        artifacts.push(ArtifactYaml {
+            id: a.id,
+            generates,
+            description: a.description,
+            template,
+            instruction: a.instruction,
+            requires: a.requires.unwrap_or_default(),
+        });
+    }
+
+    if !layer.order.is_empty() {
+        let mut ordered = Vec::with_capacity(artifacts.len());
+        for id in &layer.order {
+            let Some(idx) = artifacts.iter().position(|a| &a.id == id) else {
+                return Err(invalid(
+                    &name,
+                    format!("cannot order unknown artifact '{id}'"),
+                ));
+            };
+            ordered.push(artifacts.remove(idx));
+        }
+        ordered.append(&mut artifacts);
+        artifacts = ordered;
+    }
+
+    let schema = SchemaYaml {
+        extends: layer.extends,
+        version: layer.version.or(parent.version),
+        description: layer.description.or(parent.description),
+        artifacts,
+        apply: layer.apply.or(parent.apply),
+        name,
+    };
+    Ok((schema, origins))
+}
+
+/// Fail on the first `requires` entry that names no artifact of the flattened schema.
+pub(crate) fn check_requires(schema: &SchemaYaml) -> Result<(), WorkflowError> {
+    for a in &schema.artifacts {
+        for r in &a.requires {
+            if !schema.artifacts.iter().any(|other| &other.id == r) {
+                return Err(invalid(
+                    &schema.name,
+                    format!("artifact '{id}' requires unknown artifact '{r}'", id = a.id),
+                ));
+            }
+        }
+    }
+    Ok(())
+}
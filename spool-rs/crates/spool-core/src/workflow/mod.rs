@@ -1,11 +1,20 @@
 use crate::config::ConfigContext;
 use serde::{Deserialize, Serialize};
+use spool_domain::changes::ChangeRepository;
+use spool_domain::tasks::TaskRepository;
 use spool_templates::SPOOL_END_MARKER;
 use std::collections::{BTreeMap, BTreeSet};
 use std::env;
 use std::fs;
 use std::path::{Path, PathBuf};
 
+mod inherit;
+mod scaffold;
+
+pub use inherit::ArtifactOrigin;
+pub(crate) use inherit::SchemaLayerYaml;
+pub use scaffold::{render_schema_preview, scaffold_schema};
+
 #[derive(Debug, thiserror::Error)]
 pub enum WorkflowError {
     #[error("Invalid change name")]
@@ -23,6 +32,12 @@ pub enum WorkflowError {
     #[error("Artifact '{0}' not found")]
     ArtifactNotFound(String),
 
+    #[error("Schema directory already exists: {0}")]
+    SchemaExists(String),
+
+    #[error("Invalid schema '{name}': {message}")]
+    InvalidSchema { name: String, message: String },
+
     #[error(transparent)]
     Io(#[from] std::io::Error),
 
@@ -38,6 +53,25 @@ pub struct ArtifactStatus {
     pub status: String,
     #[serde(rename = "missingDeps", skip_serializing_if = "Vec::is_empty")]
     pub missing_deps: Vec<String>,
+    /// Files matched by the artifact's `generates` pattern, sorted by path.
+    pub files: Vec<ArtifactFile>,
+    /// Most recent modification time across `files` (RFC 3339, UTC).
+    #[serde(rename = "lastModified", skip_serializing_if = "Option::is_none")]
+    pub last_modified: Option<String>,
+    /// Whether an upstream artifact (direct or transitive `requires`) changed after this one.
+    pub stale: bool,
+    /// Upstream artifacts modified after this one, in build order.
+    #[serde(rename = "staleAgainst", skip_serializing_if = "Vec::is_empty")]
+    pub stale_against: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ArtifactFile {
+    /// Path relative to the change directory, using `/` separators.
+    pub path: String,
+    /// Last modification time (RFC 3339, UTC), when the filesystem reports one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub modified: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -83,6 +117,8 @@ pub struct InstructionsResponse {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub instruction: Option<String>,
     pub template: String,
+    /// Schema layers that defined or overrode the artifact, base first.
+    pub layers: Vec<String>,
     pub dependencies: Vec<DependencyInfo>,
     pub unlocks: Vec<String>,
 }
@@ -131,6 +167,10 @@ pub struct ApplyInstructionsResponse {
     pub tracks_format: Option<String>,
     #[serde(rename = "tracksDiagnostics", skip_serializing_if = "Option::is_none")]
     pub tracks_diagnostics: Option<Vec<TaskDiagnostic>>,
+    /// Per-file details when the schema tracks more than one file. `tracksFile`,
+    /// `tracksPath` and `tracksFormat` then describe the first of them.
+    #[serde(rename = "trackedFiles", skip_serializing_if = "Vec::is_empty")]
+    pub tracked_files: Vec<TrackedFileInfo>,
     pub state: String,
     #[serde(rename = "contextFiles")]
     pub context_files: BTreeMap<String, String>,
@@ -141,6 +181,15 @@ pub struct ApplyInstructionsResponse {
     pub instruction: String,
 }
 
+#[derive(Debug, Clone, Serialize)]
+pub struct TrackedFileInfo {
+    pub file: String,
+    pub path: String,
+    pub exists: bool,
+    pub format: Option<String>,
+    pub progress: ProgressInfo,
+}
+
 #[derive(Debug, Clone, Serialize)]
 pub struct AgentInstructionResponse {
     #[serde(rename = "artifactId")]
@@ -152,6 +201,8 @@ pub struct AgentInstructionResponse {
 pub enum SchemaSource {
     Package,
     User,
+    /// Checked into the repository under `<spoolDir>/schemas/`.
+    Project,
 }
 
 impl SchemaSource {
@@ -159,52 +210,98 @@ impl SchemaSource {
         match self {
             SchemaSource::Package => "package",
             SchemaSource::User => "user",
+            SchemaSource::Project => "project",
         }
     }
 }
 
 #[derive(Debug, Clone)]
 pub struct ResolvedSchema {
+    /// The flattened schema, with any `extends` chain applied.
     pub schema: SchemaYaml,
     pub schema_dir: PathBuf,
     pub source: SchemaSource,
+    /// Per-artifact layer history and template location.
+    pub artifact_origins: BTreeMap<String, ArtifactOrigin>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+impl ResolvedSchema {
+    /// Path of an artifact's template, in whichever layer last set it.
+    pub fn template_path(&self, artifact: &ArtifactYaml) -> PathBuf {
+        self.artifact_origins
+            .get(&artifact.id)
+            .map(|o| o.template_dir.as_path())
+            .unwrap_or(self.schema_dir.as_path())
+            .join("templates")
+            .join(&artifact.template)
+    }
+
+    /// Schema layers that defined or overrode an artifact, base first.
+    pub fn artifact_layers(&self, artifact_id: &str) -> Vec<String> {
+        self.artifact_origins
+            .get(artifact_id)
+            .map(|o| o.layers.clone())
+            .unwrap_or_else(|| vec![self.schema.name.clone()])
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SchemaYaml {
     pub name: String,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub version: Option<u32>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
+    /// Name of the schema this one was layered on, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub extends: Option<String>,
     pub artifacts: Vec<ArtifactYaml>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub apply: Option<ApplyYaml>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ArtifactYaml {
     pub id: String,
     pub generates: String,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
     pub template: String,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub instruction: Option<String>,
     #[serde(default)]
     pub requires: Vec<String>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ApplyYaml {
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub requires: Option<Vec<String>>,
-    #[serde(default)]
-    pub tracks: Option<String>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tracks: Option<TracksYaml>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub instruction: Option<String>,
 }
 
+impl ApplyYaml {
+    /// Tracking files named by `tracks`, in order (empty when the schema tracks none).
+    pub fn tracks_files(&self) -> Vec<String> {
+        match &self.tracks {
+            None => Vec::new(),
+            Some(TracksYaml::One(f)) => vec![f.clone()],
+            Some(TracksYaml::Many(fs)) => fs.clone(),
+        }
+    }
+}
+
+/// `apply.tracks`: a single tracking file or a list of them, each with separate progress.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum TracksYaml {
+    One(String),
+    Many(Vec<String>),
+}
+
 pub fn default_schema_name() -> &'static str {
     "spec-driven"
 }
@@ -241,13 +338,56 @@ pub fn read_change_schema(spool_path: &Path, change: &str) -> String {
     default_schema_name().to_string()
 }
 
+/// Task tracking files of a change, relative to its directory: the `tracks` override in
+/// its `.spool.yaml`, else its schema's `apply.tracks`, else `tasks.md`.
+pub fn change_tracked_files(spool_path: &Path, change: &str, ctx: &ConfigContext) -> Vec<String> {
+    let schema_name = read_change_schema(spool_path, change);
+    let schema = resolve_schema(spool_path, Some(&schema_name), ctx).ok();
+    let files = change_tracks(spool_path, change, schema.as_ref());
+    if files.is_empty() {
+        return vec!["tasks.md".to_string()];
+    }
+    files
+}
+
+/// Task repository that finds each change's tracking files through its schema (see
+/// [`change_tracked_files`]), so `spool tasks`, listings and apply instructions agree.
+pub fn task_repository<'a>(spool_path: &'a Path, ctx: &'a ConfigContext) -> TaskRepository<'a> {
+    TaskRepository::new(spool_path)
+        .with_tracks(move |p, change| change_tracked_files(p, change, ctx))
+}
+
+/// Change repository whose task progress follows each change's schema (see
+/// [`task_repository`]).
+pub fn change_repository<'a>(spool_path: &'a Path, ctx: &'a ConfigContext) -> ChangeRepository<'a> {
+    ChangeRepository::new(spool_path)
+        .with_tracks(move |p, change| change_tracked_files(p, change, ctx))
+}
+
+/// Tracking files of a change under `schema`: its `.spool.yaml` override, else the
+/// schema's `apply.tracks` (empty when neither names any).
+fn change_tracks(spool_path: &Path, change: &str, schema: Option<&ResolvedSchema>) -> Vec<String> {
+    if let Some(files) = spool_domain::tasks::tracks_override(spool_path, change) {
+        return files;
+    }
+    let files = schema
+        .and_then(|r| r.schema.apply.as_ref())
+        .map(ApplyYaml::tracks_files)
+        .unwrap_or_default();
+    spool_domain::tasks::normalize_tracked_files(files)
+}
+
 pub fn list_available_changes(spool_path: &Path) -> Vec<String> {
     crate::discovery::list_change_dir_names(spool_path).unwrap_or_default()
 }
 
-pub fn list_available_schemas(ctx: &ConfigContext) -> Vec<String> {
+pub fn list_available_schemas(spool_path: &Path, ctx: &ConfigContext) -> Vec<String> {
     let mut set: BTreeSet<String> = BTreeSet::new();
-    for dir in [Some(package_schemas_dir()), user_schemas_dir(ctx)] {
+    for dir in [
+        Some(package_schemas_dir()),
+        user_schemas_dir(ctx),
+        Some(project_schemas_dir(spool_path)),
+    ] {
         let Some(dir) = dir else { continue };
         let Ok(names) = crate::discovery::list_dir_names(&dir) else {
             continue;
@@ -262,34 +402,87 @@ pub fn list_available_schemas(ctx: &ConfigContext) -> Vec<String> {
     set.into_iter().collect()
 }
 
+/// Find a schema by name: project schemas first, then the user's, then the packaged ones.
+///
+/// A schema that `extends` another is flattened onto its parent. A parent with the same name
+/// as its child resolves to the next layer down, so a project `spec-driven` can extend the
+/// packaged `spec-driven`.
 pub fn resolve_schema(
+    spool_path: &Path,
+    schema_name: Option<&str>,
+    ctx: &ConfigContext,
+) -> Result<ResolvedSchema, WorkflowError> {
+    let resolved = resolve_schema_layers(spool_path, schema_name, ctx)?;
+    inherit::check_requires(&resolved.schema)?;
+    Ok(resolved)
+}
+
+/// Like [`resolve_schema`], without checking that `requires` ids exist (for linting).
+pub(crate) fn resolve_schema_layers(
+    spool_path: &Path,
     schema_name: Option<&str>,
     ctx: &ConfigContext,
 ) -> Result<ResolvedSchema, WorkflowError> {
     let name = schema_name.unwrap_or(default_schema_name());
-    let user_dir = user_schemas_dir(ctx).map(|d| d.join(name));
-    if let Some(d) = user_dir
-        && d.join("schema.yaml").exists()
-    {
-        let schema = load_schema_yaml(&d)?;
-        return Ok(ResolvedSchema {
-            schema,
-            schema_dir: d,
-            source: SchemaSource::User,
-        });
-    }
+    resolve_schema_chain(spool_path, name, ctx, &mut Vec::new())
+}
 
-    let pkg = package_schemas_dir().join(name);
-    if pkg.join("schema.yaml").exists() {
-        let schema = load_schema_yaml(&pkg)?;
-        return Ok(ResolvedSchema {
-            schema,
-            schema_dir: pkg,
-            source: SchemaSource::Package,
-        });
+/// Directory and source `resolve_schema` would load a schema's top layer from.
+pub fn find_schema_dir(
+    spool_path: &Path,
+    schema_name: &str,
+    ctx: &ConfigContext,
+) -> Option<(PathBuf, SchemaSource)> {
+    schema_candidates(spool_path, schema_name, ctx)
+        .into_iter()
+        .next()
+}
+
+fn schema_candidates(
+    spool_path: &Path,
+    name: &str,
+    ctx: &ConfigContext,
+) -> Vec<(PathBuf, SchemaSource)> {
+    let mut out = vec![(
+        project_schemas_dir(spool_path).join(name),
+        SchemaSource::Project,
+    )];
+    if let Some(d) = user_schemas_dir(ctx) {
+        out.push((d.join(name), SchemaSource::User));
     }
+    out.push((package_schemas_dir().join(name), SchemaSource::Package));
+    out.retain(|(dir, _)| dir.join("schema.yaml").exists());
+    out
+}
 
-    Err(WorkflowError::SchemaNotFound(name.to_string()))
+fn resolve_schema_chain(
+    spool_path: &Path,
+    name: &str,
+    ctx: &ConfigContext,
+    visited: &mut Vec<PathBuf>,
+) -> Result<ResolvedSchema, WorkflowError> {
+    let Some((schema_dir, source)) = schema_candidates(spool_path, name, ctx)
+        .into_iter()
+        .find(|(dir, _)| !visited.contains(dir))
+    else {
+        return Err(WorkflowError::SchemaNotFound(name.to_string()));
+    };
+    visited.push(schema_dir.clone());
+
+    let layer = inherit::load_schema_layer(&schema_dir)?;
+    let (schema, artifact_origins) = match layer.extends.clone() {
+        None => inherit::flatten_base(layer, &schema_dir)?,
+        Some(parent_name) => {
+            let parent = resolve_schema_chain(spool_path, &parent_name, ctx, visited)?;
+            inherit::apply_layer(parent.schema, parent.artifact_origins, layer, &schema_dir)?
+        }
+    };
+    Ok(ResolvedSchema {
+        schema,
+        schema_dir,
+        source,
+        artifact_origins,
+    })
 }
 
 pub fn compute_change_status(
@@ -304,7 +497,7 @@ pub fn compute_change_status(
     let schema_name = schema_name
         .map(|s| s.to_string())
         .unwrap_or_else(|| read_change_schema(spool_path, change));
-    let resolved = resolve_schema(Some(&schema_name), ctx)?;
+    let resolved = resolve_schema(spool_path, Some(&schema_name), ctx)?;
 
     let change_dir = crate::paths::change_dir(spool_path, change);
     if !change_dir.exists() {
@@ -314,13 +507,34 @@ pub fn compute_change_status(
     let mut artifacts_out: Vec<ArtifactStatus> = Vec::new();
     let mut done_count: usize = 0;
     let done_by_id = compute_done_by_id(&change_dir, &resolved.schema);
+    let mut modified_by_id: BTreeMap<String, std::time::SystemTime> = BTreeMap::new();
 
     let order = build_order(&resolved.schema);
-    for id in order {
-        let Some(a) = resolved.schema.artifacts.iter().find(|a| a.id == id) else {
+    for id in &order {
+        let Some(a) = resolved.schema.artifacts.iter().find(|a| &a.id == id) else {
             continue;
         };
         let done = *done_by_id.get(&a.id).unwrap_or(&false);
+        let (files, latest) = artifact_file_details(&change_dir, &a.generates);
+        if let Some(latest) = latest {
+            modified_by_id.insert(a.id.clone(), latest);
+        }
+        let stale_against: Vec<String> = match latest {
+            Some(latest) if done => {
+                let upstream = upstream_artifacts(&resolved.schema, &a.id);
+                order
+                    .iter()
+                    .filter(|u| upstream.contains(*u))
+                    .filter(|u| {
+                        modified_by_id
+                            .get(*u)
+                            .is_some_and(|t| modified_after(*t, latest))
+                    })
+                    .cloned()
+                    .collect()
+            }
+            _ => Vec::new(),
+        };
         let mut missing: Vec<String> = Vec::new();
         if !done {
             for r in &a.requires {
@@ -343,6 +557,10 @@ pub fn compute_change_status(
             output_path: a.generates.clone(),
             status,
             missing_deps: missing,
+            files,
+            last_modified: latest.map(format_modified),
+            stale: !stale_against.is_empty(),
+            stale_against,
         });
     }
 
@@ -370,7 +588,7 @@ pub fn compute_change_status(
     })
 }
 
-fn build_order(schema: &SchemaYaml) -> Vec<String> {
+pub(crate) fn build_order(schema: &SchemaYaml) -> Vec<String> {
     // Match TS ArtifactGraph.getBuildOrder (Kahn's algorithm with deterministic sorting
     // of roots + newlyReady only).
     let mut in_degree: std::collections::HashMap<String, usize> = std::collections::HashMap::new();
@@ -421,11 +639,11 @@ fn build_order(schema: &SchemaYaml) -> Vec<String> {
 }
 
 pub fn resolve_templates(
+    spool_path: &Path,
     schema_name: Option<&str>,
     ctx: &ConfigContext,
 ) -> Result<(String, BTreeMap<String, TemplateInfo>), WorkflowError> {
-    let resolved = resolve_schema(schema_name, ctx)?;
-    let templates_dir = resolved.schema_dir.join("templates");
+    let resolved = resolve_schema(spool_path, schema_name, ctx)?;
 
     let mut templates: BTreeMap<String, TemplateInfo> = BTreeMap::new();
     for a in &resolved.schema.artifacts {
@@ -433,10 +651,7 @@ pub fn resolve_templates(
             a.id.clone(),
             TemplateInfo {
                 source: resolved.source.as_str().to_string(),
-                path: templates_dir
-                    .join(&a.template)
-                    .to_string_lossy()
-                    .to_string(),
+                path: resolved.template_path(a).to_string_lossy().to_string(),
             },
         );
     }
@@ -456,7 +671,7 @@ pub fn resolve_instructions(
     let schema_name = schema_name
         .map(|s| s.to_string())
         .unwrap_or_else(|| read_change_schema(spool_path, change));
-    let resolved = resolve_schema(Some(&schema_name), ctx)?;
+    let resolved = resolve_schema(spool_path, Some(&schema_name), ctx)?;
 
     let change_dir = crate::paths::change_dir(spool_path, change);
     if !change_dir.exists() {
@@ -470,7 +685,6 @@ pub fn resolve_instructions(
         .find(|a| a.id == artifact_id)
         .ok_or_else(|| WorkflowError::ArtifactNotFound(artifact_id.to_string()))?;
 
-    let templates_dir = resolved.schema_dir.join("templates");
     let done_by_id = compute_done_by_id(&change_dir, &resolved.schema);
 
     let deps: Vec<DependencyInfo> = a
@@ -498,7 +712,8 @@ pub fn resolve_instructions(
         .collect();
     unlocks.sort();
 
-    let template = crate::io::read_to_string_std(&templates_dir.join(&a.template))?;
+    let template = crate::io::read_to_string_std(&resolved.template_path(a))?;
+    let layers = resolved.artifact_layers(&a.id);
 
     Ok(InstructionsResponse {
         change_name: change.to_string(),
@@ -509,6 +724,7 @@ pub fn resolve_instructions(
         description: a.description.clone().unwrap_or_default(),
         instruction: a.instruction.clone(),
         template,
+        layers,
         dependencies: deps,
         unlocks,
     })
@@ -526,7 +742,7 @@ pub fn compute_apply_instructions(
     let schema_name = schema_name
         .map(|s| s.to_string())
         .unwrap_or_else(|| read_change_schema(spool_path, change));
-    let resolved = resolve_schema(Some(&schema_name), ctx)?;
+    let resolved = resolve_schema(spool_path, Some(&schema_name), ctx)?;
     let change_dir = crate::paths::change_dir(spool_path, change);
     if !change_dir.exists() {
         return Err(WorkflowError::ChangeNotFound(change.to_string()));
@@ -541,7 +757,8 @@ pub fn compute_apply_instructions(
     let required_artifact_ids: Vec<String> = apply
         .and_then(|a| a.requires.clone())
         .unwrap_or_else(|| all_artifact_ids.clone());
-    let tracks_file: Option<String> = apply.and_then(|a| a.tracks.clone());
+    let tracks_files = change_tracks(spool_path, change, Some(&resolved));
+    let tracks_file: Option<String> = tracks_files.first().cloned();
     let schema_instruction: Option<String> = apply.and_then(|a| a.instruction.clone());
 
     // Check which required artifacts are missing.
@@ -569,80 +786,58 @@ pub fn compute_apply_instructions(
         }
     }
 
-    // Parse tasks if tracking file exists.
+    // Parse tasks from each tracking file that exists.
+    let multiple = tracks_files.len() > 1;
     let mut tasks: Vec<TaskItem> = Vec::new();
-    let mut tracks_file_exists = false;
-    let mut tracks_path: Option<String> = None;
-    let mut tracks_format: Option<String> = None;
-    let tracks_diagnostics: Option<Vec<TaskDiagnostic>> = None;
-
-    if let Some(tf) = &tracks_file {
+    let mut tracked_files: Vec<TrackedFileInfo> = Vec::new();
+    let mut progress = ProgressInfo {
+        total: 0,
+        complete: 0,
+        remaining: 0,
+        in_progress: None,
+        pending: None,
+    };
+    let mut missing_tracks_file: Option<String> = None;
+    for tf in &tracks_files {
         let p = change_dir.join(tf);
-        tracks_path = Some(p.to_string_lossy().to_string());
-        tracks_file_exists = p.exists();
-        if tracks_file_exists {
-            let content = crate::io::read_to_string_std(&p)?;
-            let checkbox = parse_checkbox_tasks(&content);
-            if !checkbox.is_empty() {
-                tracks_format = Some("checkbox".to_string());
-                tasks = checkbox;
-            } else {
-                let enhanced = parse_enhanced_tasks(&content);
-                if !enhanced.is_empty() {
-                    tracks_format = Some("enhanced".to_string());
-                    tasks = enhanced;
-                } else if looks_like_enhanced_tasks(&content) {
-                    tracks_format = Some("enhanced".to_string());
-                } else {
-                    tracks_format = Some("unknown".to_string());
-                }
+        let exists = p.exists();
+        let (format, mut file_tasks) = if exists {
+            read_tracking_file(&p)?
+        } else {
+            if missing_tracks_file.is_none() {
+                missing_tracks_file = Some(tf.clone());
             }
-        }
-    }
-
-    // Calculate progress.
-    let total = tasks.len();
-    let complete = tasks.iter().filter(|t| t.done).count();
-    let remaining = total.saturating_sub(complete);
-    let mut in_progress: Option<usize> = None;
-    let mut pending: Option<usize> = None;
-    if tracks_format.as_deref() == Some("enhanced") {
-        let mut in_progress_count = 0;
-        let mut pending_count = 0;
-        for task in &tasks {
-            let Some(status) = task.status.as_deref() else {
-                continue;
-            };
-            let status = status.trim();
-            match status {
-                "in-progress" | "in_progress" | "in progress" => in_progress_count += 1,
-                "pending" => pending_count += 1,
-                _ => {}
+            (None, Vec::new())
+        };
+        let file_progress = tracking_progress(format.as_deref(), &file_tasks);
+        progress.total += file_progress.total;
+        progress.complete += file_progress.complete;
+        progress.remaining += file_progress.remaining;
+        progress.in_progress = add_counts(progress.in_progress, file_progress.in_progress);
+        progress.pending = add_counts(progress.pending, file_progress.pending);
+        if multiple {
+            for t in &mut file_tasks {
+                t.id = format!("{tf}:{}", t.id);
             }
         }
-        in_progress = Some(in_progress_count);
-        pending = Some(pending_count);
+        tasks.extend(file_tasks);
+        tracked_files.push(TrackedFileInfo {
+            file: tf.clone(),
+            path: p.to_string_lossy().to_string(),
+            exists,
+            format,
+            progress: file_progress,
+        });
     }
-    if tracks_format.as_deref() == Some("checkbox") {
-        let mut in_progress_count = 0;
-        for task in &tasks {
-            let Some(status) = task.status.as_deref() else {
-                continue;
-            };
-            if status.trim() == "in-progress" {
-                in_progress_count += 1;
-            }
-        }
-        in_progress = Some(in_progress_count);
-        pending = Some(total.saturating_sub(complete + in_progress_count));
+    let tracks_path = tracked_files.first().map(|t| t.path.clone());
+    let tracks_format = tracked_files.first().and_then(|t| t.format.clone());
+    let tracks_file_exists = missing_tracks_file.is_none();
+    let tracks_diagnostics: Option<Vec<TaskDiagnostic>> = None;
+    let total = progress.total;
+    let remaining = progress.remaining;
+    if !multiple {
+        tracked_files.clear();
     }
-    let progress = ProgressInfo {
-        total,
-        complete,
-        remaining,
-        in_progress,
-        pending,
-    };
 
     // Determine state and instruction.
     let (state, instruction) = if !missing_artifacts.is_empty() {
@@ -654,7 +849,7 @@ pub fn compute_apply_instructions(
             ),
         )
     } else if tracks_file.is_some() && !tracks_file_exists {
-        let tracks_filename = tracks_file
+        let tracks_filename = missing_tracks_file
             .as_deref()
             .and_then(|p| Path::new(p).file_name())
             .map(|s| s.to_string_lossy().to_string())
@@ -713,6 +908,7 @@ pub fn compute_apply_instructions(
         tracks_file,
         tracks_format,
         tracks_diagnostics,
+        tracked_files,
         context_files,
         progress,
         tasks,
@@ -726,6 +922,72 @@ pub fn compute_apply_instructions(
     })
 }
 
+/// Detected format (`checkbox`, `enhanced` or `unknown`) and tasks of a tracking file.
+fn read_tracking_file(path: &Path) -> Result<(Option<String>, Vec<TaskItem>), WorkflowError> {
+    let content = crate::io::read_to_string_std(path)?;
+    let checkbox = parse_checkbox_tasks(&content);
+    if !checkbox.is_empty() {
+        return Ok((Some("checkbox".to_string()), checkbox));
+    }
+    let enhanced = parse_enhanced_tasks(&content);
+    if !enhanced.is_empty() || looks_like_enhanced_tasks(&content) {
+        return Ok((Some("enhanced".to_string()), enhanced));
+    }
+    Ok((Some("unknown".to_string()), Vec::new()))
+}
+
+fn tracking_progress(format: Option<&str>, tasks: &[TaskItem]) -> ProgressInfo {
+    let total = tasks.len();
+    let complete = tasks.iter().filter(|t| t.done).count();
+    let remaining = total.saturating_sub(complete);
+    let mut in_progress: Option<usize> = None;
+    let mut pending: Option<usize> = None;
+    if format == Some("enhanced") {
+        let mut in_progress_count = 0;
+        let mut pending_count = 0;
+        for task in tasks {
+            let Some(status) = task.status.as_deref() else {
+                continue;
+            };
+            let status = status.trim();
+            match status {
+                "in-progress" | "in_progress" | "in progress" => in_progress_count += 1,
+                "pending" => pending_count += 1,
+                _ => {}
+            }
+        }
+        in_progress = Some(in_progress_count);
+        pending = Some(pending_count);
+    }
+    if format == Some("checkbox") {
+        let mut in_progress_count = 0;
+        for task in tasks {
+            let Some(status) = task.status.as_deref() else {
+                continue;
+            };
+            if status.trim() == "in-progress" {
+                in_progress_count += 1;
+            }
+        }
+        in_progress = Some(in_progress_count);
+        pending = Some(total.saturating_sub(complete + in_progress_count));
+    }
+    ProgressInfo {
+        total,
+        complete,
+        remaining,
+        in_progress,
+        pending,
+    }
+}
+
+fn add_counts(a: Option<usize>, b: Option<usize>) -> Option<usize> {
+    match (a, b) {
+        (None, None) => None,
+        (a, b) => Some(a.unwrap_or(0) + b.unwrap_or(0)),
+    }
+}
+
 fn parse_checkbox_tasks(contents: &str) -> Vec<TaskItem> {
     let mut tasks: Vec<TaskItem> = Vec::new();
     for line in contents.lines() {
@@ -888,7 +1150,13 @@ fn package_schemas_dir() -> PathBuf {
     root.join("schemas")
 }
 
-fn user_schemas_dir(ctx: &ConfigContext) -> Option<PathBuf> {
+/// Project-local schemas, `<spool_dir>/schemas`.
+pub fn project_schemas_dir(spool_path: &Path) -> PathBuf {
+    spool_path.join("schemas")
+}
+
+/// User schemas, `$XDG_DATA_HOME/spool/schemas` (default `~/.local/share/spool/schemas`).
+pub fn user_schemas_dir(ctx: &ConfigContext) -> Option<PathBuf> {
     let data_home = match env::var("XDG_DATA_HOME") {
         Ok(v) if !v.trim().is_empty() => Some(PathBuf::from(v)),
         _ => ctx
@@ -899,11 +1167,6 @@ fn user_schemas_dir(ctx: &ConfigContext) -> Option<PathBuf> {
     Some(data_home.join("spool").join("schemas"))
 }
 
-fn load_schema_yaml(schema_dir: &Path) -> Result<SchemaYaml, WorkflowError> {
-    let s = crate::io::read_to_string_std(&schema_dir.join("schema.yaml"))?;
-    Ok(serde_yaml::from_str(&s)?)
-}
-
 fn compute_done_by_id(change_dir: &Path, schema: &SchemaYaml) -> BTreeMap<String, bool> {
     let mut out = BTreeMap::new();
     for a in &schema.artifacts {
@@ -929,6 +1192,108 @@ fn artifact_done(change_dir: &Path, generates: &str) -> bool {
     dir_contains_filename_suffix(&base_dir, &suffix)
 }
 
+/// Files in `change_dir` produced for an artifact's `generates` pattern, sorted by path.
+pub(crate) fn artifact_files(change_dir: &Path, generates: &str) -> Vec<PathBuf> {
+    if !generates.contains('*') {
+        let path = change_dir.join(generates);
+        return if path.is_file() {
+            vec![path]
+        } else {
+            Vec::new()
+        };
+    }
+    let Some((base, suffix)) = split_glob_pattern(generates) else {
+        return Vec::new();
+    };
+    let mut out = Vec::new();
+    collect_files_with_suffix(&change_dir.join(base), &suffix, &mut out);
+    out.sort();
+    out
+}
+
+/// Matched files for an artifact with their modification times, plus the newest of them.
+fn artifact_file_details(
+    change_dir: &Path,
+    generates: &str,
+) -> (Vec<ArtifactFile>, Option<std::time::SystemTime>) {
+    let mut latest: Option<std::time::SystemTime> = None;
+    let mut out = Vec::new();
+    for path in artifact_files(change_dir, generates) {
+        let modified = fs::metadata(&path).and_then(|m| m.modified()).ok();
+        if let Some(m) = modified
+            && latest.is_none_or(|l| m > l)
+        {
+            latest = Some(m);
+        }
+        let rel = path.strip_prefix(change_dir).unwrap_or(&path);
+        out.push(ArtifactFile {
+            path: rel.to_string_lossy().replace('\\', "/"),
+            modified: modified.map(format_modified),
+        });
+    }
+    (out, latest)
+}
+
+/// Every artifact `id` depends on, directly or through other artifacts.
+fn upstream_artifacts(schema: &SchemaYaml, id: &str) -> BTreeSet<String> {
+    let mut out = BTreeSet::new();
+    let mut pending = vec![id.to_string()];
+    while let Some(current) = pending.pop() {
+        let Some(a) = schema.artifacts.iter().find(|a| a.id == current) else {
+            continue;
+        };
+        for r in &a.requires {
+            if out.insert(r.clone()) {
+                pending.push(r.clone());
+            }
+        }
+    }
+    out
+}
+
+/// `upstream` was modified after `downstream`. A second of slack keeps checkouts and
+/// copies that write a change's files together from looking stale.
+fn modified_after(upstream: std::time::SystemTime, downstream: std::time::SystemTime) -> bool {
+    upstream
+        .duration_since(downstream)
+        .is_ok_and(|d| d > std::time::Duration::from_secs(1))
+}
+
+fn format_modified(t: std::time::SystemTime) -> String {
+    chrono::DateTime::<chrono::Utc>::from(t).to_rfc3339_opts(chrono::SecondsFormat::Secs, true)
+}
+
+fn collect_files_with_suffix(dir: &Path, suffix: &str, out: &mut Vec<PathBuf>) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    for e in entries.flatten() {
+        let path = e.path();
+        if e.file_type().ok().is_some_and(|t| t.is_dir()) {
+            collect_files_with_suffix(&path, suffix, out);
+        } else if e.file_name().to_string_lossy().ends_with(suffix) {
+            out.push(path);
+        }
+    }
+}
+
+/// Whether a `generates` pattern is a relative path or a glob the artifact matcher supports.
+pub(crate) fn generates_pattern_supported(pattern: &str) -> bool {
+    let path = Path::new(pattern);
+    if pattern.trim().is_empty()
+        || path.is_absolute()
+        || path
+            .components()
+            .any(|c| c == std::path::Component::ParentDir)
+    {
+        return false;
+    }
+    if !pattern.contains('*') {
+        return true;
+    }
+    split_glob_pattern(pattern).is_some_and(|(_, suffix)| !suffix.contains('*'))
+}
+
 fn split_glob_pattern(pattern: &str) -> Option<(String, String)> {
     let pattern = pattern.strip_prefix("./").unwrap_or(pattern);
 
@@ -977,6 +1342,188 @@ fn dir_contains_filename_suffix(dir: &Path, suffix: &str) -> bool {
 mod tests {
     use super::*;
 
+    #[test]
+    fn project_schemas_take_precedence_and_are_listed() {
+        let dir = tempfile::tempdir().expect("tempdir should succeed");
+        let spool_path = dir.path().join(".spool");
+        let schema = "name: team\nartifacts:\n  - id: brief\n    generates: brief.md\n    template: brief.md\n";
+        for name in ["team", "spec-driven"] {
+            let schema_dir = spool_path.join("schemas").join(name);
+            std::fs::create_dir_all(&schema_dir).expect("mkdir should succeed");
+            std::fs::write(schema_dir.join("schema.yaml"), schema).expect("write should succeed");
+        }
+        let ctx = ConfigContext::default();
+
+        let resolved =
+            resolve_schema(&spool_path, Some("team"), &ctx).expect("team should resolve");
+        assert_eq!(resolved.source, SchemaSource::Project);
+        assert_eq!(resolved.schema.artifacts[0].id, "brief");
+
+        // A project schema shadows the packaged schema of the same name.
+        let resolved =
+            resolve_schema(&spool_path, None, &ctx).expect("default schema should resolve");
+        assert_eq!(resolved.source, SchemaSource::Project);
+        assert_eq!(resolved.schema_dir, spool_path.join("schemas/spec-driven"));
+
+        let names = list_available_schemas(&spool_path, &ctx);
+        assert!(names.contains(&"team".to_string()));
+        assert!(names.contains(&"tdd".to_string()));
+    }
+
+    #[test]
+    fn schemas_extend_a_parent_and_adjust_its_artifacts() {
+        let dir = tempfile::tempdir().expect("tempdir should succeed");
+        let spool_path = dir.path().join(".spool");
+        let schema_dir = spool_path.join("schemas/spec-driven");
+        std::fs::create_dir_all(schema_dir.join("templates")).expect("mkdir should succeed");
+        std::fs::write(
+            schema_dir.join("schema.yaml"),
+            "name: spec-driven\nextends: spec-driven\nremove: [design]\norder: [proposal, tasks]\nartifacts:\n  - id: tasks\n    instruction: Keep tasks small.\n    requires: [proposal]\n  - id: runbook\n    generates: runbook.md\n    template: runbook.md\n    requires: [tasks]\napply:\n  requires: [runbook]\n",
+        )
+        .expect("write should succeed");
+        let ctx = ConfigContext::default();
+
+        // The same-named parent resolves to the packaged schema.
+        let resolved = resolve_schema(&spool_path, None, &ctx).expect("schema should resolve");
+        assert_eq!(resolved.source, SchemaSource::Project);
+        assert_eq!(resolved.schema.extends.as_deref(), Some("spec-driven"));
+        let ids: Vec<&str> = resolved
+            .schema
+            .artifacts
+            .iter()
+            .map(|a| a.id.as_str())
+            .collect();
+        assert_eq!(ids, vec!["proposal", "tasks", "specs", "runbook"]);
+
+        let tasks = &resolved.schema.artifacts[1];
+        assert_eq!(tasks.instruction.as_deref(), Some("Keep tasks small."));
+        assert_eq!(tasks.requires, vec!["proposal"]);
+        assert_eq!(
+            resolved.artifact_layers("tasks"),
+            vec!["spec-driven", "spec-driven"]
+        );
+        // Overriding the instruction keeps the parent's template.
+        assert_eq!(
+            resolved.template_path(tasks),
+            package_schemas_dir().join("spec-driven/templates/tasks.md")
+        );
+        let runbook = &resolved.schema.artifacts[3];
+        assert_eq!(
+            resolved.template_path(runbook),
+            schema_dir.join("templates/runbook.md")
+        );
+        assert_eq!(
+            resolved
+                .schema
+                .apply
+                .as_ref()
+                .and_then(|a| a.requires.clone()),
+            Some(vec!["runbook".to_string()])
+        );
+    }
+
+    #[test]
+    fn schema_inheritance_reports_bad_layers_and_cycles() {
+        let dir = tempfile::tempdir().expect("tempdir should succeed");
+        let spool_path = dir.path().join(".spool");
+        let write = |name: &str, yaml: &str| {
+            let schema_dir = spool_path.join("schemas").join(name);
+            std::fs::create_dir_all(&schema_dir).expect("mkdir should succeed");
+            std::fs::write(schema_dir.join("schema.yaml"), yaml).expect("write should succeed");
+        };
+        write("a", "name: a\nextends: b\n");
+        write("b", "name: b\nextends: a\n");
+        write("bad", "name: bad\nextends: spec-driven\nremove: [nope]\n");
+        write(
+            "dangling",
+            "name: dangling\nextends: spec-driven\nremove: [proposal]\n",
+        );
+        let ctx = ConfigContext::default();
+
+        let err = resolve_schema(&spool_path, Some("a"), &ctx).expect_err("cycle should fail");
+        assert!(matches!(err, WorkflowError::SchemaNotFound(name) if name == "a"));
+
+        let err = resolve_schema(&spool_path, Some("bad"), &ctx).expect_err("remove should fail");
+        assert_eq!(
+            err.to_string(),
+            "Invalid schema 'bad': cannot remove unknown artifact 'nope'"
+        );
+
+        let err =
+            resolve_schema(&spool_path, Some("dangling"), &ctx).expect_err("requires should fail");
+        assert!(
+            err.to_string()
+                .contains("requires unknown artifact 'proposal'")
+        );
+    }
+
+    #[test]
+    fn change_status_lists_artifact_files_and_flags_stale_downstream_artifacts() {
+        let dir = tempfile::tempdir().expect("tempdir should succeed");
+        let spool_path = dir.path().join(".spool");
+        let change_dir = spool_path.join("changes/001-01_demo");
+        std::fs::create_dir_all(change_dir.join("specs/auth")).expect("mkdir should succeed");
+        let now = std::time::SystemTime::now();
+        let write = |rel: &str, age_secs: u64| {
+            let path = change_dir.join(rel);
+            std::fs::write(&path, "# x\n").expect("write should succeed");
+            let file = std::fs::File::options()
+                .write(true)
+                .open(&path)
+                .expect("open should succeed");
+            file.set_modified(now - std::time::Duration::from_secs(age_secs))
+                .expect("set_modified should succeed");
+        };
+        // The proposal was edited after specs, design and tasks were written.
+        write("specs/auth/spec.md", 300);
+        write("design.md", 300);
+        write("tasks.md", 200);
+        write("proposal.md", 10);
+
+        let status = compute_change_status(
+            &spool_path,
+            "001-01_demo",
+            Some("spec-driven"),
+            &ConfigContext::default(),
+        )
+        .expect("status should compute");
+        let by_id = |id: &str| {
+            status
+                .artifacts
+                .iter()
+                .find(|a| a.id == id)
+                .expect("artifact should exist")
+        };
+
+        let proposal = by_id("proposal");
+        assert!(!proposal.stale);
+        assert_eq!(proposal.files.len(), 1);
+        assert_eq!(proposal.files[0].path, "proposal.md");
+        assert_eq!(proposal.last_modified, proposal.files[0].modified);
+
+        let specs = by_id("specs");
+        assert_eq!(specs.files[0].path, "specs/auth/spec.md");
+        assert_eq!(specs.stale_against, vec!["proposal"]);
+
+        // Staleness follows transitive requires: tasks -> specs -> proposal.
+        let tasks = by_id("tasks");
+        assert!(tasks.stale);
+        assert_eq!(tasks.stale_against, vec!["proposal"]);
+
+        // Refreshing the downstream artifact clears the flag.
+        write("tasks.md", 0);
+        let status = compute_change_status(
+            &spool_path,
+            "001-01_demo",
+            Some("spec-driven"),
+            &ConfigContext::default(),
+        )
+        .expect("status should compute");
+        let tasks = status.artifacts.iter().find(|a| a.id == "tasks").unwrap();
+        assert!(!tasks.stale);
+        assert!(tasks.stale_against.is_empty());
+    }
+
     #[test]
     fn load_user_guidance_returns_trimmed_content_after_marker() {
         let dir = tempfile::tempdir().expect("tempdir should succeed");
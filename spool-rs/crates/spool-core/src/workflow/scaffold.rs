@@ -0,0 +1,151 @@
+//! Create custom schemas from existing ones and preview how a schema renders.
+
+use super::{
+    ResolvedSchema, WorkflowError, build_order, resolve_schema, validate_change_name_input,
+};
+use crate::config::ConfigContext;
+use serde::Serialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Change name used when previewing templates.
+const PREVIEW_CHANGE_NAME: &str = "000-01_example-change";
+
+/// Copy schema `from` to `schemas_root/<name>`, renamed to `name`.
+///
+/// A base schema's `schema.yaml` is copied as written; a schema that extends others is
+/// written out flattened. Every template the artifacts use is copied into `templates/`.
+pub fn scaffold_schema(
+    spool_path: &Path,
+    schemas_root: &Path,
+    name: &str,
+    from: &str,
+    ctx: &ConfigContext,
+) -> Result<PathBuf, WorkflowError> {
+    if !validate_change_name_input(name) {
+        return Err(WorkflowError::InvalidSchema {
+            name: name.to_string(),
+            message: "schema names cannot contain path separators or '..'".to_string(),
+        });
+    }
+    let dest = schemas_root.join(name);
+    if dest.exists() {
+        return Err(WorkflowError::SchemaExists(
+            dest.to_string_lossy().to_string(),
+        ));
+    }
+    let resolved = resolve_schema(spool_path, Some(from), ctx)?;
+
+    let schema_yaml = if resolved.schema.extends.is_none() {
+        let original = crate::io::read_to_string_std(&resolved.schema_dir.join("schema.yaml"))?;
+        rename_schema(&original, name)
+    } else {
+        let mut flattened = resolved.schema.clone();
+        flattened.name = name.to_string();
+        flattened.extends = None;
+        serde_yaml::to_string(&flattened)?
+    };
+
+    let templates_dir = dest.join("templates");
+    fs::create_dir_all(&templates_dir)?;
+    fs::write(dest.join("schema.yaml"), schema_yaml)?;
+    for a in &resolved.schema.artifacts {
+        let target = templates_dir.join(&a.template);
+        if let Some(parent) = target.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::copy(resolved.template_path(a), target)?;
+    }
+    Ok(dest)
+}
+
+/// Replace the top-level `name:` line, keeping the rest of the file (and its comments).
+fn rename_schema(yaml: &str, name: &str) -> String {
+    let mut renamed = false;
+    let mut out: Vec<String> = Vec::new();
+    for line in yaml.lines() {
+        if !renamed && line.starts_with("name:") {
+            out.push(format!("name: {name}"));
+            renamed = true;
+        } else {
+            out.push(line.to_string());
+        }
+    }
+    if !renamed {
+        out.insert(0, format!("name: {name}"));
+    }
+    let mut s = out.join("\n");
+    s.push('\n');
+    s
+}
+
+/// Sample variables available to schema templates in a preview.
+#[derive(Debug, Serialize)]
+struct PreviewVars<'a> {
+    change_name: &'a str,
+    schema_name: &'a str,
+    artifact_id: &'a str,
+    output_path: &'a str,
+    description: &'a str,
+}
+
+/// Render a schema's dependency graph and each artifact's template, in build order.
+pub fn render_schema_preview(resolved: &ResolvedSchema) -> Result<String, WorkflowError> {
+    let schema = &resolved.schema;
+    let order = build_order(schema);
+    let width = order.iter().map(|id| id.len()).max().unwrap_or(0);
+
+    let mut out = format!(
+        "# Schema: {} ({})\n\n## Dependency Graph\n\n",
+        schema.name,
+        resolved.source.as_str()
+    );
+    for (idx, id) in order.iter().enumerate() {
+        let Some(a) = schema.artifacts.iter().find(|a| &a.id == id) else {
+            continue;
+        };
+        let requires = if a.requires.is_empty() {
+            String::new()
+        } else {
+            format!(" <- {}", a.requires.join(", "))
+        };
+        out.push_str(&format!(
+            "{n:>3}. {id:<width$}  {generates}{requires}\n",
+            n = idx + 1,
+            generates = a.generates
+        ));
+    }
+    if let Some(apply) = &schema.apply
+        && let Some(requires) = &apply.requires
+    {
+        out.push_str(&format!("     apply <- {}\n", requires.join(", ")));
+    }
+
+    for id in &order {
+        let Some(a) = schema.artifacts.iter().find(|a| &a.id == id) else {
+            continue;
+        };
+        let template = crate::io::read_to_string_std(&resolved.template_path(a))?;
+        let vars = PreviewVars {
+            change_name: PREVIEW_CHANGE_NAME,
+            schema_name: &schema.name,
+            artifact_id: &a.id,
+            output_path: &a.generates,
+            description: a.description.as_deref().unwrap_or(""),
+        };
+        out.push_str(&format!(
+            "\n## {id} -> {generates} (template: {template})\n\n",
+            generates = a.generates,
+            template = a.template
+        ));
+        match spool_templates::instructions::render_template_str(&template, &vars) {
+            Ok(rendered) => out.push_str(rendered.trim_end()),
+            Err(e) => {
+                out.push_str(&format!("> Template did not render: {e}\n\n"));
+                out.push_str(template.trim_end());
+            }
+        }
+        out.push('\n');
+    }
+    Ok(out)
+}
@@ -0,0 +1,119 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use spool_core::archive::{
+    ArchiveManifestInput, build_archive_manifest, content_hash, move_to_archive,
+    read_archive_manifest, snapshot_spec_hashes, write_archive_manifest,
+};
+
+fn write(path: &Path, contents: &str) {
+    let Some(parent) = path.parent() else {
+        panic!("path has no parent: {}", path.display());
+    };
+    std::fs::create_dir_all(parent).unwrap();
+    std::fs::write(path, contents).unwrap();
+}
+
+#[test]
+fn snapshot_spec_hashes_reports_missing_specs_as_none() {
+    let td = tempfile::tempdir().unwrap();
+    let spool = td.path().join(".spool");
+    write(&spool.join("specs/alpha/spec.md"), "# Alpha\n");
+
+    let names = vec!["alpha".to_string(), "beta".to_string()];
+    let hashes = snapshot_spec_hashes(&spool, &names).unwrap();
+
+    assert_eq!(hashes["alpha"], Some(content_hash(b"# Alpha\n")));
+    assert_eq!(hashes["beta"], None);
+}
+
+#[test]
+fn archive_manifest_round_trips_with_before_and_after_hashes() {
+    let td = tempfile::tempdir().unwrap();
+    let spool = td.path().join(".spool");
+    write(&spool.join("specs/alpha/spec.md"), "old\n");
+
+    let names = vec!["alpha".to_string(), "beta".to_string()];
+    let before: BTreeMap<String, Option<String>> = snapshot_spec_hashes(&spool, &names).unwrap();
+
+    write(&spool.join("specs/alpha/spec.md"), "new\n");
+    write(&spool.join("specs/beta/spec.md"), "beta\n");
+
+    let manifest = build_archive_manifest(
+        &spool,
+        ArchiveManifestInput {
+            change_id: "000-01_demo",
+            archive_name: "2026-01-01-000-01_demo",
+            spool_version: "0.0.0-test",
+            validation_skipped: false,
+            specs_skipped: false,
+            new_specs: &["beta".to_string()],
+            updated_specs: &["alpha".to_string()],
+            hashes_before: &before,
+        },
+    )
+    .unwrap();
+
+    assert_eq!(manifest.specs.len(), 2);
+    assert_eq!(manifest.specs[0].name, "alpha");
+    assert_eq!(manifest.specs[0].hash_before, Some(content_hash(b"old\n")));
+    assert_eq!(manifest.specs[0].hash_after, Some(content_hash(b"new\n")));
+    assert_eq!(manifest.specs[1].name, "beta");
+    assert_eq!(manifest.specs[1].hash_before, None);
+    assert_eq!(manifest.specs[1].hash_after, Some(content_hash(b"beta\n")));
+
+    std::fs::create_dir_all(spool.join("changes/archive/2026-01-01-000-01_demo")).unwrap();
+    write_archive_manifest(&spool, "2026-01-01-000-01_demo", &manifest).unwrap();
+    let loaded = read_archive_manifest(&spool, "2026-01-01-000-01_demo")
+        .unwrap()
+        .expect("manifest");
+    assert_eq!(loaded, manifest);
+}
+
+#[test]
+fn order_changes_for_archive_respects_module_dependencies() {
+    let td = tempfile::tempdir().unwrap();
+    let spool = td.path().join(".spool");
+    write(
+        &spool.join("modules/001_app/module.md"),
+        "# App\n\n## Purpose\nApp module.\n\n## Scope\n- *\n\n## Depends On\n- 002\n\n## Changes\n",
+    );
+    write(
+        &spool.join("modules/002_core/module.md"),
+        "# Core\n\n## Purpose\nCore module.\n\n## Scope\n- *\n\n## Changes\n",
+    );
+
+    let ids = vec![
+        "001-02_second".to_string(),
+        "001-01_first".to_string(),
+        "002-10_tenth".to_string(),
+        "002-02_second".to_string(),
+    ];
+    let ordered = spool_core::archive::order_changes_for_archive(&spool, &ids);
+    assert_eq!(
+        ordered,
+        vec![
+            "002-02_second",
+            "002-10_tenth",
+            "001-01_first",
+            "001-02_second"
+        ]
+    );
+}
+
+#[test]
+fn move_to_archive_only_checks_off_the_exact_change() {
+    let td = tempfile::tempdir().unwrap();
+    let spool = td.path().join(".spool");
+    write(
+        &spool.join("modules/001_demo/module.md"),
+        "# Demo\n\n## Purpose\nDemo.\n\n## Scope\n- *\n\n## Changes\n- [ ] 001-01_foo\n- [ ] 001-02_foo-bar\n",
+    );
+    write(&spool.join("changes/001-01_foo/proposal.md"), "# Foo\n");
+
+    move_to_archive(&spool, "001-01_foo", "2026-01-01-001-01_foo").unwrap();
+
+    let md = std::fs::read_to_string(spool.join("modules/001_demo/module.md")).unwrap();
+    assert!(md.contains("- [x] 001-01_foo\n"));
+    assert!(md.contains("- [ ] 001-02_foo-bar\n"));
+}
@@ -1,4 +1,5 @@
-use spool_core::ralph::{RalphOptions, run_ralph};
+use spool_core::config::ConfigContext;
+use spool_core::ralph::{CompletionGates, RalphOptions, run_ralph};
 use spool_harness::{Harness, HarnessName, HarnessRunConfig, HarnessRunResult};
 use std::path::Path;
 use std::sync::Mutex;
@@ -44,6 +45,7 @@ impl Harness for FixedHarness {
     fn run(&mut self, _config: &HarnessRunConfig) -> miette::Result<HarnessRunResult> {
         let (stdout, stderr, exit_code) = self.next();
         Ok(HarnessRunResult {
+            usage: spool_harness::parse_token_usage(&stdout),
             stdout,
             stderr,
             exit_code,
@@ -91,6 +93,19 @@ fn default_opts() -> RalphOptions {
         clear_context: false,
         verbose: false,
         inactivity_timeout: None,
+        log: None,
+        worktree: false,
+        merge: false,
+        discard: false,
+        gates: CompletionGates::default(),
+        max_failures: 3,
+        retry_backoff: Duration::ZERO,
+        pricing: None,
+        max_cost: None,
+        max_duration: None,
+        context_budget: None,
+        hooks: Default::default(),
+        ctx: Default::default(),
     }
 }
 
@@ -311,3 +326,658 @@ fn run_ralph_module_multiple_changes_errors_when_non_interactive() {
     let err = run_ralph(&spool, opts, &mut h).unwrap_err();
     assert!(err.to_string().contains("Multiple changes"));
 }
+
+#[test]
+fn run_ralph_records_iteration_transcripts_even_on_failure() {
+    let td = tempfile::tempdir().unwrap();
+    let spool = td.path().join(".spool");
+    std::fs::create_dir_all(&spool).unwrap();
+    write_fixture_spool(&spool, "006-09_fixture");
+
+    let mut h = FixedHarness::new(
+        HarnessName::STUB,
+        vec![
+            ("working\n".to_string(), "warn: slow\n".to_string(), 0),
+            ("boom\n".to_string(), "fatal\n".to_string(), 3),
+        ],
+    );
+    let mut opts = default_opts();
+    opts.change_id = Some("006-09_fixture".to_string());
+    assert!(run_ralph(&spool, opts, &mut h).is_err());
+
+    let first = spool_core::ralph::state::load_iteration_transcript(&spool, "006-09_fixture", 1)
+        .unwrap()
+        .unwrap();
+    assert!(first.prompt.contains("do the thing"));
+    assert_eq!(first.stdout, "working\n");
+    assert_eq!(first.stderr, "warn: slow\n");
+    assert_eq!(first.meta.exit_code, 0);
+    assert!(!first.meta.timed_out);
+
+    let second = spool_core::ralph::state::load_iteration_transcript(&spool, "006-09_fixture", 2)
+        .unwrap()
+        .unwrap();
+    assert_eq!(second.meta.exit_code, 3);
+    assert_eq!(second.stderr, "fatal\n");
+
+    assert!(
+        spool_core::ralph::state::load_iteration_transcript(&spool, "006-09_fixture", 3)
+            .unwrap()
+            .is_none()
+    );
+}
+
+#[test]
+fn save_iteration_transcript_keeps_retried_attempts() {
+    use spool_core::ralph::state::{
+        IterationMeta, IterationTranscript, load_iteration_transcript, ralph_iteration_dir,
+        save_iteration_transcript,
+    };
+
+    let td = tempfile::tempdir().unwrap();
+    let spool = td.path().join(".spool");
+    let mut transcript = IterationTranscript {
+        meta: IterationMeta {
+            iteration: 1,
+            timestamp: 0,
+            duration: 10,
+            exit_code: -1,
+            timed_out: true,
+            completion_promise_found: false,
+        },
+        prompt: "p".to_string(),
+        stdout: "stalled\n".to_string(),
+        stderr: String::new(),
+        git_diff_stat: Some(" a.txt | 1 +\n".to_string()),
+        previous_attempts: 0,
+    };
+    save_iteration_transcript(&spool, "006-09_fixture", &transcript).unwrap();
+
+    transcript.meta.timed_out = false;
+    transcript.meta.exit_code = 0;
+    transcript.stdout = "done\n".to_string();
+    transcript.git_diff_stat = None;
+    save_iteration_transcript(&spool, "006-09_fixture", &transcript).unwrap();
+
+    let latest = load_iteration_transcript(&spool, "006-09_fixture", 1)
+        .unwrap()
+        .unwrap();
+    assert_eq!(latest.stdout, "done\n");
+    assert_eq!(latest.previous_attempts, 1);
+    assert_eq!(latest.git_diff_stat, None);
+
+    let attempt = ralph_iteration_dir(&spool, "006-09_fixture", 1).join("attempt-1");
+    assert_eq!(
+        std::fs::read_to_string(attempt.join("stdout.log")).unwrap(),
+        "stalled\n"
+    );
+    assert!(attempt.join("diff-stat.txt").exists());
+}
+
+fn git(repo: &Path, args: &[&str]) -> String {
+    let out = std::process::Command::new("git")
+        .args(args)
+        .current_dir(repo)
+        .output()
+        .unwrap();
+    assert!(
+        out.status.success(),
+        "git {args:?} failed: {}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    String::from_utf8_lossy(&out.stdout).trim().to_string()
+}
+
+fn init_git_repo(repo: &Path) {
+    git(repo, &["init", "-q"]);
+    git(repo, &["config", "user.name", "Test"]);
+    git(repo, &["config", "user.email", "test@example.com"]);
+    std::fs::write(repo.join("README.md"), "# temp\n").unwrap();
+    git(repo, &["add", "-A"]);
+    git(repo, &["commit", "-q", "-m", "initial"]);
+}
+
+#[test]
+fn worktree_is_created_reused_and_merged_back() {
+    use spool_core::ralph::worktree::{ensure_worktree, find_worktree, merge_worktree, repo_root};
+
+    let td = tempfile::tempdir().unwrap();
+    init_git_repo(td.path());
+    let root = repo_root(td.path()).unwrap();
+
+    assert!(find_worktree(&root, "006-09_fixture").unwrap().is_none());
+    let wt = ensure_worktree(&root, "006-09_fixture").unwrap();
+    assert_eq!(wt.branch, "spool/ralph/006-09_fixture");
+    assert!(wt.path.join("README.md").exists());
+    assert_eq!(
+        wt.translate(&root.join(".spool")),
+        Some(wt.path.join(".spool"))
+    );
+    assert_eq!(ensure_worktree(&root, "006-09_fixture").unwrap(), wt);
+
+    std::fs::write(wt.path.join("feature.txt"), "done\n").unwrap();
+    git(&wt.path, &["add", "-A"]);
+    git(&wt.path, &["commit", "-q", "-m", "feature"]);
+
+    merge_worktree(&root, "006-09_fixture").unwrap();
+    assert!(root.join("feature.txt").exists());
+    assert!(!wt.path.exists());
+    assert!(git(&root, &["branch", "--list", &wt.branch]).is_empty());
+    assert!(find_worktree(&root, "006-09_fixture").unwrap().is_none());
+}
+
+#[test]
+fn worktree_discard_drops_branch_without_merging() {
+    use spool_core::ralph::worktree::{discard_worktree, ensure_worktree, repo_root};
+
+    let td = tempfile::tempdir().unwrap();
+    init_git_repo(td.path());
+    let root = repo_root(td.path()).unwrap();
+
+    let wt = ensure_worktree(&root, "006-09_fixture").unwrap();
+    std::fs::write(wt.path.join("feature.txt"), "wip\n").unwrap();
+    git(&wt.path, &["add", "-A"]);
+    git(&wt.path, &["commit", "-q", "-m", "wip"]);
+
+    discard_worktree(&root, "006-09_fixture").unwrap();
+    assert!(!root.join("feature.txt").exists());
+    assert!(!wt.path.exists());
+    assert!(git(&root, &["branch", "--list", &wt.branch]).is_empty());
+
+    let err = discard_worktree(&root, "006-09_fixture").unwrap_err();
+    assert!(err.to_string().contains("No Ralph worktree"));
+}
+
+#[test]
+fn worktree_with_uncommitted_changes_is_neither_merged_nor_discarded() {
+    use spool_core::ralph::worktree::{
+        discard_worktree, ensure_worktree, find_worktree, merge_worktree, repo_root,
+    };
+
+    let td = tempfile::tempdir().unwrap();
+    init_git_repo(td.path());
+    let root = repo_root(td.path()).unwrap();
+
+    let wt = ensure_worktree(&root, "006-09_fixture").unwrap();
+    std::fs::write(wt.path.join("feature.txt"), "done\n").unwrap();
+    git(&wt.path, &["add", "-A"]);
+    git(&wt.path, &["commit", "-q", "-m", "feature"]);
+    std::fs::write(wt.path.join("uncommitted.txt"), "wip\n").unwrap();
+
+    for err in [
+        merge_worktree(&root, "006-09_fixture").unwrap_err(),
+        discard_worktree(&root, "006-09_fixture").unwrap_err(),
+    ] {
+        let msg = err.to_string();
+        assert!(msg.contains("uncommitted changes"), "{msg}");
+        assert!(msg.contains(&wt.path.display().to_string()), "{msg}");
+    }
+    assert!(wt.path.join("uncommitted.txt").exists());
+    assert!(!root.join("feature.txt").exists());
+    assert!(find_worktree(&root, "006-09_fixture").unwrap().is_some());
+
+    git(&wt.path, &["add", "-A"]);
+    git(&wt.path, &["commit", "-q", "-m", "wip"]);
+    merge_worktree(&root, "006-09_fixture").unwrap();
+    assert!(root.join("uncommitted.txt").exists());
+}
+
+#[test]
+fn completion_gates_keep_the_loop_running_and_feed_back_failures() {
+    let td = tempfile::tempdir().unwrap();
+    let spool = td.path().join(".spool");
+    write_fixture_spool(&spool, "006-09_fixture");
+    let tasks = spool.join("changes/006-09_fixture/tasks.md");
+    std::fs::write(
+        &tasks,
+        "## 1. Implementation\n- [x] 1.1 Done\n- [ ] 1.2 Todo\n",
+    )
+    .unwrap();
+
+    let complete = (
+        "<promise>COMPLETE</promise>\n".to_string(),
+        String::new(),
+        0,
+    );
+    let mut h = FixedHarness::new(HarnessName::STUB, vec![complete]);
+    let mut opts = default_opts();
+    opts.change_id = Some("006-09_fixture".to_string());
+    opts.max_iterations = Some(2);
+    opts.gates.tasks = true;
+    run_ralph(&spool, opts.clone(), &mut h).unwrap();
+
+    let state = spool_core::ralph::state::load_state(&spool, "006-09_fixture")
+        .unwrap()
+        .unwrap();
+    assert_eq!(state.iteration, 2);
+    assert!(state.history.iter().all(|h| h.gates_passed == Some(false)));
+
+    let second = spool_core::ralph::state::load_iteration_transcript(&spool, "006-09_fixture", 2)
+        .unwrap()
+        .unwrap();
+    assert!(second.prompt.contains("## Completion Gates Failed"));
+    assert!(second.prompt.contains("1 of 2 tasks are not complete"));
+
+    // Once the tasks are done the promise is honoured.
+    std::fs::write(
+        &tasks,
+        "## 1. Implementation\n- [x] 1.1 Done\n- [x] 1.2 Todo\n",
+    )
+    .unwrap();
+    run_ralph(&spool, opts, &mut h).unwrap();
+    let state = spool_core::ralph::state::load_state(&spool, "006-09_fixture")
+        .unwrap()
+        .unwrap();
+    assert_eq!(state.iteration, 3);
+    assert_eq!(state.history.last().unwrap().gates_passed, Some(true));
+}
+
+#[test]
+fn check_gates_reports_verify_command_output_and_validation_issues() {
+    use spool_core::ralph::gates::{CompletionGates, check_gates};
+
+    let td = tempfile::tempdir().unwrap();
+    let spool = td.path().join(".spool");
+    write_fixture_spool(&spool, "006-09_fixture");
+
+    // Tracking file problems fail the gate just as they fail `spool validate`.
+    std::fs::write(
+        spool.join("changes/006-09_fixture/tasks.md"),
+        "## Wave 1\n\n### Task 1.1: Do it\n- **Status**: [ ] sideways\n",
+    )
+    .unwrap();
+
+    let gates = CompletionGates {
+        tasks: false,
+        validate: true,
+        command: Some("echo boom; exit 3".to_string()),
+        coverage: None,
+    };
+    let failures = check_gates(
+        &spool,
+        "006-09_fixture",
+        &gates,
+        td.path(),
+        &ConfigContext::default(),
+    )
+    .unwrap();
+    assert_eq!(failures.len(), 2);
+    assert_eq!(failures[0].gate, "validate");
+    assert!(
+        failures[0]
+            .detail
+            .contains("Change must have at least one delta")
+    );
+    assert!(
+        failures[0].detail.contains("Invalid or missing status"),
+        "{}",
+        failures[0].detail
+    );
+    assert_eq!(failures[1].gate, "command");
+    assert!(failures[1].detail.contains("exited with 3"));
+    assert!(failures[1].detail.contains("boom"));
+
+    let passing = CompletionGates {
+        command: Some("true".to_string()),
+        ..CompletionGates::default()
+    };
+    assert!(
+        check_gates(
+            &spool,
+            "006-09_fixture",
+            &passing,
+            td.path(),
+            &ConfigContext::default()
+        )
+        .unwrap()
+        .is_empty()
+    );
+}
+
+#[test]
+fn coverage_gate_checks_the_report_against_the_target() {
+    use spool_core::ralph::gates::{CompletionGates, CoverageGate, check_gates};
+
+    let td = tempfile::tempdir().unwrap();
+    let spool = td.path().join(".spool");
+    write_fixture_spool(&spool, "006-09_fixture");
+    std::fs::write(
+        td.path().join("lcov.info"),
+        "SF:src/lib.rs\nDA:1,1\nDA:2,0\nend_of_record\n",
+    )
+    .unwrap();
+
+    let gates = |target_percent| CompletionGates {
+        coverage: Some(CoverageGate {
+            report: None,
+            target_percent,
+        }),
+        ..CompletionGates::default()
+    };
+    let failures = check_gates(
+        &spool,
+        "006-09_fixture",
+        &gates(80),
+        td.path(),
+        &ConfigContext::default(),
+    )
+    .unwrap();
+    assert_eq!(failures.len(), 1);
+    assert_eq!(failures[0].gate, "coverage");
+    assert_eq!(
+        failures[0].detail,
+        "Overall line coverage 50.0% is below the 80% target"
+    );
+    assert!(
+        check_gates(
+            &spool,
+            "006-09_fixture",
+            &gates(50),
+            td.path(),
+            &ConfigContext::default()
+        )
+        .unwrap()
+        .is_empty()
+    );
+}
+
+#[test]
+fn failed_attempts_are_retried_with_failure_feedback() {
+    let td = tempfile::tempdir().unwrap();
+    let spool = td.path().join(".spool");
+    write_fixture_spool(&spool, "006-09_fixture");
+
+    let mut h = FixedHarness::new(
+        HarnessName::STUB,
+        vec![
+            (
+                "partial\n".to_string(),
+                "error: cannot find crate\n".to_string(),
+                101,
+            ),
+            (
+                "<promise>COMPLETE</promise>\n".to_string(),
+                String::new(),
+                0,
+            ),
+        ],
+    );
+    let mut opts = default_opts();
+    opts.change_id = Some("006-09_fixture".to_string());
+    run_ralph(&spool, opts, &mut h).unwrap();
+
+    let state = spool_core::ralph::state::load_state(&spool, "006-09_fixture")
+        .unwrap()
+        .unwrap();
+    assert_eq!(state.iteration, 1);
+
+    let first = spool_core::ralph::state::load_iteration_transcript(&spool, "006-09_fixture", 1)
+        .unwrap()
+        .unwrap();
+    assert_eq!(first.previous_attempts, 1);
+    assert_eq!(first.meta.exit_code, 0);
+    assert!(first.prompt.contains("## Previous Iteration Failed"));
+    assert!(first.prompt.contains("exited with code 101"));
+    assert!(first.prompt.contains("error: cannot find crate"));
+
+    // A clean iteration clears the feedback again.
+    let feedback = spool_core::ralph::state::load_feedback(&spool, "006-09_fixture").unwrap();
+    assert!(feedback.trim().is_empty());
+}
+
+#[test]
+fn a_new_run_does_not_inherit_feedback_from_an_earlier_run() {
+    let td = tempfile::tempdir().unwrap();
+    let spool = td.path().join(".spool");
+    write_fixture_spool(&spool, "006-09_fixture");
+    spool_core::ralph::state::save_feedback(
+        &spool,
+        "006-09_fixture",
+        "## Previous Iteration Failed\n\nstale failure from an earlier run\n",
+    )
+    .unwrap();
+
+    let mut h = FixedHarness::new(
+        HarnessName::STUB,
+        vec![(
+            "<promise>COMPLETE</promise>\n".to_string(),
+            String::new(),
+            0,
+        )],
+    );
+    let mut opts = default_opts();
+    opts.change_id = Some("006-09_fixture".to_string());
+    run_ralph(&spool, opts, &mut h).unwrap();
+
+    let first = spool_core::ralph::state::load_iteration_transcript(&spool, "006-09_fixture", 1)
+        .unwrap()
+        .unwrap();
+    assert!(!first.prompt.contains("stale failure from an earlier run"));
+}
+
+#[test]
+fn consecutive_failures_stop_the_loop_at_the_limit() {
+    let td = tempfile::tempdir().unwrap();
+    let spool = td.path().join(".spool");
+    write_fixture_spool(&spool, "006-09_fixture");
+
+    let mut h = FixedHarness::new(
+        HarnessName::STUB,
+        vec![("".to_string(), "nope\n".to_string(), 1)],
+    );
+    let mut opts = default_opts();
+    opts.change_id = Some("006-09_fixture".to_string());
+    opts.max_failures = 2;
+    let err = run_ralph(&spool, opts, &mut h).unwrap_err();
+    assert!(err.to_string().contains("2 consecutive failure(s)"));
+    assert_eq!(h.idx, 2);
+
+    let first = spool_core::ralph::state::load_iteration_transcript(&spool, "006-09_fixture", 1)
+        .unwrap()
+        .unwrap();
+    assert_eq!(first.previous_attempts, 1);
+    let state = spool_core::ralph::state::load_state(&spool, "006-09_fixture")
+        .unwrap()
+        .unwrap();
+    assert_eq!(state.iteration, 0);
+    assert_eq!(state.history.len(), 2);
+    assert!(state.history.iter().all(|h| h.failed));
+}
+
+#[test]
+fn iterations_record_cost_and_max_cost_stops_the_loop() {
+    let td = tempfile::tempdir().unwrap();
+    let spool = td.path().join(".spool");
+    write_fixture_spool(&spool, "006-09_fixture");
+
+    let usage_line = r#"{"type":"turn.completed","usage":{"input_tokens":1000000,"cached_input_tokens":0,"output_tokens":100000}}"#;
+    let mut h = FixedHarness::new(
+        HarnessName::STUB,
+        vec![(format!("{usage_line}\n"), String::new(), 0)],
+    );
+    let pricing: spool_models::Model = serde_json::from_value(serde_json::json!({
+        "id": "openai/test",
+        "provider": {"id": "openai", "name": "OpenAI"},
+        "name": "Test",
+        "input_cost_per_million": 1.0,
+        "output_cost_per_million": 10.0
+    }))
+    .unwrap();
+
+    let mut opts = default_opts();
+    opts.change_id = Some("006-09_fixture".to_string());
+    opts.max_iterations = Some(10);
+    opts.pricing = Some(pricing);
+    opts.max_cost = Some(3.5);
+    run_ralph(&spool, opts, &mut h).unwrap();
+
+    let state = spool_core::ralph::state::load_state(&spool, "006-09_fixture")
+        .unwrap()
+        .unwrap();
+    // $2 per iteration: the limit is crossed on the second one.
+    assert_eq!(state.iteration, 2);
+    let first = &state.history[0];
+    assert_eq!(first.tokens.as_ref().unwrap().input_tokens, 1_000_000);
+    assert!((first.cost.unwrap() - 2.0).abs() < 1e-9);
+}
+
+#[test]
+fn failed_attempts_count_towards_the_cost_total() {
+    let td = tempfile::tempdir().unwrap();
+    let spool = td.path().join(".spool");
+    write_fixture_spool(&spool, "006-09_fixture");
+
+    let usage = |promise: &str| {
+        format!(
+            "{{\"type\":\"result\",\"usage\":{{\"input_tokens\":10,\"output_tokens\":5}},\"total_cost_usd\":0.5}}\n{promise}"
+        )
+    };
+    let mut h = FixedHarness::new(
+        HarnessName::STUB,
+        vec![
+            (usage(""), "boom\n".to_string(), 1),
+            (usage("<promise>COMPLETE</promise>\n"), String::new(), 0),
+        ],
+    );
+    let mut opts = default_opts();
+    opts.change_id = Some("006-09_fixture".to_string());
+    opts.max_cost = Some(0.75);
+    run_ralph(&spool, opts, &mut h).unwrap();
+
+    let state = spool_core::ralph::state::load_state(&spool, "006-09_fixture")
+        .unwrap()
+        .unwrap();
+    assert_eq!(state.iteration, 1);
+    assert_eq!(state.history.len(), 2);
+    assert!(state.history[0].failed);
+    assert!(!state.history[1].failed);
+    let total: f64 = state.history.iter().filter_map(|h| h.cost).sum();
+    assert!((total - 1.0).abs() < 1e-9);
+}
+
+#[test]
+fn max_duration_stops_the_loop_after_the_current_iteration() {
+    let td = tempfile::tempdir().unwrap();
+    let spool = td.path().join(".spool");
+    write_fixture_spool(&spool, "006-09_fixture");
+
+    let mut h = FixedHarness::new(HarnessName::STUB, vec![]);
+    let mut opts = default_opts();
+    opts.change_id = Some("006-09_fixture".to_string());
+    opts.max_iterations = Some(10);
+    opts.max_duration = Some(Duration::ZERO);
+    run_ralph(&spool, opts, &mut h).unwrap();
+
+    let state = spool_core::ralph::state::load_state(&spool, "006-09_fixture")
+        .unwrap()
+        .unwrap();
+    assert_eq!(state.iteration, 1);
+}
+
+#[test]
+fn hooks_get_loop_env_and_post_hook_failures_feed_the_next_prompt() {
+    let td = tempfile::tempdir().unwrap();
+    let spool = td.path().join(".spool");
+    write_fixture_spool(&spool, "006-09_fixture");
+    let log = td.path().join("hooks.log");
+    let log = log.display();
+
+    let mut h = FixedHarness::new(
+        HarnessName::STUB,
+        vec![
+            ("working\n".to_string(), String::new(), 0),
+            (
+                "<promise>COMPLETE</promise>\n".to_string(),
+                String::new(),
+                0,
+            ),
+        ],
+    );
+    let mut opts = default_opts();
+    opts.change_id = Some("006-09_fixture".to_string());
+    opts.hooks.pre_iteration = Some(format!(
+        "echo \"pre $SPOOL_RALPH_HOOK $SPOOL_CHANGE_ID $SPOOL_MODULE_ID $SPOOL_RALPH_ITERATION\" >> {log}"
+    ));
+    opts.hooks.post_iteration = Some(format!(
+        "echo \"post $SPOOL_RALPH_ITERATION $SPOOL_RALPH_EXIT_CODE $SPOOL_RALPH_PROMISE_FOUND\" >> {log}; \
+         if [ \"$SPOOL_RALPH_ITERATION\" = 1 ]; then echo 'lint: unused import'; exit 2; fi"
+    ));
+    opts.hooks.on_complete = Some(format!(
+        "echo \"complete $SPOOL_RALPH_ITERATION $SPOOL_RALPH_PROMISE_FOUND\" >> {log}"
+    ));
+    opts.hooks.on_failure = Some(format!("echo failure >> {log}"));
+    run_ralph(&spool, opts, &mut h).unwrap();
+
+    let lines = std::fs::read_to_string(td.path().join("hooks.log")).unwrap();
+    assert_eq!(
+        lines,
+        "pre pre_iteration 006-09_fixture 006 1\n\
+         post 1 0 false\n\
+         pre pre_iteration 006-09_fixture 006 2\n\
+         post 2 0 true\n\
+         complete 2 true\n"
+    );
+
+    let second = spool_core::ralph::state::load_iteration_transcript(&spool, "006-09_fixture", 2)
+        .unwrap()
+        .unwrap();
+    assert!(second.prompt.contains("## Post-Iteration Hook Failed"));
+    assert!(second.prompt.contains("failed with exit code 2"));
+    assert!(second.prompt.contains("lint: unused import"));
+    let feedback = spool_core::ralph::state::load_feedback(&spool, "006-09_fixture").unwrap();
+    assert!(feedback.trim().is_empty());
+}
+
+#[test]
+fn failing_pre_hook_skips_iterations_and_runs_on_failure() {
+    let td = tempfile::tempdir().unwrap();
+    let spool = td.path().join(".spool");
+    write_fixture_spool(&spool, "006-09_fixture");
+    let out = td.path().join("failure.txt");
+
+    let mut h = FixedHarness::new(HarnessName::STUB, vec![]);
+    let mut opts = default_opts();
+    opts.change_id = Some("006-09_fixture".to_string());
+    opts.max_failures = 2;
+    opts.hooks.pre_iteration = Some("exit 7".to_string());
+    opts.hooks.on_failure = Some(format!(
+        "echo \"$SPOOL_RALPH_ITERATION $SPOOL_RALPH_ERROR\" > {}",
+        out.display()
+    ));
+    let err = run_ralph(&spool, opts, &mut h).unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("pre_iteration hook failed 2 time(s) in a row (exit code 7)")
+    );
+    assert_eq!(h.idx, 0);
+    assert_eq!(
+        std::fs::read_to_string(&out).unwrap(),
+        "0 pre_iteration hook failed 2 time(s) in a row (exit code 7)\n"
+    );
+}
+
+#[test]
+fn ralph_hooks_load_from_project_config() {
+    let td = tempfile::tempdir().unwrap();
+    let spool = td.path().join(".spool");
+    std::fs::create_dir_all(&spool).unwrap();
+    std::fs::write(
+        spool.join("config.json"),
+        r#"{"ralph":{"hooks":{"pre_iteration":"cargo fmt","on_complete":"notify-send done"}}}"#,
+    )
+    .unwrap();
+
+    let ctx = spool_core::config::ConfigContext::default();
+    let hooks = spool_core::ralph::load_ralph_hooks(td.path(), &spool, &ctx).unwrap();
+    assert_eq!(hooks.pre_iteration.as_deref(), Some("cargo fmt"));
+    assert_eq!(hooks.on_complete.as_deref(), Some("notify-send done"));
+    assert!(hooks.post_iteration.is_none());
+
+    std::fs::write(
+        spool.join("config.json"),
+        r#"{"ralph":{"hooks":{"pre_iteration":42}}}"#,
+    )
+    .unwrap();
+    let err = spool_core::ralph::load_ralph_hooks(td.path(), &spool, &ctx).unwrap_err();
+    assert!(err.to_string().contains("Invalid ralph.hooks config"));
+}
@@ -0,0 +1,174 @@
+use spool_core::ralph::prompt::{BuildPromptOptions, build_ralph_prompt};
+use std::path::Path;
+
+fn write(path: &Path, contents: &str) {
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    std::fs::write(path, contents).unwrap();
+}
+
+fn options(harness: &str) -> BuildPromptOptions {
+    BuildPromptOptions {
+        change_id: Some("001-01_demo".to_string()),
+        module_id: None,
+        iteration: Some(2),
+        max_iterations: Some(5),
+        min_iterations: 1,
+        completion_promise: "DONE".to_string(),
+        context_content: Some("  remember the docs  ".to_string()),
+        feedback: None,
+        harness: Some(harness.to_string()),
+        recent_history: None,
+        context_budget: None,
+        ctx: Default::default(),
+    }
+}
+
+fn fixture() -> tempfile::TempDir {
+    let td = tempfile::tempdir().unwrap();
+    let spool = td.path().join(".spool");
+    write(
+        &spool.join("changes/001-01_demo/proposal.md"),
+        "## Why\nDemo\n",
+    );
+    write(
+        &spool.join("changes/001-01_demo/tasks.md"),
+        "## 1. Work\n- [x] 1.1 First\n- [ ] 1.2 Second\n- [ ] 1.3 Third\n",
+    );
+    td
+}
+
+#[test]
+fn default_preamble_renders_options_and_task_progress() {
+    let td = fixture();
+    let spool = td.path().join(".spool");
+
+    let prompt = build_ralph_prompt(&spool, "ship it", options("stub")).unwrap();
+    assert!(prompt.starts_with("# Ralph Wiggum Loop - Iteration 2\n"));
+    assert!(
+        prompt.contains("## Additional Context (added by user mid-loop)\n\nremember the docs\n")
+    );
+    assert!(prompt.contains("## Change Proposal (001-01_demo)"));
+    assert!(prompt.contains("ship it"));
+    assert!(prompt.contains("1 of 3 tasks complete (0 in progress, 2 pending)."));
+    assert!(prompt.contains("<promise>DONE</promise>"));
+    assert!(prompt.contains("## Current Iteration: 2 / 5 (min: 1)"));
+    assert!(!prompt.contains("TodoWrite"));
+    assert!(prompt.contains("remember the docs\n\n---\n\n## Your Task\n"));
+}
+
+#[test]
+fn opencode_variant_mentions_todowrite() {
+    let td = fixture();
+    let spool = td.path().join(".spool");
+
+    let mut opts = options("opencode");
+    opts.max_iterations = None;
+    opts.context_content = None;
+    let prompt = build_ralph_prompt(&spool, "ship it", opts).unwrap();
+    assert!(prompt.contains("Use the TodoWrite tool"));
+    assert!(prompt.contains("## Current Iteration: 2 (unlimited) (min: 1)"));
+    assert!(!prompt.contains("Additional Context"));
+}
+
+#[test]
+fn project_templates_override_the_embedded_preamble() {
+    let td = fixture();
+    let spool = td.path().join(".spool");
+    write(
+        &spool.join("ralph/preamble.md.j2"),
+        "Iteration {{ iteration }} for {{ change_id }} via {{ harness }}: {{ user_prompt }} ({{ tasks.remaining }} left){% if feedback %}\n{{ feedback }}{% endif %}",
+    );
+    write(
+        &spool.join("ralph/preamble.opencode.md.j2"),
+        "opencode {{ completion_promise }}",
+    );
+
+    let mut opts = options("stub");
+    opts.feedback = Some("## Previous Iteration Failed\n".to_string());
+    let prompt = build_ralph_prompt(&spool, "ship it", opts).unwrap();
+    assert_eq!(
+        prompt,
+        "Iteration 2 for 001-01_demo via stub: ship it (2 left)\n## Previous Iteration Failed"
+    );
+
+    let prompt = build_ralph_prompt(&spool, "ship it", options("opencode")).unwrap();
+    assert_eq!(prompt, "opencode DONE");
+
+    write(&spool.join("ralph/preamble.md.j2"), "{{ missing }}");
+    let err = build_ralph_prompt(&spool, "ship it", options("stub")).unwrap_err();
+    assert!(err.to_string().contains("Failed to render Ralph preamble"));
+}
+
+#[test]
+fn change_context_includes_schema_artifacts_in_build_order() {
+    let td = fixture();
+    let spool = td.path().join(".spool");
+    write(
+        &spool.join("changes/001-01_demo/specs/auth/spec.md"),
+        "## ADDED Requirements\n",
+    );
+    write(
+        &spool.join("changes/001-01_demo/design.md"),
+        "## Decisions\n",
+    );
+
+    let mut opts = options("stub");
+    opts.recent_history = Some("## Recent Git History\n\nabc123 Ralph loop iteration 1\n".into());
+    let prompt = build_ralph_prompt(&spool, "ship it", opts).unwrap();
+
+    let proposal = prompt.find("## Change Proposal (001-01_demo)").unwrap();
+    let specs = prompt
+        .find("## Change Specs (001-01_demo)\n\n### specs/auth/spec.md\n\n## ADDED Requirements")
+        .unwrap();
+    let design = prompt.find("## Change Design (001-01_demo)").unwrap();
+    let tasks = prompt.find("## Change Tasks (001-01_demo)").unwrap();
+    let history = prompt.find("abc123 Ralph loop iteration 1").unwrap();
+    let task = prompt.find("ship it").unwrap();
+    assert!(proposal < specs && proposal < design);
+    assert!(specs < tasks && design < tasks && tasks < history);
+    assert!(history < task);
+}
+
+#[test]
+fn change_context_resolves_the_schema_with_the_given_config_context() {
+    let td = fixture();
+    let spool = td.path().join(".spool");
+    let home = td.path().join("home");
+    write(
+        &home.join(".local/share/spool/schemas/notes-only/schema.yaml"),
+        "name: notes-only\nartifacts:\n  - id: notes\n    generates: notes.md\n    template: notes.md\n",
+    );
+    write(
+        &spool.join("changes/001-01_demo/.spool.yaml"),
+        "schema: notes-only\n",
+    );
+    write(&spool.join("changes/001-01_demo/notes.md"), "Some notes\n");
+
+    let mut opts = options("stub");
+    opts.ctx.home_dir = Some(home);
+    let prompt = build_ralph_prompt(&spool, "ship it", opts).unwrap();
+    assert!(prompt.contains("## Change Notes (001-01_demo)\n\nSome notes"));
+    assert!(!prompt.contains("## Change Proposal"));
+}
+
+#[test]
+fn context_budget_truncates_lower_priority_sections_first() {
+    let td = fixture();
+    let spool = td.path().join(".spool");
+    let design = format!("## Decisions\n{}", "- keep it simple\n".repeat(400));
+    write(&spool.join("changes/001-01_demo/design.md"), &design);
+
+    let mut opts = options("stub");
+    opts.recent_history = Some(format!("## Recent Git History\n{}", "x\n".repeat(100)));
+    opts.context_budget = Some(1_000);
+    let prompt = build_ralph_prompt(&spool, "ship it", opts).unwrap();
+
+    // History is lower priority than design and is dropped entirely; design is cut short.
+    assert!(!prompt.contains("## Recent Git History"));
+    assert!(prompt.contains("## Change Design (001-01_demo)"));
+    assert!(prompt.contains("[... truncated to fit the Ralph context budget]"));
+    assert!(prompt.len() < design.len());
+    assert!(prompt.contains("## Change Proposal (001-01_demo)\n\n## Why\nDemo"));
+    assert!(prompt.contains("## Change Tasks (001-01_demo)"));
+    assert!(prompt.contains("ship it"));
+}
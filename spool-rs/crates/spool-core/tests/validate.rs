@@ -1,4 +1,7 @@
-use spool_core::validate::{validate_change, validate_module, validate_spec_markdown};
+use spool_core::config::ConfigContext;
+use spool_core::validate::{
+    validate_change, validate_module, validate_schema, validate_spec_markdown,
+};
 use std::path::Path;
 
 fn write(path: &Path, contents: &str) {
@@ -115,3 +118,72 @@ Too short.
     assert!(!r.valid);
     assert!(r.summary.errors >= 1);
 }
+
+#[test]
+fn validate_schema_reports_every_issue_with_file_and_line() {
+    let td = tempfile::tempdir().unwrap();
+    let spool = td.path().join(".spool");
+    let schema_dir = spool.join("schemas/broken");
+    write(
+        &schema_dir.join("schema.yaml"),
+        "name: broken\nartifacts:\n  - id: a\n    generates: a.md\n    template: a.md\n    requires: [b]\n  - id: b\n    generates: ../b.md\n    template: b.md\n    requires: [a, zzz]\napply:\n  requires: [nope]\n  tracks: [todo.md, ../escape.md, /etc/tasks.md]\n",
+    );
+    write(&schema_dir.join("templates/a.md"), "# A\n");
+
+    let r = validate_schema(&spool, "broken", &ConfigContext::default(), false).unwrap();
+    assert!(!r.valid);
+    let found = |needle: &str| {
+        r.issues
+            .iter()
+            .find(|i| i.message.contains(needle))
+            .unwrap_or_else(|| panic!("missing issue '{needle}': {:?}", r.issues))
+    };
+    let unknown = found("requires unknown artifact 'zzz'");
+    assert_eq!(unknown.path, ".spool/schemas/broken/schema.yaml");
+    assert_eq!(unknown.line, Some(7));
+    found("unsupported generates pattern '../b.md'");
+    found("Template for artifact 'b' not found");
+    found("Dependency cycle between artifacts: a, b");
+    found("apply.requires names unknown artifact 'nope'");
+    assert_eq!(found("apply.tracks 'todo.md'").level, "WARNING");
+    found("apply.tracks must be a relative file path, got '../escape.md'");
+    found("apply.tracks must be a relative file path, got '/etc/tasks.md'");
+    assert_eq!(r.summary.errors, 7);
+}
+
+#[test]
+fn validate_schema_reports_duplicate_ids_and_yaml_errors() {
+    let td = tempfile::tempdir().unwrap();
+    let spool = td.path().join(".spool");
+    write(
+        &spool.join("schemas/dup/schema.yaml"),
+        "name: dup\nartifacts:\n  - id: a\n    generates: a.md\n    template: a.md\n  - id: a\n    generates: b.md\n  - id: a\n    generates: c.md\n    template: c.md\n",
+    );
+    write(
+        &spool.join("schemas/garbled/schema.yaml"),
+        "name: garbled\nartifacts: [\n",
+    );
+    let ctx = ConfigContext::default();
+
+    let r = validate_schema(&spool, "dup", &ctx, false).unwrap();
+    let messages: Vec<(&str, Option<u32>)> = r
+        .issues
+        .iter()
+        .map(|i| (i.message.as_str(), i.line))
+        .collect();
+    assert!(messages.contains(&("Duplicate artifact id 'a'", Some(6))));
+    assert!(messages.contains(&("Duplicate artifact id 'a'", Some(8))));
+    assert!(messages.contains(&("Artifact 'a' needs `generates` and `template`", Some(6))));
+
+    let r = validate_schema(&spool, "garbled", &ctx, false).unwrap();
+    assert_eq!(r.issues.len(), 1);
+    assert!(r.issues[0].message.starts_with("Invalid schema YAML"));
+    assert!(r.issues[0].line.is_some());
+
+    assert!(
+        validate_schema(&spool, "spec-driven", &ctx, true)
+            .unwrap()
+            .valid
+    );
+    assert!(validate_schema(&spool, "missing", &ctx, false).is_err());
+}
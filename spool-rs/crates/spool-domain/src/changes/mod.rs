@@ -198,6 +198,16 @@ pub struct ChangeSummary {
     pub has_specs: bool,
     /// Whether tasks.md exists and has tasks
     pub has_tasks: bool,
+    /// Progress per tracking file, in tracking order; the task counts above are their sums
+    pub tracks: Vec<TrackProgress>,
+}
+
+/// Task progress for one tracking file of a change.
+#[derive(Debug, Clone)]
+pub struct TrackProgress {
+    /// File name relative to the change directory (e.g. `tasks.md`)
+    pub file: String,
+    pub progress: ProgressInfo,
 }
 
 impl ChangeSummary {
@@ -382,6 +392,7 @@ mod tests {
             has_design: false,
             has_specs: false,
             has_tasks: false,
+            tracks: Vec::new(),
         };
 
         assert_eq!(summary.status(), ChangeStatus::NoTasks);
@@ -409,6 +420,7 @@ mod tests {
             has_design: false,
             has_specs: false,
             has_tasks: false,
+            tracks: Vec::new(),
         };
 
         assert_eq!(summary.work_status(), ChangeWorkStatus::Draft);
@@ -5,7 +5,9 @@ use miette::{IntoDiagnostic, Result, miette};
 use std::fs;
 use std::path::Path;
 
-use super::{Change, ChangeStatus, ChangeSummary, Spec, extract_module_id, parse_change_id};
+use super::{
+    Change, ChangeStatus, ChangeSummary, Spec, TrackProgress, extract_module_id, parse_change_id,
+};
 use crate::tasks::TaskRepository;
 
 /// Repository for accessing change data.
@@ -27,6 +29,13 @@ impl<'a> ChangeRepository<'a> {
         }
     }
 
+    /// Use `tracks` to find each change's task tracking files (see
+    /// [`TaskRepository::with_tracks`]).
+    pub fn with_tracks(mut self, tracks: impl Fn(&Path, &str) -> Vec<String> + 'a) -> Self {
+        self.task_repo = self.task_repo.with_tracks(tracks);
+        self
+    }
+
     /// Get the path to the changes directory.
     fn changes_dir(&self) -> std::path::PathBuf {
         self.spool_path.join("changes")
@@ -170,7 +179,18 @@ impl<'a> ChangeRepository<'a> {
             .find_change_dir(id)
             .ok_or_else(|| miette!("Change not found: {}", id))?;
 
-        let progress = self.task_repo.get_progress(&actual_id)?;
+        let tracked = self.task_repo.load_tracked(&actual_id)?;
+        let tracks: Vec<TrackProgress> = tracked
+            .into_iter()
+            .map(|t| TrackProgress {
+                file: t.file,
+                progress: t.result.progress,
+            })
+            .collect();
+        let mut progress = crate::tasks::TasksParseResult::empty().progress;
+        for t in &tracks {
+            progress.add(&t.progress);
+        }
         let completed_tasks = progress.complete as u32;
         let shelved_tasks = progress.shelved as u32;
         let in_progress_tasks = progress.in_progress as u32;
@@ -196,6 +216,7 @@ impl<'a> ChangeRepository<'a> {
             has_design,
             has_specs,
             has_tasks,
+            tracks,
         })
     }
 
@@ -0,0 +1,504 @@
+//! Typed model for `module.md`.
+//!
+//! A module document has a `# Title`, then `## Purpose`, `## Scope`, an optional
+//! `## Depends On`, and a `## Changes` checklist. Parsing keeps the original text so
+//! that serializing an unmodified document reproduces it byte-for-byte, and a modified
+//! document only rewrites the sections (or checklist lines) that actually changed.
+
+const PURPOSE_PLACEHOLDER: &str = "<!-- Describe the purpose of this module/epic -->";
+const SCOPE_PLACEHOLDER: &str = "<!-- List the scope of this module -->";
+const CHANGES_PLACEHOLDER: &str = "<!-- Changes will be listed here as they are created -->";
+const PLANNED_MARKER: &str = "(planned)";
+
+/// One entry in the `## Changes` checklist.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModuleChangeEntry {
+    /// Change id (e.g., "005-01_add-auth").
+    pub id: String,
+    /// Whether the checkbox is ticked.
+    pub completed: bool,
+    /// Whether the entry carries the `(planned)` marker.
+    pub planned: bool,
+    /// Any text after the id (and planned marker), including its leading whitespace.
+    pub note: String,
+    /// Body line index within the parsed `## Changes` section.
+    source_line: Option<usize>,
+}
+
+impl ModuleChangeEntry {
+    /// Create a new, unchecked entry.
+    pub fn new(id: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            completed: false,
+            planned: false,
+            note: String::new(),
+            source_line: None,
+        }
+    }
+
+    fn render(&self) -> String {
+        let check = if self.completed { "x" } else { " " };
+        let planned = if self.planned { " (planned)" } else { "" };
+        format!("- [{check}] {}{planned}{}", self.id, self.note)
+    }
+}
+
+/// Parsed `module.md`.
+#[derive(Debug, Clone)]
+pub struct ModuleDocument {
+    pub title: String,
+    pub purpose: Option<String>,
+    pub scope: Vec<String>,
+    pub depends_on: Vec<String>,
+    pub changes: Vec<ModuleChangeEntry>,
+    source: Option<Source>,
+}
+
+#[derive(Debug, Clone)]
+struct Source {
+    preamble: Vec<String>,
+    sections: Vec<RawSection>,
+    trailing_newline: bool,
+    title: String,
+    purpose: Option<String>,
+    scope: Vec<String>,
+    depends_on: Vec<String>,
+    changes: Vec<ModuleChangeEntry>,
+}
+
+#[derive(Debug, Clone)]
+struct RawSection {
+    heading_line: String,
+    kind: SectionKind,
+    body: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SectionKind {
+    Purpose,
+    Scope,
+    DependsOn,
+    Changes,
+    Other,
+}
+
+impl SectionKind {
+    fn from_heading(heading: &str) -> Self {
+        let h = heading.trim();
+        if h.eq_ignore_ascii_case("Purpose") {
+            SectionKind::Purpose
+        } else if h.eq_ignore_ascii_case("Scope") {
+            SectionKind::Scope
+        } else if h.eq_ignore_ascii_case("Depends On") {
+            SectionKind::DependsOn
+        } else if h.eq_ignore_ascii_case("Changes") {
+            SectionKind::Changes
+        } else {
+            SectionKind::Other
+        }
+    }
+}
+
+impl ModuleDocument {
+    /// Create a new document that has not been read from disk.
+    pub fn new(
+        title: impl Into<String>,
+        purpose: Option<String>,
+        scope: Vec<String>,
+        depends_on: Vec<String>,
+    ) -> Self {
+        Self {
+            title: title.into(),
+            purpose,
+            scope,
+            depends_on,
+            changes: Vec::new(),
+            source: None,
+        }
+    }
+
+    /// Parse `module.md` content.
+    pub fn parse(markdown: &str) -> Self {
+        let normalized = markdown.replace("\r\n", "\n");
+        let trailing_newline = normalized.ends_with('\n');
+        let mut lines: Vec<String> = normalized.split('\n').map(str::to_string).collect();
+        if trailing_newline {
+            lines.pop();
+        }
+
+        let mut preamble: Vec<String> = Vec::new();
+        let mut sections: Vec<RawSection> = Vec::new();
+        for line in lines {
+            if let Some(heading) = line.trim_start().strip_prefix("## ") {
+                sections.push(RawSection {
+                    kind: SectionKind::from_heading(heading),
+                    heading_line: line.clone(),
+                    body: Vec::new(),
+                });
+                continue;
+            }
+            match sections.last_mut() {
+                Some(section) => section.body.push(line),
+                None => preamble.push(line),
+            }
+        }
+
+        let title = preamble
+            .iter()
+            .find_map(|l| l.trim().strip_prefix("# ").map(|t| t.trim().to_string()))
+            .unwrap_or_default();
+        let section = |kind: SectionKind| sections.iter().find(|s| s.kind == kind);
+        let purpose = section(SectionKind::Purpose)
+            .map(|s| s.body.join("\n").trim().to_string())
+            .filter(|s| !s.is_empty());
+        let scope = section(SectionKind::Scope)
+            .map(|s| parse_bullets(&s.body))
+            .unwrap_or_default();
+        let depends_on = section(SectionKind::DependsOn)
+            .map(|s| parse_bullets(&s.body))
+            .unwrap_or_default();
+        let changes = section(SectionKind::Changes)
+            .map(|s| parse_changes(&s.body))
+            .unwrap_or_default();
+
+        Self {
+            title: title.clone(),
+            purpose: purpose.clone(),
+            scope: scope.clone(),
+            depends_on: depends_on.clone(),
+            changes: changes.clone(),
+            source: Some(Source {
+                preamble,
+                sections,
+                trailing_newline,
+                title,
+                purpose,
+                scope,
+                depends_on,
+                changes,
+            }),
+        }
+    }
+
+    /// Look up a checklist entry by exact change id.
+    pub fn change(&self, id: &str) -> Option<&ModuleChangeEntry> {
+        self.changes.iter().find(|c| c.id == id)
+    }
+
+    /// Append an unchecked entry. Returns `false` if the change is already listed.
+    pub fn add_change(&mut self, id: &str) -> bool {
+        if self.change(id).is_some() {
+            return false;
+        }
+        self.changes.push(ModuleChangeEntry::new(id));
+        true
+    }
+
+    /// Tick the checkbox for an exact change id. Returns `false` if it is not listed.
+    pub fn mark_change_complete(&mut self, id: &str) -> bool {
+        let Some(entry) = self.changes.iter_mut().find(|c| c.id == id) else {
+            return false;
+        };
+        entry.completed = true;
+        true
+    }
+
+    /// Serialize back to markdown.
+    pub fn to_markdown(&self) -> String {
+        match &self.source {
+            Some(source) => self.render_with_source(source),
+            None => self.render_canonical(),
+        }
+    }
+
+    fn render_canonical(&self) -> String {
+        // Match TS formatting (generateModuleContent):
+        // - No blank line between section header and content
+        // - Omit "Depends On" section when empty
+        let mut out = String::new();
+        out.push_str(&format!("# {}\n\n", self.title));
+
+        out.push_str("## Purpose\n");
+        out.push_str(&self.purpose_body().join("\n"));
+        out.push_str("\n\n");
+
+        out.push_str("## Scope\n");
+        out.push_str(&self.scope_body().join("\n"));
+        out.push_str("\n\n");
+
+        if !self.depends_on.is_empty() {
+            out.push_str("## Depends On\n");
+            out.push_str(&bullets(&self.depends_on).join("\n"));
+            out.push_str("\n\n");
+        }
+
+        out.push_str("## Changes\n");
+        out.push_str(&self.changes_body().join("\n"));
+        out.push('\n');
+        out
+    }
+
+    fn render_with_source(&self, source: &Source) -> String {
+        let mut lines: Vec<String> = Vec::new();
+
+        let mut title_written = false;
+        for line in &source.preamble {
+            if !title_written && line.trim().starts_with("# ") {
+                title_written = true;
+                if self.title != source.title {
+                    lines.push(format!("# {}", self.title));
+                    continue;
+                }
+            }
+            lines.push(line.clone());
+        }
+        if !title_written && !self.title.is_empty() {
+            lines.insert(0, format!("# {}", self.title));
+            lines.insert(1, String::new());
+        }
+
+        let has = |kind: SectionKind| source.sections.iter().any(|s| s.kind == kind);
+        let mut seen: Vec<SectionKind> = Vec::new();
+        for section in &source.sections {
+            if section.kind == SectionKind::Changes
+                && !has(SectionKind::DependsOn)
+                && !self.depends_on.is_empty()
+            {
+                push_new_section(&mut lines, "Depends On", bullets(&self.depends_on));
+            }
+
+            lines.push(section.heading_line.clone());
+            let first = !seen.contains(&section.kind);
+            seen.push(section.kind);
+            if !first {
+                lines.extend(section.body.iter().cloned());
+                continue;
+            }
+
+            match section.kind {
+                SectionKind::Purpose if self.purpose != source.purpose => {
+                    lines.extend(reflow(&section.body, self.purpose_body()));
+                }
+                SectionKind::Scope if self.scope != source.scope => {
+                    lines.extend(reflow(&section.body, self.scope_body()));
+                }
+                SectionKind::DependsOn if self.depends_on != source.depends_on => {
+                    lines.extend(reflow(&section.body, bullets(&self.depends_on)));
+                }
+                SectionKind::Changes if self.changes != source.changes => {
+                    lines.extend(self.render_changes_section(&section.body, source));
+                }
+                _ => lines.extend(section.body.iter().cloned()),
+            }
+        }
+
+        if !has(SectionKind::Purpose) && self.purpose.is_some() {
+            push_new_section(&mut lines, "Purpose", self.purpose_body());
+        }
+        if !has(SectionKind::Scope) && !self.scope.is_empty() {
+            push_new_section(&mut lines, "Scope", self.scope_body());
+        }
+        if !has(SectionKind::Changes) && !self.changes.is_empty() {
+            if !has(SectionKind::DependsOn) && !self.depends_on.is_empty() {
+                push_new_section(&mut lines, "Depends On", bullets(&self.depends_on));
+            }
+            push_new_section(&mut lines, "Changes", self.changes_body());
+        }
+
+        let mut out = lines.join("\n");
+        if source.trailing_newline {
+            out.push('\n');
+        }
+        out
+    }
+
+    /// Rewrite only the checklist lines that changed, keeping surrounding notes intact.
+    fn render_changes_section(&self, body: &[String], source: &Source) -> Vec<String> {
+        let original_lines: Vec<usize> = source
+            .changes
+            .iter()
+            .filter_map(|c| c.source_line)
+            .collect();
+        let insert_after = original_lines.iter().max().copied().or_else(|| {
+            body.iter()
+                .rposition(|l| !l.trim().is_empty() && l.trim() != CHANGES_PLACEHOLDER)
+        });
+        let new_entries: Vec<String> = self
+            .changes
+            .iter()
+            .filter(|c| c.source_line.is_none())
+            .map(ModuleChangeEntry::render)
+            .collect();
+
+        let mut out: Vec<String> = Vec::new();
+        if insert_after.is_none() {
+            let lead = body.iter().take_while(|l| l.trim().is_empty()).count();
+            out.extend(body[..lead].iter().cloned());
+            out.extend(new_entries.iter().cloned());
+            for line in &body[lead..] {
+                if line.trim() == CHANGES_PLACEHOLDER && !self.changes.is_empty() {
+                    continue;
+                }
+                out.push(line.clone());
+            }
+            return out;
+        }
+
+        for (idx, line) in body.iter().enumerate() {
+            if original_lines.contains(&idx) {
+                let current = self.changes.iter().find(|c| c.source_line == Some(idx));
+                let original = source.changes.iter().find(|c| c.source_line == Some(idx));
+                match current {
+                    Some(c) if Some(c) == original => out.push(line.clone()),
+                    Some(c) => out.push(c.render()),
+                    None => {}
+                }
+            } else if line.trim() != CHANGES_PLACEHOLDER || self.changes.is_empty() {
+                out.push(line.clone());
+            }
+            if Some(idx) == insert_after {
+                out.extend(new_entries.iter().cloned());
+            }
+        }
+        out
+    }
+
+    fn purpose_body(&self) -> Vec<String> {
+        self.purpose
+            .as_deref()
+            .unwrap_or(PURPOSE_PLACEHOLDER)
+            .lines()
+            .map(str::to_string)
+            .collect()
+    }
+
+    fn scope_body(&self) -> Vec<String> {
+        if self.scope.is_empty() {
+            return vec![SCOPE_PLACEHOLDER.to_string()];
+        }
+        bullets(&self.scope)
+    }
+
+    fn changes_body(&self) -> Vec<String> {
+        if self.changes.is_empty() {
+            return vec![CHANGES_PLACEHOLDER.to_string()];
+        }
+        self.changes.iter().map(ModuleChangeEntry::render).collect()
+    }
+}
+
+/// Replace a section's content while keeping its leading and trailing blank lines.
+fn reflow(body: &[String], content: Vec<String>) -> Vec<String> {
+    let lead = body.iter().take_while(|l| l.trim().is_empty()).count();
+    let trail = body[lead..]
+        .iter()
+        .rev()
+        .take_while(|l| l.trim().is_empty())
+        .count();
+    let mut out: Vec<String> = Vec::new();
+    out.extend(body[..lead].iter().cloned());
+    out.extend(content);
+    out.extend(body[body.len() - trail..].iter().cloned());
+    out
+}
+
+fn push_new_section(lines: &mut Vec<String>, heading: &str, content: Vec<String>) {
+    if lines.last().is_some_and(|l| !l.trim().is_empty()) {
+        lines.push(String::new());
+    }
+    lines.push(format!("## {heading}"));
+    lines.extend(content);
+    lines.push(String::new());
+}
+
+fn bullets(items: &[String]) -> Vec<String> {
+    items.iter().map(|s| format!("- {s}")).collect()
+}
+
+fn parse_bullets(body: &[String]) -> Vec<String> {
+    let mut items = Vec::new();
+    for line in body {
+        let t = line.trim();
+        if let Some(rest) = t.strip_prefix("- ").or_else(|| t.strip_prefix("* ")) {
+            let s = rest.trim();
+            if !s.is_empty() {
+                items.push(s.to_string());
+            }
+        }
+    }
+    items
+}
+
+fn parse_changes(body: &[String]) -> Vec<ModuleChangeEntry> {
+    let mut out = Vec::new();
+    for (idx, line) in body.iter().enumerate() {
+        let t = line.trim();
+        let (completed, after) = if let Some(rest) = t.strip_prefix("- [") {
+            // - [x] id (planned)
+            let mut chars = rest.chars();
+            let checked = chars.next().unwrap_or(' ');
+            let Some(after) = chars.as_str().strip_prefix(']') else {
+                continue;
+            };
+            (checked == 'x' || checked == 'X', after.trim_start())
+        } else if let Some(rest) = t.strip_prefix("- ").or_else(|| t.strip_prefix("* ")) {
+            (false, rest.trim_start())
+        } else {
+            continue;
+        };
+
+        let id = after.split_whitespace().next().unwrap_or("");
+        if id.is_empty() {
+            continue;
+        }
+        let rest = &after[id.len()..];
+        let planned = rest.contains(PLANNED_MARKER);
+        let note = if planned {
+            rest.replacen(&format!(" {PLANNED_MARKER}"), "", 1)
+                .replacen(PLANNED_MARKER, "", 1)
+        } else {
+            rest.to_string()
+        };
+        out.push(ModuleChangeEntry {
+            id: id.to_string(),
+            completed,
+            planned,
+            note: note.trim_end().to_string(),
+            source_line: Some(idx),
+        });
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn canonical_render_matches_generated_module_layout() {
+        let mut doc = ModuleDocument::new(
+            "Dev Tooling",
+            None,
+            vec!["*".to_string()],
+            vec!["001".to_string()],
+        );
+        doc.add_change("005-01_first");
+        assert_eq!(
+            doc.to_markdown(),
+            "# Dev Tooling\n\n## Purpose\n<!-- Describe the purpose of this module/epic -->\n\n## Scope\n- *\n\n## Depends On\n- 001\n\n## Changes\n- [ ] 005-01_first\n"
+        );
+    }
+
+    #[test]
+    fn parse_handles_plain_bullets_and_planned_marker() {
+        let doc = ModuleDocument::parse(
+            "# M\n\n## Changes\n- [X] 001-01_a (planned) extra\n- 001-02_b\n- [ ]\n",
+        );
+        assert_eq!(doc.changes.len(), 2);
+        assert!(doc.changes[0].completed);
+        assert!(doc.changes[0].planned);
+        assert_eq!(doc.changes[0].note, " extra");
+        assert!(!doc.changes[1].completed);
+    }
+}
@@ -1,10 +1,12 @@
 //! Module domain models and repository.
 //!
-//! This module provides domain models for Spool modules and a repository
-//! for loading and querying module data.
+//! This module provides domain models for Spool modules, a repository
+//! for loading and querying module data, and a typed `module.md` document.
 
+mod document;
 mod repository;
 
+pub use document::{ModuleChangeEntry, ModuleDocument};
 pub use repository::ModuleRepository;
 
 use std::path::PathBuf;
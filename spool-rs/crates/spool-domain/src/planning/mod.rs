@@ -1,6 +1,9 @@
-use regex::Regex;
 use std::path::{Path, PathBuf};
 
+mod roadmap;
+
+pub use roadmap::{ChangeProgress, CurrentMilestone, Milestone, Phase, PhaseStatus, Roadmap};
+
 pub fn planning_dir(spool_path: &Path) -> PathBuf {
     spool_path.join("planning")
 }
@@ -42,7 +45,7 @@ pub fn init_planning_structure(
     if !project_path.exists() {
         std::fs::write(project_path, project_md_template(None, None))?;
     }
-    let roadmap_path = planning.join("ROADMAP.md");
+    let roadmap_path = roadmap_path(spool_path);
     if !roadmap_path.exists() {
         std::fs::write(roadmap_path, roadmap_md_template())?;
     }
@@ -53,52 +56,30 @@ pub fn init_planning_structure(
     Ok(())
 }
 
+pub fn roadmap_path(spool_path: &Path) -> PathBuf {
+    planning_dir(spool_path).join("ROADMAP.md")
+}
+
 pub fn read_current_progress(roadmap_contents: &str) -> Option<(String, String, String)> {
-    let re = Regex::new(r"## Current Milestone: (.+)\n- Status: (.+)\n- Phase: (.+)").unwrap();
-    let caps = re.captures(roadmap_contents)?;
-    Some((
-        caps[1].to_string(),
-        caps[2].to_string(),
-        caps[3].to_string(),
-    ))
+    let current = Roadmap::parse(roadmap_contents).current?;
+    Some((current.name, current.status, current.phase))
 }
 
 pub fn read_phase_rows(roadmap_contents: &str) -> Vec<(String, String, String, String)> {
+    let roadmap = Roadmap::parse(roadmap_contents);
     let mut rows: Vec<(String, String, String, String)> = Vec::new();
-
-    let mut in_table = false;
-    let mut saw_sep = false;
-    for line in roadmap_contents.lines() {
-        let t = line.trim();
-        if t == "| Phase | Name | Status | Changes |" {
-            in_table = true;
-            saw_sep = false;
-            continue;
-        }
-        if !in_table {
-            continue;
-        }
-        if !saw_sep {
-            if t.starts_with("|-------") {
-                saw_sep = true;
-            }
-            continue;
-        }
-        if t.is_empty() || !t.starts_with('|') {
-            break;
-        }
-
-        let cols: Vec<String> = t
-            .split('|')
-            .map(|c| c.trim().to_string())
-            .filter(|c| !c.is_empty())
-            .collect();
-        if cols.len() >= 4 {
+    for milestone in roadmap.milestones {
+        for phase in milestone.phases {
+            let changes = if phase.changes.is_empty() {
+                "-".to_string()
+            } else {
+                phase.changes.join(", ")
+            };
             rows.push((
-                cols[0].clone(),
-                cols[1].clone(),
-                cols[2].clone(),
-                cols[3].clone(),
+                phase.number,
+                phase.name,
+                phase.status.as_str().to_string(),
+                changes,
             ));
         }
     }
@@ -0,0 +1,834 @@
+//! Typed model for `planning/ROADMAP.md`.
+//!
+//! The roadmap has a `## Current Milestone: <name>` summary, a `## Milestones` section with
+//! one `### <name>` block (target line plus phase table) per milestone, and a
+//! `## Completed Milestones` list. Parsing keeps the original lines so unchanged parts are
+//! written back verbatim; only the blocks that were modified are regenerated.
+
+const PHASE_TABLE_HEADER: &str = "| Phase | Name | Status | Changes |";
+const PHASE_TABLE_SEPARATOR: &str = "|-------|------|--------|---------|";
+const TARGET_PLACEHOLDER: &str = "[Define the goal for this milestone]";
+
+/// Status of a roadmap phase.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PhaseStatus {
+    Pending,
+    InProgress,
+    Complete,
+}
+
+impl PhaseStatus {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            PhaseStatus::Pending => "Pending",
+            PhaseStatus::InProgress => "In Progress",
+            PhaseStatus::Complete => "Complete",
+        }
+    }
+
+    /// Parse a status label (case-insensitive); unknown labels are treated as pending.
+    pub fn from_label(label: &str) -> Self {
+        let l = label.trim().to_ascii_lowercase().replace(['-', '_'], " ");
+        match l.as_str() {
+            "complete" | "completed" | "done" => PhaseStatus::Complete,
+            "in progress" | "active" => PhaseStatus::InProgress,
+            _ => PhaseStatus::Pending,
+        }
+    }
+}
+
+/// Task progress for a change linked to a phase.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ChangeProgress {
+    pub complete: usize,
+    pub total: usize,
+}
+
+impl ChangeProgress {
+    pub fn is_complete(self) -> bool {
+        self.total > 0 && self.complete >= self.total
+    }
+}
+
+/// One row of a milestone's phase table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Phase {
+    pub number: String,
+    pub name: String,
+    pub status: PhaseStatus,
+    /// Change ids whose task progress drives this phase's status.
+    pub changes: Vec<String>,
+    /// Line index of the table row this phase was parsed from.
+    row: Option<usize>,
+}
+
+impl Phase {
+    pub fn new(number: impl Into<String>, name: impl Into<String>) -> Self {
+        Self {
+            number: number.into(),
+            name: name.into(),
+            status: PhaseStatus::Pending,
+            changes: Vec::new(),
+            row: None,
+        }
+    }
+
+    /// Status derived from linked changes, or the recorded status when none are linked.
+    pub fn computed_status(&self, progress: &impl Fn(&str) -> ChangeProgress) -> PhaseStatus {
+        if self.changes.is_empty() {
+            return self.status;
+        }
+        let all: Vec<ChangeProgress> = self.changes.iter().map(|c| progress(c)).collect();
+        if all.iter().all(|p| p.is_complete()) {
+            PhaseStatus::Complete
+        } else if all.iter().any(|p| p.complete > 0 || p.is_complete()) {
+            PhaseStatus::InProgress
+        } else {
+            PhaseStatus::Pending
+        }
+    }
+
+    fn render(&self) -> String {
+        let changes = if self.changes.is_empty() {
+            "-".to_string()
+        } else {
+            self.changes.join(", ")
+        };
+        format!(
+            "| {} | {} | {} | {changes} |",
+            self.number,
+            self.name,
+            self.status.as_str()
+        )
+    }
+}
+
+/// A `### <name>` block under `## Milestones`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Milestone {
+    pub name: String,
+    pub target: Option<String>,
+    pub phases: Vec<Phase>,
+}
+
+impl Milestone {
+    pub fn phase_mut(&mut self, number: &str) -> Option<&mut Phase> {
+        self.phases.iter_mut().find(|p| p.number == number)
+    }
+}
+
+/// The `## Current Milestone` summary.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CurrentMilestone {
+    pub name: String,
+    pub status: String,
+    pub phase: String,
+}
+
+/// Parsed `ROADMAP.md`.
+#[derive(Debug, Clone)]
+pub struct Roadmap {
+    pub current: Option<CurrentMilestone>,
+    pub milestones: Vec<Milestone>,
+    /// Entries under `## Completed Milestones`.
+    pub completed: Vec<String>,
+    source: Source,
+}
+
+#[derive(Debug, Clone, Default)]
+struct Source {
+    lines: Vec<String>,
+    trailing_newline: bool,
+    /// Whether headings are followed by a blank line (mdformat style).
+    spaced: bool,
+    current: Option<CurrentSpan>,
+    milestones_heading: Option<usize>,
+    milestones_end: usize,
+    milestones: Vec<MilestoneSpan>,
+    completed: Option<(usize, usize)>,
+    snapshot_current: Option<CurrentMilestone>,
+    snapshot_milestones: Vec<Milestone>,
+    snapshot_completed: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+struct CurrentSpan {
+    heading: usize,
+    status: Option<usize>,
+    phase: Option<usize>,
+}
+
+#[derive(Debug, Clone)]
+struct MilestoneSpan {
+    start: usize,
+    end: usize,
+    target: Option<usize>,
+    table: Option<(usize, usize)>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Top {
+    Other,
+    Current,
+    Milestones,
+    Completed,
+}
+
+impl Roadmap {
+    /// Parse `ROADMAP.md` content.
+    pub fn parse(markdown: &str) -> Self {
+        let normalized = markdown.replace("\r\n", "\n");
+        let trailing_newline = normalized.ends_with('\n');
+        let lines: Vec<String> = normalized.lines().map(str::to_string).collect();
+
+        let mut source = Source {
+            trailing_newline,
+            milestones_end: lines.len(),
+            ..Source::default()
+        };
+        let mut current: Option<CurrentMilestone> = None;
+        let mut milestones: Vec<Milestone> = Vec::new();
+        let mut completed: Vec<String> = Vec::new();
+        let mut top = Top::Other;
+        let mut saw_heading = false;
+
+        for (idx, line) in lines.iter().enumerate() {
+            let t = line.trim();
+            if let Some(h) = t.strip_prefix("## ") {
+                if !saw_heading {
+                    saw_heading = true;
+                    source.spaced = lines.get(idx + 1).is_some_and(|l| l.trim().is_empty());
+                }
+                if top == Top::Milestones {
+                    source.milestones_end = idx;
+                    if let Some(last) = source.milestones.last_mut() {
+                        last.end = idx;
+                    }
+                }
+                if let Some((_, end)) = source.completed.as_mut()
+                    && top == Top::Completed
+                {
+                    *end = idx;
+                }
+                top = if let Some(name) = h.strip_prefix("Current Milestone:") {
+                    current = Some(CurrentMilestone {
+                        name: name.trim().to_string(),
+                        status: String::new(),
+                        phase: String::new(),
+                    });
+                    source.current = Some(CurrentSpan {
+                        heading: idx,
+                        status: None,
+                        phase: None,
+                    });
+                    Top::Current
+                } else if h.trim() == "Milestones" {
+                    source.milestones_heading = Some(idx);
+                    Top::Milestones
+                } else if h.trim() == "Completed Milestones" {
+                    source.completed = Some((idx + 1, lines.len()));
+                    Top::Completed
+                } else {
+                    Top::Other
+                };
+                continue;
+            }
+
+            match top {
+                Top::Other => {}
+                Top::Current => {
+                    let (Some(cur), Some(span)) = (current.as_mut(), source.current.as_mut())
+                    else {
+                        continue;
+                    };
+                    if let Some(v) = t.strip_prefix("- Status:") {
+                        cur.status = v.trim().to_string();
+                        span.status = Some(idx);
+                    } else if let Some(v) = t.strip_prefix("- Phase:") {
+                        cur.phase = v.trim().to_string();
+                        span.phase = Some(idx);
+                    }
+                }
+                Top::Milestones => {
+                    if let Some(name) = t.strip_prefix("### ") {
+                        if let Some(last) = source.milestones.last_mut() {
+                            last.end = idx;
+                        }
+                        milestones.push(Milestone {
+                            name: name.trim().to_string(),
+                            target: None,
+                            phases: Vec::new(),
+                        });
+                        source.milestones.push(MilestoneSpan {
+                            start: idx,
+                            end: lines.len(),
+                            target: None,
+                            table: None,
+                        });
+                        continue;
+                    }
+                    let (Some(m), Some(span)) =
+                        (milestones.last_mut(), source.milestones.last_mut())
+                    else {
+                        continue;
+                    };
+                    if let Some(target) = t.strip_prefix("Target:") {
+                        let target = target.trim();
+                        if !is_placeholder(target) && !target.is_empty() {
+                            m.target = Some(target.to_string());
+                        }
+                        span.target = Some(idx);
+                        continue;
+                    }
+                    match span.table {
+                        None if t.starts_with("| Phase") => span.table = Some((idx, idx + 1)),
+                        Some((start, end)) if end == idx && t.starts_with('|') => {
+                            span.table = Some((start, idx + 1));
+                            if t.starts_with("|--") || t.starts_with("| --") {
+                                continue;
+                            }
+                            if let Some(phase) = parse_phase_row(t, idx) {
+                                m.phases.push(phase);
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+                Top::Completed => {
+                    if let Some(item) = t.strip_prefix("- ") {
+                        completed.push(item.trim().to_string());
+                    }
+                }
+            }
+        }
+
+        source.snapshot_current = current.clone();
+        source.snapshot_milestones = milestones.clone();
+        source.snapshot_completed = completed.clone();
+        source.lines = lines;
+
+        Self {
+            current,
+            milestones,
+            completed,
+            source,
+        }
+    }
+
+    pub fn milestone(&self, name: &str) -> Option<&Milestone> {
+        self.milestones.iter().find(|m| m.name == name)
+    }
+
+    pub fn milestone_mut(&mut self, name: &str) -> Option<&mut Milestone> {
+        self.milestones.iter_mut().find(|m| m.name == name)
+    }
+
+    /// Name of the current milestone, if the roadmap declares one.
+    pub fn current_name(&self) -> Option<&str> {
+        self.current.as_ref().map(|c| c.name.as_str())
+    }
+
+    /// Add a milestone with an empty phase table. When `make_current` is set (or no current
+    /// milestone exists yet) it also becomes the current milestone.
+    pub fn add_milestone(
+        &mut self,
+        name: &str,
+        target: Option<&str>,
+        make_current: bool,
+    ) -> Result<(), String> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err("Milestone name cannot be empty".to_string());
+        }
+        if self.milestone(name).is_some() {
+            return Err(format!("Milestone '{name}' already exists"));
+        }
+        self.milestones.push(Milestone {
+            name: name.to_string(),
+            target: target.map(str::to_string),
+            phases: Vec::new(),
+        });
+        if make_current || self.current.is_none() {
+            self.current = Some(CurrentMilestone {
+                name: name.to_string(),
+                status: "Not Started".to_string(),
+                phase: "0 of 0".to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Move a milestone to `## Completed Milestones`. If it was current, the next remaining
+    /// milestone becomes current.
+    pub fn complete_milestone(&mut self, name: &str, date: &str) -> Result<(), String> {
+        let Some(idx) = self.milestones.iter().position(|m| m.name == name) else {
+            return Err(format!("Milestone '{name}' not found"));
+        };
+        self.milestones.remove(idx);
+        self.completed.push(format!("{name} (completed {date})"));
+
+        if self.current_name() == Some(name) {
+            let next = self
+                .milestones
+                .get(idx)
+                .or_else(|| self.milestones.first())
+                .map(|m| m.name.clone());
+            let current = self.current.get_or_insert_with(|| CurrentMilestone {
+                name: String::new(),
+                status: String::new(),
+                phase: String::new(),
+            });
+            match next {
+                Some(next) => {
+                    current.name = next;
+                    current.status = "Not Started".to_string();
+                    current.phase = "0 of 0".to_string();
+                }
+                None => current.status = "Complete".to_string(),
+            }
+        }
+        Ok(())
+    }
+
+    /// Append a phase to a milestone and return its number.
+    pub fn add_phase(
+        &mut self,
+        milestone: &str,
+        name: &str,
+        changes: Vec<String>,
+    ) -> Result<String, String> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err("Phase name cannot be empty".to_string());
+        }
+        let Some(m) = self.milestone_mut(milestone) else {
+            return Err(format!("Milestone '{milestone}' not found"));
+        };
+        // Drop the template's placeholder row the first time a real phase is added.
+        m.phases.retain(|p| !is_placeholder(&p.name));
+        let next = m
+            .phases
+            .iter()
+            .filter_map(|p| p.number.parse::<u32>().ok())
+            .max()
+            .unwrap_or(0)
+            + 1;
+        let mut phase = Phase::new(next.to_string(), name);
+        phase.changes = changes;
+        m.phases.push(phase);
+        Ok(next.to_string())
+    }
+
+    /// Set a phase's status by hand. Phases linked to changes are rejected because their
+    /// status is derived from task progress.
+    pub fn set_phase_status(
+        &mut self,
+        milestone: &str,
+        number: &str,
+        status: PhaseStatus,
+    ) -> Result<(), String> {
+        let phase = self.phase_mut(milestone, number)?;
+        if !phase.changes.is_empty() {
+            return Err(format!(
+                "Phase {number} is linked to changes ({}); its status follows their task progress",
+                phase.changes.join(", ")
+            ));
+        }
+        phase.status = status;
+        Ok(())
+    }
+
+    /// Link change ids to a phase (duplicates are ignored).
+    pub fn link_phase(
+        &mut self,
+        milestone: &str,
+        number: &str,
+        changes: &[String],
+    ) -> Result<(), String> {
+        let phase = self.phase_mut(milestone, number)?;
+        for change in changes {
+            if !phase.changes.contains(change) {
+                phase.changes.push(change.clone());
+            }
+        }
+        Ok(())
+    }
+
+    fn phase_mut(&mut self, milestone: &str, number: &str) -> Result<&mut Phase, String> {
+        let Some(m) = self.milestone_mut(milestone) else {
+            return Err(format!("Milestone '{milestone}' not found"));
+        };
+        m.phase_mut(number)
+            .ok_or_else(|| format!("Phase {number} not found in milestone '{milestone}'"))
+    }
+
+    /// Recompute linked phase statuses and the current milestone summary.
+    pub fn refresh_progress(&mut self, progress: impl Fn(&str) -> ChangeProgress) {
+        for m in &mut self.milestones {
+            for p in &mut m.phases {
+                p.status = p.computed_status(&progress);
+            }
+        }
+
+        let Some(current) = self.current.as_mut() else {
+            return;
+        };
+        let Some(m) = self.milestones.iter().find(|m| m.name == current.name) else {
+            return;
+        };
+        let total = m.phases.len();
+        let done = m
+            .phases
+            .iter()
+            .filter(|p| p.status == PhaseStatus::Complete)
+            .count();
+        let active = m
+            .phases
+            .iter()
+            .position(|p| p.status != PhaseStatus::Complete)
+            .map(|i| i + 1)
+            .unwrap_or(total);
+        current.phase = format!("{active} of {total}");
+        current.status = if total > 0 && done == total {
+            "Complete".to_string()
+        } else if m.phases.iter().all(|p| p.status == PhaseStatus::Pending) {
+            "Not Started".to_string()
+        } else {
+            "In Progress".to_string()
+        };
+    }
+
+    /// Serialize back to markdown.
+    pub fn to_markdown(&self) -> String {
+        let src = &self.source;
+        let lines = &src.lines;
+        let mut out: Vec<String> = Vec::new();
+
+        let completed_changed = self.completed != src.snapshot_completed;
+
+        // Milestones that disappeared from the model are dropped along with their block.
+        let kept: Vec<(usize, &MilestoneSpan)> = src
+            .snapshot_milestones
+            .iter()
+            .zip(&src.milestones)
+            .enumerate()
+            .filter(|(_, (m, _))| self.milestone(&m.name).is_some())
+            .map(|(i, (_, span))| (i, span))
+            .collect();
+        let removed: Vec<&MilestoneSpan> = src
+            .milestones
+            .iter()
+            .zip(&src.snapshot_milestones)
+            .filter(|(_, m)| self.milestone(&m.name).is_none())
+            .map(|(span, _)| span)
+            .collect();
+        let new_milestones: Vec<&Milestone> = self
+            .milestones
+            .iter()
+            .filter(|m| !src.snapshot_milestones.iter().any(|s| s.name == m.name))
+            .collect();
+
+        if self.current.is_some() && src.current.is_none() {
+            let first_heading = lines
+                .iter()
+                .position(|l| l.trim().starts_with("## "))
+                .unwrap_or(lines.len());
+            out.extend(lines[..first_heading].iter().cloned());
+            if out.last().is_some_and(|l| !l.trim().is_empty()) {
+                out.push(String::new());
+            }
+            out.extend(self.render_current());
+            out.push(String::new());
+            self.render_body(&mut out, first_heading, &kept, &removed, &new_milestones);
+        } else {
+            self.render_body(&mut out, 0, &kept, &removed, &new_milestones);
+        }
+
+        if !new_milestones.is_empty() && src.milestones_heading.is_none() {
+            if out.last().is_some_and(|l| !l.trim().is_empty()) {
+                out.push(String::new());
+            }
+            out.push("## Milestones".to_string());
+            if src.spaced {
+                out.push(String::new());
+            }
+            for m in &new_milestones {
+                out.extend(self.render_milestone(m));
+            }
+        }
+        if completed_changed && src.completed.is_none() {
+            if out.last().is_some_and(|l| !l.trim().is_empty()) {
+                out.push(String::new());
+            }
+            out.push("## Completed Milestones".to_string());
+            if src.spaced {
+                out.push(String::new());
+            }
+            out.extend(self.completed.iter().map(|c| format!("- {c}")));
+        }
+
+        let mut s = out.join("\n");
+        if src.trailing_newline || src.lines.is_empty() {
+            s.push('\n');
+        }
+        s
+    }
+
+    fn render_body(
+        &self,
+        out: &mut Vec<String>,
+        from: usize,
+        kept: &[(usize, &MilestoneSpan)],
+        removed: &[&MilestoneSpan],
+        new_milestones: &[&Milestone],
+    ) {
+        let src = &self.source;
+        let lines = &src.lines;
+        // Every milestone block runs to the end of the section, so new ones go there.
+        let insert_at = src.milestones_heading.map(|_| src.milestones_end);
+        let mut idx = from;
+        while idx < lines.len() {
+            if Some(idx) == insert_at {
+                self.push_new_milestones(out, new_milestones);
+            }
+            // Current milestone summary lines.
+            if let Some(span) = &src.current
+                && self.current != src.snapshot_current
+                && let Some(cur) = &self.current
+            {
+                if idx == span.heading {
+                    out.push(format!("## Current Milestone: {}", cur.name));
+                    if span.status.is_none() || span.phase.is_none() {
+                        if src.spaced {
+                            out.push(String::new());
+                        }
+                        if span.status.is_none() {
+                            out.push(format!("- Status: {}", cur.status));
+                        }
+                        if span.phase.is_none() {
+                            out.push(format!("- Phase: {}", cur.phase));
+                        }
+                        if src.spaced && lines.get(idx + 1).is_some_and(|l| l.trim().is_empty()) {
+                            idx += 1;
+                        }
+                    }
+                    idx += 1;
+                    continue;
+                }
+                if Some(idx) == span.status {
+                    out.push(format!("- Status: {}", cur.status));
+                    idx += 1;
+                    continue;
+                }
+                if Some(idx) == span.phase {
+                    out.push(format!("- Phase: {}", cur.phase));
+                    idx += 1;
+                    continue;
+                }
+            }
+
+            if let Some(span) = removed.iter().find(|s| s.start == idx) {
+                idx = span.end;
+                continue;
+            }
+            if let Some((i, span)) = kept.iter().find(|(_, s)| s.start == idx) {
+                let before = &src.snapshot_milestones[*i];
+                match self.milestone(&before.name) {
+                    Some(now) if now != before => {
+                        out.extend(self.rewrite_milestone(now, span));
+                    }
+                    _ => out.extend(lines[span.start..span.end].iter().cloned()),
+                }
+                idx = span.end;
+                continue;
+            }
+
+            if let Some((start, end)) = src.completed
+                && idx == start
+                && self.completed != src.snapshot_completed
+            {
+                out.extend(self.rewrite_completed(start, end));
+                idx = end;
+                continue;
+            }
+
+            out.push(lines[idx].clone());
+            idx += 1;
+        }
+        if insert_at == Some(lines.len()) {
+            self.push_new_milestones(out, new_milestones);
+        }
+    }
+
+    fn push_new_milestones(&self, out: &mut Vec<String>, new_milestones: &[&Milestone]) {
+        if new_milestones.is_empty() {
+            return;
+        }
+        // New blocks go before the blank lines that separate Milestones from the next section.
+        let mut trailing = 0usize;
+        while out.last().is_some_and(|l| l.trim().is_empty()) {
+            out.pop();
+            trailing += 1;
+        }
+        out.push(String::new());
+        for (i, m) in new_milestones.iter().enumerate() {
+            let mut block = self.render_milestone(m);
+            if i + 1 == new_milestones.len() {
+                block.pop();
+            }
+            out.extend(block);
+        }
+        for _ in 0..trailing.max(1) {
+            out.push(String::new());
+        }
+    }
+
+    fn render_current(&self) -> Vec<String> {
+        let Some(cur) = &self.current else {
+            return Vec::new();
+        };
+        let mut out = vec![format!("## Current Milestone: {}", cur.name)];
+        if self.source.spaced {
+            out.push(String::new());
+        }
+        out.push(format!("- Status: {}", cur.status));
+        out.push(format!("- Phase: {}", cur.phase));
+        out
+    }
+
+    fn render_table(&self, m: &Milestone, original: Option<(usize, usize)>) -> Vec<String> {
+        let lines = &self.source.lines;
+        let (header, separator) = match original {
+            Some((start, end)) if end > start + 1 => {
+                (lines[start].clone(), lines[start + 1].clone())
+            }
+            _ => (
+                PHASE_TABLE_HEADER.to_string(),
+                PHASE_TABLE_SEPARATOR.to_string(),
+            ),
+        };
+        let before = self
+            .source
+            .snapshot_milestones
+            .iter()
+            .find(|s| s.name == m.name);
+        let mut out = vec![header, separator];
+        for p in &m.phases {
+            let unchanged =
+                p.row.is_some() && before.is_some_and(|b| b.phases.iter().any(|bp| bp == p));
+            match p.row {
+                Some(row) if unchanged => out.push(lines[row].clone()),
+                _ => out.push(p.render()),
+            }
+        }
+        out
+    }
+
+    /// Render a brand-new milestone block, including its trailing blank line.
+    fn render_milestone(&self, m: &Milestone) -> Vec<String> {
+        let mut out = vec![format!("### {}", m.name)];
+        if self.source.spaced {
+            out.push(String::new());
+        }
+        out.push(format!(
+            "Target: {}",
+            m.target.as_deref().unwrap_or(TARGET_PLACEHOLDER)
+        ));
+        out.push(String::new());
+        out.extend(self.render_table(m, None));
+        out.push(String::new());
+        out
+    }
+
+    fn rewrite_milestone(&self, m: &Milestone, span: &MilestoneSpan) -> Vec<String> {
+        let lines = &self.source.lines;
+        let mut out: Vec<String> = Vec::new();
+        let mut idx = span.start;
+        while idx < span.end {
+            if idx == span.start {
+                out.push(format!("### {}", m.name));
+                if span.target.is_none() && m.target.is_some() {
+                    out.push(format!(
+                        "Target: {}",
+                        m.target.as_deref().unwrap_or_default()
+                    ));
+                }
+            } else if Some(idx) == span.target {
+                match &m.target {
+                    Some(t) => out.push(format!("Target: {t}")),
+                    None => out.push(lines[idx].clone()),
+                }
+            } else if let Some((start, end)) = span.table
+                && idx == start
+            {
+                out.extend(self.render_table(m, span.table));
+                idx = end;
+                continue;
+            } else {
+                out.push(lines[idx].clone());
+            }
+            idx += 1;
+        }
+        if span.table.is_none() {
+            let mut trailing = 0usize;
+            while out.last().is_some_and(|l| l.trim().is_empty()) {
+                out.pop();
+                trailing += 1;
+            }
+            out.push(String::new());
+            out.extend(self.render_table(m, None));
+            for _ in 0..trailing {
+                out.push(String::new());
+            }
+        }
+        out
+    }
+
+    fn rewrite_completed(&self, start: usize, end: usize) -> Vec<String> {
+        let body = &self.source.lines[start..end];
+        let lead = body.iter().take_while(|l| l.trim().is_empty()).count();
+        let trail = body[lead..]
+            .iter()
+            .rev()
+            .take_while(|l| l.trim().is_empty())
+            .count();
+        let mut out: Vec<String> = body[..lead].to_vec();
+        if self.completed.is_empty() {
+            out.push("[None yet]".to_string());
+        } else {
+            out.extend(self.completed.iter().map(|c| format!("- {c}")));
+        }
+        out.extend(body[body.len() - trail..].iter().cloned());
+        out
+    }
+}
+
+fn is_placeholder(text: &str) -> bool {
+    let t = text.trim();
+    (t.starts_with('[') || t.starts_with("\\[")) && t.ends_with(']')
+}
+
+fn parse_phase_row(line: &str, idx: usize) -> Option<Phase> {
+    let cols: Vec<&str> = line
+        .trim()
+        .trim_matches('|')
+        .split('|')
+        .map(str::trim)
+        .collect();
+    if cols.len() < 4 || cols[0].is_empty() {
+        return None;
+    }
+    let changes = cols[3]
+        .split(',')
+        .map(str::trim)
+        .filter(|c| !c.is_empty() && *c != "-")
+        .map(str::to_string)
+        .collect();
+    Some(Phase {
+        number: cols[0].to_string(),
+        name: cols[1].to_string(),
+        status: PhaseStatus::from_label(cols[2]),
+        changes,
+        row: Some(idx),
+    })
+}
@@ -1,5 +1,6 @@
 use chrono::Local;
 use regex::Regex;
+use serde::{Deserialize, Serialize};
 
 pub fn update_last_updated(contents: &str, date: &str) -> String {
     let re = Regex::new(r"(?m)^Last Updated: .+$").unwrap();
@@ -135,3 +136,344 @@ pub fn now_time() -> String {
 pub fn now_date() -> String {
     Local::now().format("%Y-%m-%d").to_string()
 }
+
+/// Kind of entry tracked in `STATE.md`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum StateEntryKind {
+    Decision,
+    Question,
+    Blocker,
+    Note,
+}
+
+impl StateEntryKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            StateEntryKind::Decision => "decision",
+            StateEntryKind::Question => "question",
+            StateEntryKind::Blocker => "blocker",
+            StateEntryKind::Note => "note",
+        }
+    }
+
+    fn from_heading(heading: &str) -> Option<Self> {
+        match heading.trim() {
+            "## Recent Decisions" => Some(StateEntryKind::Decision),
+            "## Open Questions" => Some(StateEntryKind::Question),
+            "## Blockers" => Some(StateEntryKind::Blocker),
+            "## Session Notes" => Some(StateEntryKind::Note),
+            _ => None,
+        }
+    }
+}
+
+/// A single decision, question, blocker or session note parsed from `STATE.md`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StateEntry {
+    pub kind: StateEntryKind,
+    /// 1-based position among entries of the same kind, in file order.
+    pub number: usize,
+    /// Date the entry was recorded, when the file carries one.
+    pub date: Option<String>,
+    /// Time of day for session notes written by `spool state note`.
+    pub time: Option<String>,
+    pub text: String,
+    /// Answered questions and resolved blockers.
+    pub resolved: bool,
+    pub resolved_on: Option<String>,
+    /// Answer to a question or resolution note for a blocker.
+    pub resolution: Option<String>,
+}
+
+/// Structured view of `STATE.md`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectState {
+    pub last_updated: Option<String>,
+    pub focus: Option<String>,
+    pub entries: Vec<StateEntry>,
+}
+
+impl ProjectState {
+    pub fn entries_of(&self, kind: StateEntryKind) -> impl Iterator<Item = &StateEntry> {
+        self.entries.iter().filter(move |e| e.kind == kind)
+    }
+
+    /// Unanswered questions and unresolved blockers (decisions and notes are always open).
+    pub fn open_entries_of(&self, kind: StateEntryKind) -> impl Iterator<Item = &StateEntry> {
+        self.entries_of(kind).filter(|e| !e.resolved)
+    }
+}
+
+struct LocatedEntry {
+    entry: StateEntry,
+    /// Line index of the entry bullet.
+    line: usize,
+    /// Line index of the last line that belongs to the entry (its sub-bullets).
+    last_line: usize,
+}
+
+fn is_placeholder(text: &str) -> bool {
+    let t = text.trim();
+    (t.starts_with('[') || t.starts_with("\\[")) && t.ends_with(']')
+}
+
+fn split_date_prefix(text: &str) -> (Option<String>, String) {
+    let re = Regex::new(r"^(\d{4}-\d{2}-\d{2}): (.*)$").unwrap();
+    match re.captures(text) {
+        Some(caps) => (Some(caps[1].to_string()), caps[2].to_string()),
+        None => (None, text.to_string()),
+    }
+}
+
+fn locate_entries(contents: &str) -> (ProjectState, Vec<LocatedEntry>) {
+    let date_re = Regex::new(r"^(\d{4}-\d{2}-\d{2})\b").unwrap();
+    let time_re = Regex::new(r"^(\d{2}:\d{2}(?::\d{2})?): (.*)$").unwrap();
+    let answered_re = Regex::new(r"^Answered (\d{4}-\d{2}-\d{2}): (.*)$").unwrap();
+    let resolved_re = Regex::new(r"^Resolved (\d{4}-\d{2}-\d{2})(?:: (.*))?$").unwrap();
+
+    let mut state = ProjectState::default();
+    let mut located: Vec<LocatedEntry> = Vec::new();
+    let mut section: Option<StateEntryKind> = None;
+    let mut in_focus = false;
+    let mut session_date: Option<String> = None;
+
+    for (idx, line) in contents.lines().enumerate() {
+        let trimmed = line.trim();
+        if let Some(rest) = trimmed.strip_prefix("Last Updated: ") {
+            state.last_updated = Some(rest.trim().to_string());
+            continue;
+        }
+        if trimmed.starts_with("## ") {
+            section = StateEntryKind::from_heading(trimmed);
+            in_focus = trimmed == "## Current Focus";
+            session_date = None;
+            continue;
+        }
+        if in_focus {
+            if !trimmed.is_empty() && state.focus.is_none() && !is_placeholder(trimmed) {
+                state.focus = Some(trimmed.to_string());
+            }
+            continue;
+        }
+        let Some(kind) = section else {
+            continue;
+        };
+
+        if kind == StateEntryKind::Note
+            && let Some(header) = trimmed.strip_prefix("### ")
+        {
+            session_date = date_re.captures(header).map(|c| c[1].to_string());
+            continue;
+        }
+
+        // Indented sub-bullets carry answers/resolutions for the entry above.
+        if line.starts_with(char::is_whitespace) && !trimmed.is_empty() {
+            if let Some(prev) = located.last_mut()
+                && prev.entry.kind == kind
+                && prev.last_line + 1 == idx
+            {
+                let sub = trimmed.strip_prefix("- ").unwrap_or(trimmed);
+                if let Some(caps) = answered_re.captures(sub) {
+                    prev.entry.resolved_on = Some(caps[1].to_string());
+                    prev.entry.resolution = Some(caps[2].to_string());
+                } else if let Some(caps) = resolved_re.captures(sub) {
+                    prev.entry.resolved_on = Some(caps[1].to_string());
+                    prev.entry.resolution = caps.get(2).map(|m| m.as_str().to_string());
+                }
+                prev.last_line = idx;
+            }
+            continue;
+        }
+
+        let Some(body) = line.strip_prefix("- ") else {
+            continue;
+        };
+        let (resolved, body) = if let Some(rest) = body.strip_prefix("[ ] ") {
+            (false, rest)
+        } else if let Some(rest) = body
+            .strip_prefix("[x] ")
+            .or_else(|| body.strip_prefix("[X] "))
+        {
+            (true, rest)
+        } else {
+            (false, body)
+        };
+        if body.trim().is_empty() || is_placeholder(body) {
+            continue;
+        }
+
+        let (date, time, text) = match kind {
+            StateEntryKind::Note => match time_re.captures(body) {
+                Some(caps) => (
+                    session_date.clone(),
+                    Some(caps[1].to_string()),
+                    caps[2].to_string(),
+                ),
+                None => (session_date.clone(), None, body.to_string()),
+            },
+            _ => {
+                let (date, text) = split_date_prefix(body);
+                (date, None, text)
+            }
+        };
+
+        let number = located.iter().filter(|e| e.entry.kind == kind).count() + 1;
+        located.push(LocatedEntry {
+            entry: StateEntry {
+                kind,
+                number,
+                date,
+                time,
+                text,
+                resolved,
+                resolved_on: None,
+                resolution: None,
+            },
+            line: idx,
+            last_line: idx,
+        });
+    }
+
+    state.entries = located.iter().map(|e| e.entry.clone()).collect();
+    (state, located)
+}
+
+/// Parse `STATE.md` into typed entries.
+pub fn parse_state(contents: &str) -> ProjectState {
+    locate_entries(contents).0
+}
+
+fn close_entry(
+    contents: &str,
+    kind: StateEntryKind,
+    number: usize,
+    sub_bullet: String,
+) -> Result<String, String> {
+    let (_, located) = locate_entries(contents);
+    let Some(target) = located
+        .iter()
+        .find(|e| e.entry.kind == kind && e.entry.number == number)
+    else {
+        return Err(format!("No {} #{number} in STATE.md", kind.as_str()));
+    };
+    if target.entry.resolved {
+        let verb = match kind {
+            StateEntryKind::Question => "answered",
+            _ => "resolved",
+        };
+        return Err(format!("{} #{number} is already {verb}", kind.as_str()));
+    }
+
+    let mut lines: Vec<String> = contents.lines().map(|l| l.to_string()).collect();
+    let bullet = &lines[target.line];
+    let body = bullet.strip_prefix("- ").unwrap_or(bullet);
+    let body = body.strip_prefix("[ ] ").unwrap_or(body);
+    lines[target.line] = format!("- [x] {body}");
+    lines.insert(target.last_line + 1, format!("  - {sub_bullet}"));
+
+    let mut out = lines.join("\n");
+    out.push('\n');
+    Ok(out)
+}
+
+/// Mark blocker `number` (as numbered by [`parse_state`]) as resolved.
+pub fn resolve_blocker(
+    contents: &str,
+    date: &str,
+    number: usize,
+    note: Option<&str>,
+) -> Result<String, String> {
+    let sub = match note.map(str::trim).filter(|n| !n.is_empty()) {
+        Some(note) => format!("Resolved {date}: {note}"),
+        None => format!("Resolved {date}"),
+    };
+    let updated = close_entry(contents, StateEntryKind::Blocker, number, sub)?;
+    Ok(update_last_updated(&updated, date))
+}
+
+/// Record an answer for question `number` (as numbered by [`parse_state`]).
+pub fn answer_question(
+    contents: &str,
+    date: &str,
+    number: usize,
+    answer: &str,
+) -> Result<String, String> {
+    let answer = answer.trim();
+    if answer.is_empty() {
+        return Err("Answer text is required".to_string());
+    }
+    let sub = format!("Answered {date}: {answer}");
+    let updated = close_entry(contents, StateEntryKind::Question, number, sub)?;
+    Ok(update_last_updated(&updated, date))
+}
+
+fn is_rule(line: &str) -> bool {
+    let t = line.trim();
+    t.len() >= 3 && (t.chars().all(|c| c == '-') || t.chars().all(|c| c == '_'))
+}
+
+/// Remove dated session-note blocks older than `cutoff` (`YYYY-MM-DD`).
+///
+/// Returns the updated contents and the number of session blocks removed. Blocks whose
+/// header has no date are kept.
+pub fn prune_notes(contents: &str, cutoff: &str) -> (String, usize) {
+    let date_re = Regex::new(r"^### (\d{4}-\d{2}-\d{2})\b").unwrap();
+    let lines: Vec<&str> = contents.lines().collect();
+    let mut keep: Vec<bool> = vec![true; lines.len()];
+    let mut removed = 0usize;
+
+    let mut in_notes = false;
+    let mut i = 0usize;
+    while i < lines.len() {
+        let trimmed = lines[i].trim();
+        if trimmed.starts_with("## ") {
+            in_notes = trimmed == "## Session Notes";
+            i += 1;
+            continue;
+        }
+        let stale = in_notes
+            && date_re
+                .captures(trimmed)
+                .is_some_and(|caps| &caps[1] < cutoff);
+        if !stale {
+            i += 1;
+            continue;
+        }
+
+        let mut end = i + 1;
+        while end < lines.len() {
+            let t = lines[end].trim();
+            if t.starts_with("### ") || t.starts_with("## ") || is_rule(t) {
+                break;
+            }
+            end += 1;
+        }
+        // Keep the blank lines that separate the notes from what follows them.
+        let mut block_end = end;
+        if end >= lines.len() || !lines[end].trim().starts_with("### ") {
+            while block_end > i + 1 && lines[block_end - 1].trim().is_empty() {
+                block_end -= 1;
+            }
+        }
+        for flag in keep.iter_mut().take(block_end).skip(i) {
+            *flag = false;
+        }
+        removed += 1;
+        i = end;
+    }
+
+    if removed == 0 {
+        return (contents.to_string(), 0);
+    }
+    let mut out = lines
+        .iter()
+        .zip(keep)
+        .filter_map(|(line, keep)| keep.then_some(*line))
+        .collect::<Vec<_>>()
+        .join("\n");
+    out.push('\n');
+    (out, removed)
+}
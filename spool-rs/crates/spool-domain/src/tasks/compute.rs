@@ -17,7 +17,7 @@ pub fn compute_ready_and_blocked(
             .filter(|t| t.status == TaskStatus::Pending)
             .cloned()
             .collect();
-        ready.sort_by(|a, b| a.header_line_index.cmp(&b.header_line_index));
+        ready.sort_by_key(|t| t.header_line_index);
         return (ready, Vec::new());
     }
 
@@ -181,6 +181,7 @@ mod tests {
             action: String::new(),
             verify: None,
             done_when: None,
+            tdd_phase: None,
             kind: TaskKind::Normal,
             header_line_index,
         }
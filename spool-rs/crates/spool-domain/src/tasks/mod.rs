@@ -10,12 +10,18 @@ pub use parse::detect_tasks_format;
 pub use parse::enhanced_tasks_template;
 pub use parse::parse_tasks_tracking_file;
 pub use parse::tasks_path;
-pub use repository::TaskRepository;
+pub use parse::{
+    TracksResolver, is_valid_tracked_file, normalize_tracked_files, qualified_task_id,
+    split_task_ref, tracked_file_path, tracked_files, tracks_override,
+};
+pub use repository::{TaskRepository, TrackedTasks};
 pub use update::update_checkbox_task_status;
+pub use update::update_enhanced_task_phase;
 pub use update::update_enhanced_task_status;
 
 pub use parse::TasksParseResult;
 pub use parse::WaveInfo;
 pub use parse::{
     DiagnosticLevel, ProgressInfo, TaskDiagnostic, TaskItem, TaskKind, TaskStatus, TasksFormat,
+    TddPhase,
 };
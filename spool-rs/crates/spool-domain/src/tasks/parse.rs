@@ -47,6 +47,63 @@ impl TaskStatus {
     }
 }
 
+/// Phase of the RED -> GREEN -> REFACTOR cycle a task is in (`- **TDD Phase**: red`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TddPhase {
+    Red,
+    Green,
+    Refactor,
+}
+
+impl TddPhase {
+    pub fn as_label(self) -> &'static str {
+        match self {
+            TddPhase::Red => "red",
+            TddPhase::Green => "green",
+            TddPhase::Refactor => "refactor",
+        }
+    }
+
+    pub fn from_label(s: &str) -> Option<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "red" => Some(TddPhase::Red),
+            "green" => Some(TddPhase::Green),
+            "refactor" => Some(TddPhase::Refactor),
+            _ => None,
+        }
+    }
+
+    /// Whether the task's verify command must pass (`true`) or fail (`false`) to enter this phase.
+    pub fn requires_passing_verify(self) -> bool {
+        self != TddPhase::Red
+    }
+
+    /// Check that a task may move from `from` to `self`.
+    ///
+    /// A cycle starts at RED (from no phase, or after REFACTOR for the next test), then
+    /// GREEN, then REFACTOR.
+    pub fn check_transition(self, from: Option<TddPhase>) -> Result<(), String> {
+        let allowed = match self {
+            TddPhase::Red => matches!(from, None | Some(TddPhase::Refactor)),
+            TddPhase::Green => from == Some(TddPhase::Red),
+            TddPhase::Refactor => from == Some(TddPhase::Green),
+        };
+        if allowed {
+            return Ok(());
+        }
+        let expected = match self {
+            TddPhase::Red => "no phase or refactor",
+            TddPhase::Green => "red",
+            TddPhase::Refactor => "green",
+        };
+        let from = from.map(TddPhase::as_label).unwrap_or("no phase");
+        Err(format!(
+            "cannot move from {from} to {to} ({to} must follow {expected})",
+            to = self.as_label()
+        ))
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct TaskDiagnostic {
     pub level: DiagnosticLevel,
@@ -82,6 +139,7 @@ pub struct TaskItem {
     pub action: String,
     pub verify: Option<String>,
     pub done_when: Option<String>,
+    pub tdd_phase: Option<TddPhase>,
     pub kind: TaskKind,
     pub header_line_index: usize,
 }
@@ -103,6 +161,18 @@ pub struct ProgressInfo {
     pub remaining: usize,
 }
 
+impl ProgressInfo {
+    /// Add another file's counts to these.
+    pub fn add(&mut self, other: &ProgressInfo) {
+        self.total += other.total;
+        self.complete += other.complete;
+        self.shelved += other.shelved;
+        self.in_progress += other.in_progress;
+        self.pending += other.pending;
+        self.remaining += other.remaining;
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct WaveInfo {
     pub wave: u32,
@@ -202,6 +272,7 @@ fn parse_checkbox_tasks(contents: &str) -> TasksParseResult {
             action: String::new(),
             verify: None,
             done_when: None,
+            tdd_phase: None,
             kind: TaskKind::Normal,
             header_line_index: line_idx,
         });
@@ -232,6 +303,7 @@ fn parse_enhanced_tasks(contents: &str) -> TasksParseResult {
     let files_re = Regex::new(r"\*\*Files\*\*:\s*`([^`]+)`\s*$").unwrap();
     let verify_re = Regex::new(r"\*\*Verify\*\*:\s*`([^`]+)`\s*$").unwrap();
     let done_when_re = Regex::new(r"\*\*Done When\*\*:\s*(.+?)\s*$").unwrap();
+    let tdd_phase_re = Regex::new(r"\*\*TDD Phase\*\*:\s*(.+?)\s*$").unwrap();
 
     let mut current_wave: Option<u32> = None;
     let mut in_checkpoints = false;
@@ -260,6 +332,7 @@ fn parse_enhanced_tasks(contents: &str) -> TasksParseResult {
         action_lines: Vec<String>,
         verify: Option<String>,
         done_when: Option<String>,
+        tdd_phase_raw: Option<String>,
     }
 
     fn flush_current(
@@ -289,6 +362,23 @@ fn parse_enhanced_tasks(contents: &str) -> TasksParseResult {
             .to_string();
         let verify = current.verify.take();
         let done_when = current.done_when.take();
+        let tdd_phase = match current.tdd_phase_raw.take() {
+            Some(raw) => {
+                let phase = TddPhase::from_label(&raw);
+                if phase.is_none() {
+                    diagnostics.push(TaskDiagnostic {
+                        level: DiagnosticLevel::Error,
+                        message: format!(
+                            "Invalid TDD Phase: {raw} (expected red, green or refactor)"
+                        ),
+                        task_id: Some(id.clone()),
+                        line: Some(header_line_index + 1),
+                    });
+                }
+                phase
+            }
+            None => None,
+        };
 
         let status = match status_raw
             .as_deref()
@@ -381,6 +471,7 @@ fn parse_enhanced_tasks(contents: &str) -> TasksParseResult {
             action,
             verify,
             done_when,
+            tdd_phase,
             kind: current.kind,
             header_line_index,
         });
@@ -401,6 +492,7 @@ fn parse_enhanced_tasks(contents: &str) -> TasksParseResult {
         action_lines: Vec::new(),
         verify: None,
         done_when: None,
+        tdd_phase_raw: None,
     };
 
     let mut in_action = false;
@@ -480,6 +572,7 @@ fn parse_enhanced_tasks(contents: &str) -> TasksParseResult {
             current_task.action_lines.clear();
             current_task.verify = None;
             current_task.done_when = None;
+            current_task.tdd_phase_raw = None;
             in_action = false;
 
             if current_wave.is_none() && !in_checkpoints {
@@ -535,6 +628,10 @@ fn parse_enhanced_tasks(contents: &str) -> TasksParseResult {
                 current_task.done_when = Some(cap[1].trim().to_string());
                 continue;
             }
+            if let Some(cap) = tdd_phase_re.captures(line) {
+                current_task.tdd_phase_raw = Some(cap[1].trim().to_string());
+                continue;
+            }
         }
     }
 
@@ -726,3 +823,87 @@ fn compute_progress(tasks: &[TaskItem]) -> ProgressInfo {
 pub fn tasks_path(spool_path: &Path, change_id: &str) -> PathBuf {
     spool_path.join("changes").join(change_id).join("tasks.md")
 }
+
+/// Resolves a change's tracking files, relative to its directory and in tracking order.
+///
+/// Schemas live outside this crate, so callers that can resolve them supply a resolver
+/// (see [`crate::tasks::TaskRepository::with_tracks`]); [`tracked_files`] is the default.
+pub type TracksResolver<'a> = Box<dyn Fn(&Path, &str) -> Vec<String> + 'a>;
+
+/// Tracking files for a change when its schema is not consulted: the `.spool.yaml`
+/// override (see [`tracks_override`]), else `tasks.md`.
+pub fn tracked_files(spool_path: &Path, change_id: &str) -> Vec<String> {
+    tracks_override(spool_path, change_id).unwrap_or_else(|| vec!["tasks.md".to_string()])
+}
+
+/// Tracking files set explicitly by the `tracks` entry (a file name or a list) of the
+/// change's `.spool.yaml`, overriding those of its schema.
+pub fn tracks_override(spool_path: &Path, change_id: &str) -> Option<Vec<String>> {
+    #[derive(serde::Deserialize)]
+    #[serde(untagged)]
+    enum Tracks {
+        One(String),
+        Many(Vec<String>),
+    }
+    #[derive(serde::Deserialize)]
+    struct ChangeMeta {
+        #[serde(default)]
+        tracks: Option<Tracks>,
+    }
+
+    let meta = spool_path
+        .join("changes")
+        .join(change_id)
+        .join(".spool.yaml");
+    let files = std::fs::read_to_string(meta)
+        .ok()
+        .and_then(|s| serde_yaml::from_str::<ChangeMeta>(&s).ok())
+        .and_then(|m| m.tracks)
+        .map(|t| match t {
+            Tracks::One(f) => vec![f],
+            Tracks::Many(fs) => fs,
+        })?;
+    let files = normalize_tracked_files(files);
+    (!files.is_empty()).then_some(files)
+}
+
+/// Trim tracking file names, dropping blanks, duplicates and names that fail
+/// [`is_valid_tracked_file`].
+pub fn normalize_tracked_files(files: impl IntoIterator<Item = String>) -> Vec<String> {
+    let mut out: Vec<String> = Vec::new();
+    for f in files {
+        let f = f.trim().to_string();
+        if is_valid_tracked_file(&f) && !out.contains(&f) {
+            out.push(f);
+        }
+    }
+    out
+}
+
+/// Whether `file` names a path inside the change directory: non-empty, relative, without
+/// `..` components, and without `:` (which would make `<file>:<id>` task references
+/// ambiguous).
+pub fn is_valid_tracked_file(file: &str) -> bool {
+    !file.is_empty()
+        && !file.contains(':')
+        && !file.starts_with(['/', '\\'])
+        && !file.split(['/', '\\']).any(|part| part == "..")
+}
+
+pub fn tracked_file_path(spool_path: &Path, change_id: &str, file: &str) -> PathBuf {
+    spool_path.join("changes").join(change_id).join(file)
+}
+
+/// Task id qualified with its tracking file (`<file>:<id>`), used when a change tracks
+/// several files whose ids may collide.
+pub fn qualified_task_id(file: &str, id: &str) -> String {
+    format!("{file}:{id}")
+}
+
+/// Split a task reference into its tracking file (if qualified) and task id.
+pub fn split_task_ref(task_ref: &str) -> (Option<&str>, &str) {
+    match task_ref.split_once(':') {
+        Some((file, id)) => (Some(file), id),
+        None => (None, task_ref),
+    }
+}
@@ -4,10 +4,22 @@
 //! hiding the markdown storage implementation from consumers.
 
 use miette::{IntoDiagnostic, Result};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
-use super::parse::{ProgressInfo, TaskItem, TasksParseResult, parse_tasks_tracking_file};
-use super::tasks_path;
+use super::parse::{
+    ProgressInfo, TaskDiagnostic, TaskItem, TasksFormat, TasksParseResult, TracksResolver,
+    parse_tasks_tracking_file, qualified_task_id, tracked_file_path, tracked_files,
+};
+
+/// Tasks parsed from one of a change's tracking files.
+#[derive(Debug, Clone)]
+pub struct TrackedTasks {
+    /// File name relative to the change directory (e.g. `tasks.md`).
+    pub file: String,
+    pub path: PathBuf,
+    pub exists: bool,
+    pub result: TasksParseResult,
+}
 
 /// Repository for accessing task data.
 ///
@@ -16,24 +28,60 @@ use super::tasks_path;
 /// directly parsing markdown.
 pub struct TaskRepository<'a> {
     spool_path: &'a Path,
+    tracks: TracksResolver<'a>,
 }
 
 impl<'a> TaskRepository<'a> {
     /// Create a new task repository for the given spool directory.
     pub fn new(spool_path: &'a Path) -> Self {
-        Self { spool_path }
+        Self {
+            spool_path,
+            tracks: Box::new(tracked_files),
+        }
+    }
+
+    /// Use `tracks` to find each change's tracking files (e.g. one that consults the
+    /// change's schema) instead of [`tracked_files`].
+    pub fn with_tracks(mut self, tracks: impl Fn(&Path, &str) -> Vec<String> + 'a) -> Self {
+        self.tracks = Box::new(tracks);
+        self
+    }
+
+    /// Load each tracking file of a change separately, in tracking order.
+    ///
+    /// Missing files are reported with an empty result.
+    pub fn load_tracked(&self, change_id: &str) -> Result<Vec<TrackedTasks>> {
+        let mut out = Vec::new();
+        for file in (self.tracks)(self.spool_path, change_id) {
+            let path = tracked_file_path(self.spool_path, change_id, &file);
+            let exists = path.exists();
+            let result = if exists {
+                let contents = std::fs::read_to_string(&path).into_diagnostic()?;
+                parse_tasks_tracking_file(&contents)
+            } else {
+                TasksParseResult::empty()
+            };
+            out.push(TrackedTasks {
+                file,
+                path,
+                exists,
+                result,
+            });
+        }
+        Ok(out)
     }
 
     /// Load all tasks for a change.
     ///
-    /// Returns the full parse result including diagnostics.
+    /// Returns the full parse result including diagnostics. When the change tracks
+    /// several files the results are combined: progress is summed and task ids are
+    /// qualified as `<file>:<id>`. Waves are per file, so the combined result has none.
     pub fn load_tasks(&self, change_id: &str) -> Result<TasksParseResult> {
-        let path = tasks_path(self.spool_path, change_id);
-        if !path.exists() {
-            return Ok(TasksParseResult::empty());
+        let mut tracked = self.load_tracked(change_id)?;
+        if tracked.len() == 1 {
+            return Ok(tracked.remove(0).result);
         }
-        let contents = std::fs::read_to_string(&path).into_diagnostic()?;
-        Ok(parse_tasks_tracking_file(&contents))
+        Ok(combine_tracked(tracked))
     }
 
     /// Get task progress for a change.
@@ -54,10 +102,6 @@ impl<'a> TaskRepository<'a> {
 
     /// Check if a change has any tasks defined.
     pub fn has_tasks(&self, change_id: &str) -> Result<bool> {
-        let path = tasks_path(self.spool_path, change_id);
-        if !path.exists() {
-            return Ok(false);
-        }
         let progress = self.get_progress(change_id)?;
         Ok(progress.total > 0)
     }
@@ -69,6 +113,38 @@ impl<'a> TaskRepository<'a> {
     }
 }
 
+fn combine_tracked(tracked: Vec<TrackedTasks>) -> TasksParseResult {
+    let mut out = TasksParseResult::empty();
+    if tracked
+        .iter()
+        .any(|t| t.exists && t.result.format == TasksFormat::Enhanced)
+    {
+        out.format = TasksFormat::Enhanced;
+    }
+    for t in tracked {
+        let file = t.file;
+        let ids: Vec<String> = t.result.tasks.iter().map(|task| task.id.clone()).collect();
+        for mut task in t.result.tasks {
+            task.id = qualified_task_id(&file, &task.id);
+            for dep in &mut task.dependencies {
+                if ids.contains(dep) {
+                    *dep = qualified_task_id(&file, dep);
+                }
+            }
+            out.tasks.push(task);
+        }
+        for d in t.result.diagnostics {
+            out.diagnostics.push(TaskDiagnostic {
+                message: format!("{file}: {}", d.message),
+                task_id: d.task_id.map(|id| qualified_task_id(&file, &id)),
+                ..d
+            });
+        }
+        out.progress.add(&t.result.progress);
+    }
+    out
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -169,4 +245,55 @@ mod tests {
         assert!(!repo.has_tasks("001-02_no-tasks").unwrap());
         assert!(!repo.has_tasks("nonexistent").unwrap());
     }
+
+    #[test]
+    fn test_multiple_tracking_files_are_combined() {
+        let tmp = TempDir::new().unwrap();
+        let spool_path = tmp.path().join(".spool");
+        let change_dir = spool_path.join("changes/001-03_tdd");
+        fs::create_dir_all(&change_dir).unwrap();
+        fs::write(
+            change_dir.join(".spool.yaml"),
+            "schema: tdd-tracked\ncreated: 2026-01-28\ntracks: [tests.md, implementation.md]\n",
+        )
+        .unwrap();
+        fs::write(
+            change_dir.join("tests.md"),
+            "- [x] Write parser tests\n- [ ] Edge cases\n",
+        )
+        .unwrap();
+        fs::write(
+            change_dir.join("implementation.md"),
+            "## Wave 1\n- **Depends On**: None\n\n### Task 1.1: Parser\n- **Updated At**: 2026-01-28\n- **Status**: [ ] in-progress\n\n### Task 1.2: Errors\n- **Dependencies**: Task 1.1\n- **Updated At**: 2026-01-28\n- **Status**: [ ] pending\n",
+        )
+        .unwrap();
+
+        let repo = TaskRepository::new(&spool_path);
+        let tracked = repo.load_tracked("001-03_tdd").unwrap();
+        let files: Vec<&str> = tracked.iter().map(|t| t.file.as_str()).collect();
+        assert_eq!(files, vec!["tests.md", "implementation.md"]);
+        assert_eq!(tracked[0].result.progress.complete, 1);
+        assert_eq!(tracked[1].result.progress.in_progress, 1);
+
+        let combined = repo.load_tasks("001-03_tdd").unwrap();
+        assert_eq!(combined.format, TasksFormat::Enhanced);
+        assert_eq!(combined.progress.total, 4);
+        assert_eq!(combined.progress.complete, 1);
+        assert_eq!(combined.progress.in_progress, 1);
+        let ids: Vec<&str> = combined.tasks.iter().map(|t| t.id.as_str()).collect();
+        assert_eq!(
+            ids,
+            vec![
+                "tests.md:1",
+                "tests.md:2",
+                "implementation.md:1.1",
+                "implementation.md:1.2"
+            ]
+        );
+        assert_eq!(
+            combined.tasks[3].dependencies,
+            vec!["implementation.md:1.1"]
+        );
+        assert!(repo.has_tasks("001-03_tdd").unwrap());
+    }
 }
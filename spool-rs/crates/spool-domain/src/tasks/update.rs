@@ -1,7 +1,7 @@
 use chrono::{DateTime, Local};
 use regex::Regex;
 
-use super::TaskStatus;
+use super::{TaskStatus, TddPhase};
 
 pub fn update_checkbox_task_status(
     contents: &str,
@@ -68,13 +68,6 @@ pub fn update_enhanced_task_status(
     new_status: TaskStatus,
     now: DateTime<Local>,
 ) -> String {
-    // Match TS: `^###\s+(?:Task\s+)?${taskId}\s*:`
-    let heading = Regex::new(&format!(
-        r"(?m)^###\s+(?:Task\s+)?{}\s*:\s*.+$",
-        regex::escape(task_id)
-    ))
-    .unwrap();
-
     let status_line = match new_status {
         TaskStatus::Complete => "- **Status**: [x] complete".to_string(),
         TaskStatus::InProgress => "- **Status**: [ ] in-progress".to_string(),
@@ -86,23 +79,7 @@ pub fn update_enhanced_task_status(
     let updated_at_line = format!("- **Updated At**: {date}");
 
     let mut lines: Vec<String> = contents.lines().map(|l| l.to_string()).collect();
-    let mut start_idx: Option<usize> = None;
-    for (i, line) in lines.iter().enumerate() {
-        if heading.is_match(line) {
-            start_idx = Some(i);
-            break;
-        }
-    }
-
-    if let Some(start) = start_idx {
-        let mut end = lines.len();
-        for (i, line) in lines.iter().enumerate().skip(start + 1) {
-            if line.starts_with("### ") || line.starts_with("## ") {
-                end = i;
-                break;
-            }
-        }
-
+    if let Some((start, end)) = enhanced_task_block(&lines, task_id) {
         let mut status_idx: Option<usize> = None;
         let mut updated_idx: Option<usize> = None;
         for (i, line) in lines.iter().enumerate().take(end).skip(start + 1) {
@@ -145,3 +122,60 @@ pub fn update_enhanced_task_status(
     out.push('\n');
     out
 }
+
+/// Line range (heading inclusive, end exclusive) of an enhanced task's block.
+fn enhanced_task_block(lines: &[String], task_id: &str) -> Option<(usize, usize)> {
+    // Match TS: `^###\s+(?:Task\s+)?${taskId}\s*:`
+    let heading = Regex::new(&format!(
+        r"(?m)^###\s+(?:Task\s+)?{}\s*:\s*.+$",
+        regex::escape(task_id)
+    ))
+    .unwrap();
+
+    let start = lines.iter().position(|line| heading.is_match(line))?;
+    let end = lines
+        .iter()
+        .enumerate()
+        .skip(start + 1)
+        .find(|(_, line)| line.starts_with("### ") || line.starts_with("## "))
+        .map(|(i, _)| i)
+        .unwrap_or(lines.len());
+    Some((start, end))
+}
+
+/// Record a task's TDD phase (`- **TDD Phase**: <phase>`) and bump its `Updated At` date.
+///
+/// A new phase line goes just before `Updated At` (or `Status`), keeping the block's
+/// bookkeeping fields together. Returns `None` when the task is not found.
+pub fn update_enhanced_task_phase(
+    contents: &str,
+    task_id: &str,
+    phase: TddPhase,
+    now: DateTime<Local>,
+) -> Option<String> {
+    let mut lines: Vec<String> = contents.lines().map(|l| l.to_string()).collect();
+    let (start, end) = enhanced_task_block(&lines, task_id)?;
+
+    let phase_line = format!("- **TDD Phase**: {}", phase.as_label());
+    let updated_at_line = format!("- **Updated At**: {}", now.format("%Y-%m-%d"));
+    let find = |lines: &[String], prefix: &str| {
+        (start + 1..end).find(|i| lines[*i].trim_start().starts_with(prefix))
+    };
+
+    if let Some(i) = find(&lines, "- **Updated At**:") {
+        lines[i] = updated_at_line;
+    }
+    match find(&lines, "- **TDD Phase**:") {
+        Some(i) => lines[i] = phase_line,
+        None => {
+            let at = find(&lines, "- **Updated At**:")
+                .or_else(|| find(&lines, "- **Status**:"))
+                .unwrap_or(end);
+            lines.insert(at, phase_line);
+        }
+    }
+
+    let mut out = lines.join("\n");
+    out.push('\n');
+    Some(out)
+}
@@ -0,0 +1,71 @@
+use spool_domain::modules::ModuleDocument;
+
+const HAND_EDITED: &str = "# Dev Tooling\n\nIntro paragraph kept by hand.\n\n## Purpose\nBuild the tools.\n\nSecond paragraph.\n\n## Scope\n- cli\n- web\n\n## Depends On\n- 001\n\n## Changes\n<!-- keep this comment -->\n- [ ] 005-01_foo\n- [ ] 005-02_foo-bar (planned)\n- [x] 005-03_baz  shipped early\n\n## Notes\nFree-form notes.\n";
+
+#[test]
+fn parse_then_serialize_is_byte_identical() {
+    let doc = ModuleDocument::parse(HAND_EDITED);
+    assert_eq!(doc.title, "Dev Tooling");
+    assert_eq!(
+        doc.purpose.as_deref(),
+        Some("Build the tools.\n\nSecond paragraph.")
+    );
+    assert_eq!(doc.scope, vec!["cli", "web"]);
+    assert_eq!(doc.depends_on, vec!["001"]);
+    assert_eq!(doc.changes.len(), 3);
+    assert!(doc.changes[1].planned);
+    assert!(doc.changes[2].completed);
+    assert_eq!(doc.to_markdown(), HAND_EDITED);
+}
+
+#[test]
+fn mark_change_complete_matches_exact_id_only() {
+    let mut doc = ModuleDocument::parse(HAND_EDITED);
+    assert!(doc.mark_change_complete("005-01_foo"));
+    let out = doc.to_markdown();
+    assert!(out.contains("- [x] 005-01_foo\n"));
+    assert!(out.contains("- [ ] 005-02_foo-bar (planned)\n"));
+    assert_eq!(
+        out,
+        HAND_EDITED.replace("- [ ] 005-01_foo\n", "- [x] 005-01_foo\n")
+    );
+
+    assert!(!doc.mark_change_complete("005-01"));
+}
+
+#[test]
+fn add_only_touches_the_checklist() {
+    let mut doc = ModuleDocument::parse(HAND_EDITED);
+    assert!(doc.add_change("005-04_new"));
+    assert!(!doc.add_change("005-04_new"));
+
+    let expected = HAND_EDITED.replace(
+        "- [x] 005-03_baz  shipped early\n",
+        "- [x] 005-03_baz  shipped early\n- [ ] 005-04_new\n",
+    );
+    assert_eq!(doc.to_markdown(), expected);
+}
+
+#[test]
+fn add_change_replaces_placeholder_in_generated_module() {
+    let generated =
+        ModuleDocument::new("Ungrouped", None, vec!["*".to_string()], Vec::new()).to_markdown();
+    let mut doc = ModuleDocument::parse(&generated);
+    assert!(doc.add_change("000-01_first"));
+    assert_eq!(
+        doc.to_markdown(),
+        "# Ungrouped\n\n## Purpose\n<!-- Describe the purpose of this module/epic -->\n\n## Scope\n- *\n\n## Changes\n- [ ] 000-01_first\n"
+    );
+}
+
+#[test]
+fn depends_on_section_is_inserted_before_changes() {
+    let mut doc = ModuleDocument::parse(
+        "# M\n\n## Purpose\nP\n\n## Scope\n- *\n\n## Changes\n- [ ] 001-01_a\n",
+    );
+    doc.depends_on.push("002".to_string());
+    assert_eq!(
+        doc.to_markdown(),
+        "# M\n\n## Purpose\nP\n\n## Scope\n- *\n\n## Depends On\n- 002\n\n## Changes\n- [ ] 001-01_a\n"
+    );
+}
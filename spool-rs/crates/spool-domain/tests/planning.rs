@@ -33,3 +33,94 @@ fn init_planning_structure_writes_files() {
     assert!(state_contents.contains("Last Updated: 2026-01-28"));
     assert!(state_contents.contains("`.spool/changes/`"));
 }
+
+const SPACED_ROADMAP: &str = "# Roadmap\n\n## Current Milestone: v1-core\n\n- Status: Not Started\n- Phase: 0 of 0\n\n## Milestones\n\n### v1-core\n\nTarget: \\[Define the goal for this milestone\\]\n\n| Phase | Name | Status | Changes |\n|-------|------|--------|---------|\n| 1 | \\[Phase Name\\] | Pending | - |\n\n## Completed Milestones\n\n\\[None yet\\]\n";
+
+fn progress_of(change: &str) -> planning::ChangeProgress {
+    match change {
+        "001-01_done" => planning::ChangeProgress {
+            complete: 3,
+            total: 3,
+        },
+        "001-02_half" => planning::ChangeProgress {
+            complete: 1,
+            total: 2,
+        },
+        _ => planning::ChangeProgress::default(),
+    }
+}
+
+#[test]
+fn roadmap_round_trips_unmodified_documents() {
+    let template = planning::roadmap_md_template();
+    assert_eq!(planning::Roadmap::parse(&template).to_markdown(), template);
+    assert_eq!(
+        planning::Roadmap::parse(SPACED_ROADMAP).to_markdown(),
+        SPACED_ROADMAP
+    );
+}
+
+#[test]
+fn roadmap_add_phase_replaces_placeholder_row() {
+    let mut roadmap = planning::Roadmap::parse(&planning::roadmap_md_template());
+    let n = roadmap
+        .add_phase("v1-core", "Foundations", vec!["001-01_done".to_string()])
+        .unwrap();
+    assert_eq!(n, "1");
+    let n = roadmap.add_phase("v1-core", "Polish", Vec::new()).unwrap();
+    assert_eq!(n, "2");
+    roadmap.refresh_progress(progress_of);
+
+    assert_eq!(
+        roadmap.to_markdown(),
+        "# Roadmap\n\n## Current Milestone: v1-core\n- Status: In Progress\n- Phase: 2 of 2\n\n## Milestones\n\n### v1-core\nTarget: [Define the goal for this milestone]\n\n| Phase | Name | Status | Changes |\n|-------|------|--------|---------|\n| 1 | Foundations | Complete | 001-01_done |\n| 2 | Polish | Pending | - |\n\n## Completed Milestones\n[None yet]\n"
+    );
+}
+
+#[test]
+fn roadmap_milestone_add_and_complete_preserve_layout() {
+    let mut roadmap = planning::Roadmap::parse(SPACED_ROADMAP);
+    roadmap
+        .add_milestone("v2-scale", Some("Handle 10x load"), false)
+        .unwrap();
+    assert!(roadmap.add_milestone("v2-scale", None, false).is_err());
+    roadmap.complete_milestone("v1-core", "2026-03-01").unwrap();
+
+    assert_eq!(
+        roadmap.to_markdown(),
+        "# Roadmap\n\n## Current Milestone: v2-scale\n\n- Status: Not Started\n- Phase: 0 of 0\n\n## Milestones\n\n### v2-scale\n\nTarget: Handle 10x load\n\n| Phase | Name | Status | Changes |\n|-------|------|--------|---------|\n\n## Completed Milestones\n\n- v1-core (completed 2026-03-01)\n"
+    );
+}
+
+#[test]
+fn roadmap_linked_phases_follow_change_progress() {
+    let mut roadmap = planning::Roadmap::parse(&planning::roadmap_md_template());
+    roadmap
+        .add_phase("v1-core", "Build", vec!["001-02_half".to_string()])
+        .unwrap();
+    roadmap
+        .link_phase("v1-core", "1", &["001-01_done".to_string()])
+        .unwrap();
+    assert!(
+        roadmap
+            .set_phase_status("v1-core", "1", planning::PhaseStatus::Complete)
+            .is_err()
+    );
+    roadmap.refresh_progress(progress_of);
+
+    let phase = &roadmap.milestone("v1-core").unwrap().phases[0];
+    assert_eq!(phase.status, planning::PhaseStatus::InProgress);
+    assert_eq!(phase.changes, vec!["001-02_half", "001-01_done"]);
+    let current = roadmap.current.as_ref().unwrap();
+    assert_eq!(current.status, "In Progress");
+    assert_eq!(current.phase, "1 of 1");
+
+    roadmap.add_phase("v1-core", "Docs", Vec::new()).unwrap();
+    roadmap
+        .set_phase_status("v1-core", "2", planning::PhaseStatus::Complete)
+        .unwrap();
+    let out = roadmap.to_markdown();
+    assert!(out.contains(
+        "| 1 | Build | In Progress | 001-02_half, 001-01_done |\n| 2 | Docs | Complete | - |\n"
+    ));
+}
@@ -70,3 +70,78 @@ fn update_last_updated_noops_when_missing() {
     let out = state::update_last_updated(original, "2026-01-28");
     assert_eq!(out, original);
 }
+
+#[test]
+fn parse_state_extracts_typed_entries_and_skips_placeholders() {
+    let original = sample_state_md("2026-01-01");
+    let original = state::add_blocker(&original, "2026-01-02", "Waiting on API").unwrap();
+    let original = state::add_question(&original, "2026-01-02", "Which DB?").unwrap();
+    let original = state::add_note(&original, "2026-01-03", "09:15:00", "Paired on auth").unwrap();
+
+    let parsed = state::parse_state(&original);
+    assert_eq!(parsed.last_updated.as_deref(), Some("2026-01-03"));
+    assert_eq!(parsed.focus, None);
+
+    let decisions: Vec<_> = parsed.entries_of(state::StateEntryKind::Decision).collect();
+    assert_eq!(decisions.len(), 1);
+    assert_eq!(decisions[0].date.as_deref(), Some("2026-01-01"));
+    assert_eq!(decisions[0].text, "Project initialized");
+
+    let questions: Vec<_> = parsed.entries_of(state::StateEntryKind::Question).collect();
+    assert_eq!(questions.len(), 1);
+    assert_eq!(questions[0].text, "Which DB?");
+
+    let blockers: Vec<_> = parsed
+        .open_entries_of(state::StateEntryKind::Blocker)
+        .collect();
+    assert_eq!(blockers.len(), 1);
+    assert_eq!(blockers[0].number, 1);
+
+    let notes: Vec<_> = parsed.entries_of(state::StateEntryKind::Note).collect();
+    assert_eq!(notes.len(), 3);
+    assert_eq!(notes[0].date.as_deref(), Some("2026-01-03"));
+    assert_eq!(notes[0].time.as_deref(), Some("09:15:00"));
+    assert_eq!(notes[0].text, "Paired on auth");
+    assert_eq!(notes[1].date.as_deref(), Some("2026-01-01"));
+}
+
+#[test]
+fn resolve_blocker_and_answer_question_round_trip_through_parse() {
+    let original = "# Project State\n\nLast Updated: 2026-01-01\n\n## Open Questions\n- [ ] Which DB?\n- [ ] Which cache?\n\n## Blockers\n- First\n- Second\n\n## Session Notes\n---\n";
+    let out = state::resolve_blocker(original, "2026-02-01", 2, Some("vendor replied")).unwrap();
+    let out = state::answer_question(&out, "2026-02-01", 1, "Postgres").unwrap();
+    assert!(out.contains("- First\n- [x] Second\n  - Resolved 2026-02-01: vendor replied\n"));
+    assert!(out.contains("- [x] Which DB?\n  - Answered 2026-02-01: Postgres\n- [ ] Which cache?"));
+    assert!(out.contains("Last Updated: 2026-02-01"));
+
+    let parsed = state::parse_state(&out);
+    let blockers: Vec<_> = parsed.entries_of(state::StateEntryKind::Blocker).collect();
+    assert_eq!(blockers.len(), 2);
+    assert!(blockers[1].resolved);
+    assert_eq!(blockers[1].resolved_on.as_deref(), Some("2026-02-01"));
+    assert_eq!(blockers[1].resolution.as_deref(), Some("vendor replied"));
+    let question = parsed
+        .entries_of(state::StateEntryKind::Question)
+        .next()
+        .unwrap();
+    assert_eq!(question.resolution.as_deref(), Some("Postgres"));
+
+    assert!(state::resolve_blocker(&out, "2026-02-02", 2, None).is_err());
+    assert!(state::resolve_blocker(&out, "2026-02-02", 3, None).is_err());
+    assert!(state::answer_question(&out, "2026-02-02", 2, "  ").is_err());
+}
+
+#[test]
+fn prune_notes_removes_only_sessions_before_cutoff() {
+    let original = "# Project State\n\n## Session Notes\n### 2026-03-01 Session\n- 10:00:00: Recent\n\n### 2026-01-01 Session\n- 10:00:00: Old\n\n### Undated\n- kept\n\n---\n## For AI Assistants\n";
+    let (out, removed) = state::prune_notes(original, "2026-02-01");
+    assert_eq!(removed, 1);
+    assert_eq!(
+        out,
+        "# Project State\n\n## Session Notes\n### 2026-03-01 Session\n- 10:00:00: Recent\n\n### Undated\n- kept\n\n---\n## For AI Assistants\n"
+    );
+
+    let (same, removed) = state::prune_notes(original, "2025-01-01");
+    assert_eq!(removed, 0);
+    assert_eq!(same, original);
+}
@@ -27,3 +27,20 @@ fn update_enhanced_task_status_inserts_or_replaces_status_line() {
     let out2 = tasks::update_enhanced_task_status(&out, "1.1", tasks::TaskStatus::InProgress, now);
     assert!(out2.contains("- **Status**: [ ] in-progress"));
 }
+
+#[test]
+fn normalize_tracked_files_drops_paths_outside_the_change_dir() {
+    let files = [
+        " tests.md ",
+        "notes/impl.md",
+        "../escape.md",
+        "notes/../../escape.md",
+        "/etc/tasks.md",
+        "\\\\server\\tasks.md",
+        "a:b.md",
+        "",
+        "tests.md",
+    ];
+    let normalized = tasks::normalize_tracked_files(files.iter().map(|f| f.to_string()));
+    assert_eq!(normalized, vec!["tests.md", "notes/impl.md"]);
+}
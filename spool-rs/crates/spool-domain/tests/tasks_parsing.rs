@@ -214,6 +214,19 @@ fn enhanced_tasks_cycles_and_shelved_deps_are_reported() {
     assert!(has_shelved_dep);
 }
 
+#[test]
+fn enhanced_tasks_report_invalid_tdd_phase() {
+    let md = "## Wave 1\n- **Depends On**: None\n\n### Task 1.1: A\n- **TDD Phase**: blue\n- **Updated At**: 2026-01-28\n- **Status**: [ ] pending\n";
+    let parsed = tasks::parse_tasks_tracking_file(md);
+    assert_eq!(parsed.tasks[0].tdd_phase, None);
+    assert!(
+        parsed
+            .diagnostics
+            .iter()
+            .any(|d| d.message == "Invalid TDD Phase: blue (expected red, green or refactor)")
+    );
+}
+
 #[test]
 fn update_enhanced_task_status_inserts_missing_fields() {
     let md = "## Wave 1\n\n### Task 1.1: A\n- **Dependencies**: None\n\n";
@@ -29,3 +29,46 @@ fn update_checkbox_task_status_errors_for_invalid_or_missing_task_id() {
     assert!(tasks::update_checkbox_task_status(md, "0", tasks::TaskStatus::Complete).is_err());
     assert!(tasks::update_checkbox_task_status(md, "2", tasks::TaskStatus::Complete).is_err());
 }
+
+#[test]
+fn update_enhanced_task_phase_sets_phase_before_updated_at() {
+    use chrono::TimeZone;
+
+    let md = "## Wave 1\n- **Depends On**: None\n\n### Task 1.1: A\n- **Verify**: `cargo test`\n- **Updated At**: 2026-01-01\n- **Status**: [ ] in-progress\n\n### Task 1.2: B\n- **Updated At**: 2026-01-01\n- **Status**: [ ] pending\n";
+    let now = chrono::Local
+        .with_ymd_and_hms(2026, 1, 28, 0, 0, 0)
+        .unwrap();
+
+    let out = tasks::update_enhanced_task_phase(md, "1.1", tasks::TddPhase::Red, now).unwrap();
+    assert!(out.contains(
+        "- **Verify**: `cargo test`\n- **TDD Phase**: red\n- **Updated At**: 2026-01-28\n"
+    ));
+    assert!(out.contains("### Task 1.2: B\n- **Updated At**: 2026-01-01\n"));
+
+    let out = tasks::update_enhanced_task_phase(&out, "1.1", tasks::TddPhase::Green, now).unwrap();
+    assert_eq!(out.matches("TDD Phase").count(), 1);
+    let parsed = tasks::parse_tasks_tracking_file(&out);
+    assert!(parsed.diagnostics.is_empty(), "{:?}", parsed.diagnostics);
+    assert_eq!(parsed.tasks[0].tdd_phase, Some(tasks::TddPhase::Green));
+    assert_eq!(parsed.tasks[1].tdd_phase, None);
+
+    assert!(tasks::update_enhanced_task_phase(md, "9.9", tasks::TddPhase::Red, now).is_none());
+}
+
+#[test]
+fn tdd_phase_transitions_follow_the_red_green_refactor_cycle() {
+    use tasks::TddPhase::{Green, Red, Refactor};
+
+    assert!(Red.check_transition(None).is_ok());
+    assert!(Green.check_transition(Some(Red)).is_ok());
+    assert!(Refactor.check_transition(Some(Green)).is_ok());
+    assert!(Red.check_transition(Some(Refactor)).is_ok());
+
+    assert_eq!(
+        Green.check_transition(None).unwrap_err(),
+        "cannot move from no phase to green (green must follow red)"
+    );
+    assert!(Red.check_transition(Some(Green)).is_err());
+    assert!(Refactor.check_transition(Some(Red)).is_err());
+    assert!(Green.check_transition(Some(Green)).is_err());
+}
@@ -1,6 +1,8 @@
 pub mod opencode;
 pub mod stub;
 pub mod types;
+pub mod usage;
 
 pub use opencode::OpencodeHarness;
 pub use types::{Harness, HarnessName, HarnessRunConfig, HarnessRunResult};
+pub use usage::{TokenUsage, parse_token_usage};
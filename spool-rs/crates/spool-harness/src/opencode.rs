@@ -1,5 +1,7 @@
 use crate::types::{Harness, HarnessName, HarnessRunConfig, HarnessRunResult};
+use crate::usage::parse_token_usage;
 use miette::{Result, miette};
+use serde_json::Value;
 use std::io::{BufRead, BufReader, Write};
 use std::process::{Command, Stdio};
 use std::sync::Arc;
@@ -20,7 +22,8 @@ impl Harness for OpencodeHarness {
 
     fn run(&mut self, config: &HarnessRunConfig) -> Result<HarnessRunResult> {
         let mut cmd = Command::new("opencode");
-        cmd.arg("run");
+        // JSON events carry token usage; they are rendered back to plain text as they stream.
+        cmd.args(["run", "--format", "json"]);
 
         if let Some(model) = config.model.as_deref() {
             cmd.args(["-m", model]);
@@ -51,13 +54,17 @@ impl Harness for OpencodeHarness {
 
         // Spawn thread to stream stdout
         let last_activity_stdout = Arc::clone(&last_activity);
-        let stdout_handle =
-            thread::spawn(move || stream_pipe(stdout_pipe, &last_activity_stdout, true));
+        let stdout_handle = thread::spawn(move || {
+            stream_pipe(stdout_pipe, &last_activity_stdout, true, render_event)
+        });
 
         // Spawn thread to stream stderr
         let last_activity_stderr = Arc::clone(&last_activity);
-        let stderr_handle =
-            thread::spawn(move || stream_pipe(stderr_pipe, &last_activity_stderr, false));
+        let stderr_handle = thread::spawn(move || {
+            stream_pipe(stderr_pipe, &last_activity_stderr, false, |line| {
+                Some(line.to_string())
+            })
+        });
 
         // Spawn timeout monitor thread if timeout is configured
         let timeout = config
@@ -85,8 +92,8 @@ impl Harness for OpencodeHarness {
         done.store(true, Ordering::SeqCst);
 
         // Wait for streaming threads to finish
-        let stdout = stdout_handle.join().unwrap_or_default();
-        let stderr = stderr_handle.join().unwrap_or_default();
+        let (stdout, events) = stdout_handle.join().unwrap_or_default();
+        let (stderr, _) = stderr_handle.join().unwrap_or_default();
 
         // Stop the monitor thread (it will exit on next check since process is done)
         let _ = monitor_handle.join();
@@ -94,7 +101,9 @@ impl Harness for OpencodeHarness {
         let duration = start.elapsed();
         let was_timed_out = timed_out.load(Ordering::SeqCst);
 
+        let usage = parse_token_usage(&events);
         Ok(HarnessRunResult {
+            usage,
             stdout,
             stderr,
             exit_code: if was_timed_out {
@@ -116,13 +125,54 @@ impl Harness for OpencodeHarness {
     }
 }
 
+/// Plain-text rendering of one `opencode run --format json` output line.
+///
+/// Text parts and finished tool calls are shown, bookkeeping events (`step_start`,
+/// `step_finish`) are dropped, and lines that aren't JSON events pass through unchanged.
+fn render_event(line: &str) -> Option<String> {
+    let Ok(event) = serde_json::from_str::<Value>(line) else {
+        return Some(line.to_string());
+    };
+    let Some(kind) = event.get("type").and_then(Value::as_str) else {
+        return Some(line.to_string());
+    };
+    let part = event.get("part");
+    let str_at = |v: Option<&Value>, pointer: &str| {
+        v.and_then(|v| v.pointer(pointer))
+            .and_then(Value::as_str)
+            .map(str::to_string)
+    };
+    match kind {
+        "text" => str_at(part, "/text").map(|t| t.trim_end_matches('\n').to_string()),
+        "tool_use" => {
+            let tool = str_at(part, "/tool").unwrap_or_else(|| "tool".to_string());
+            Some(match str_at(part, "/state/title") {
+                Some(title) if !title.is_empty() => format!("| {tool} {title}"),
+                _ => format!("| {tool}"),
+            })
+        }
+        "error" => {
+            let error = event.get("error");
+            let message = str_at(error, "/data/message")
+                .or_else(|| str_at(error, "/name"))
+                .unwrap_or_else(|| "unknown error".to_string());
+            Some(format!("Error: {message}"))
+        }
+        _ => None,
+    }
+}
+
 /// Stream output from a pipe, updating last activity time on each line.
+///
+/// Each line is shown and collected as `render` returns it; the raw lines are returned too.
 fn stream_pipe(
     pipe: Option<impl std::io::Read>,
     last_activity: &std::sync::Mutex<Instant>,
     is_stdout: bool,
-) -> String {
+    render: fn(&str) -> Option<String>,
+) -> (String, String) {
     let mut collected = String::new();
+    let mut raw = String::new();
     if let Some(pipe) = pipe {
         let reader = BufReader::new(pipe);
         for line in reader.lines().map_while(Result::ok) {
@@ -131,20 +181,26 @@ fn stream_pipe(
                 *last = Instant::now();
             }
 
+            raw.push_str(&line);
+            raw.push('\n');
+            let Some(text) = render(&line) else {
+                continue;
+            };
+
             // Stream to console
             if is_stdout {
-                println!("{}", line);
+                println!("{}", text);
                 let _ = std::io::stdout().flush();
             } else {
-                eprintln!("{}", line);
+                eprintln!("{}", text);
                 let _ = std::io::stderr().flush();
             }
 
-            collected.push_str(&line);
+            collected.push_str(&text);
             collected.push('\n');
         }
     }
-    collected
+    (collected, raw)
 }
 
 /// Monitor for inactivity timeout and kill process if exceeded.
@@ -1,4 +1,5 @@
 use crate::types::{Harness, HarnessName, HarnessRunConfig, HarnessRunResult};
+use crate::usage::parse_token_usage;
 use miette::{Result, miette};
 use serde::Deserialize;
 use std::fs;
@@ -74,7 +75,9 @@ impl Harness for StubHarness {
             .next_step()
             .ok_or_else(|| miette!("Stub harness has no steps"))?;
 
+        let usage = parse_token_usage(&step.stdout);
         Ok(HarnessRunResult {
+            usage,
             stdout: step.stdout,
             stderr: step.stderr,
             exit_code: step.exit_code,
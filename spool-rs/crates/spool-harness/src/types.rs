@@ -3,6 +3,8 @@ use std::collections::BTreeMap;
 use std::path::PathBuf;
 use std::time::Duration;
 
+use crate::usage::TokenUsage;
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct HarnessName(pub &'static str);
 
@@ -30,6 +32,8 @@ pub struct HarnessRunResult {
     pub duration: Duration,
     /// True if the harness was terminated due to inactivity timeout.
     pub timed_out: bool,
+    /// Token usage the harness reported, when its output mode includes it.
+    pub usage: Option<TokenUsage>,
 }
 
 pub trait Harness {
@@ -0,0 +1,102 @@
+//! Token usage reported by harness JSON output modes.
+//!
+//! Harnesses print usage as JSON objects (one per line in streaming modes):
+//! - opencode `--format json`: `{"type":"step_finish","part":{"tokens":{"input":..,"output":..,"cache":{"read":..,"write":..}},"cost":..}}`
+//! - claude `--output-format json|stream-json`: `{"type":"result","usage":{"input_tokens":..,"output_tokens":..,"cache_read_input_tokens":..,"cache_creation_input_tokens":..},"total_cost_usd":..}`
+//! - codex `exec --json`: `{"type":"turn.completed","usage":{"input_tokens":..,"cached_input_tokens":..,"output_tokens":..}}`
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenUsage {
+    pub input_tokens: u64,
+    pub output_tokens: u64,
+    #[serde(default)]
+    pub cache_read_tokens: u64,
+    #[serde(default)]
+    pub cache_write_tokens: u64,
+    /// Cost the harness reported itself, in USD.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reported_cost: Option<f64>,
+}
+
+impl TokenUsage {
+    pub fn total_tokens(&self) -> u64 {
+        self.input_tokens + self.output_tokens + self.cache_read_tokens + self.cache_write_tokens
+    }
+
+    fn add(&mut self, other: &TokenUsage) {
+        self.input_tokens += other.input_tokens;
+        self.output_tokens += other.output_tokens;
+        self.cache_read_tokens += other.cache_read_tokens;
+        self.cache_write_tokens += other.cache_write_tokens;
+        self.reported_cost = match (self.reported_cost, other.reported_cost) {
+            (Some(a), Some(b)) => Some(a + b),
+            (a, b) => a.or(b),
+        };
+    }
+}
+
+/// Sum the token usage reported in a harness's stdout, or `None` when it reported none.
+pub fn parse_token_usage(stdout: &str) -> Option<TokenUsage> {
+    let events: Vec<Value> = stdout
+        .lines()
+        .map(str::trim)
+        .filter(|l| l.starts_with('{'))
+        .filter_map(|l| serde_json::from_str(l).ok())
+        .collect();
+
+    // Claude's final `result` event already totals the whole run; its per-message usage
+    // would otherwise be counted twice.
+    if let Some(result) = events
+        .iter()
+        .rev()
+        .find(|e| e.get("type").and_then(Value::as_str) == Some("result"))
+        && let Some(usage) = usage_from_event(result)
+    {
+        return Some(usage);
+    }
+
+    let mut total: Option<TokenUsage> = None;
+    for event in &events {
+        if let Some(usage) = usage_from_event(event) {
+            total.get_or_insert_with(TokenUsage::default).add(&usage);
+        }
+    }
+    total
+}
+
+fn usage_from_event(event: &Value) -> Option<TokenUsage> {
+    if let Some(usage) = event.get("usage").and_then(Value::as_object) {
+        let n = |key: &str| usage.get(key).and_then(Value::as_u64).unwrap_or(0);
+        let cache_read = n("cache_read_input_tokens") + n("cached_input_tokens");
+        return Some(TokenUsage {
+            // Codex counts cached tokens inside `input_tokens`.
+            input_tokens: n("input_tokens").saturating_sub(n("cached_input_tokens")),
+            output_tokens: n("output_tokens"),
+            cache_read_tokens: cache_read,
+            cache_write_tokens: n("cache_creation_input_tokens"),
+            reported_cost: event.get("total_cost_usd").and_then(Value::as_f64),
+        });
+    }
+
+    let part = event.get("part").unwrap_or(event);
+    let tokens = part.get("tokens").and_then(Value::as_object)?;
+    let n = |key: &str| tokens.get(key).and_then(Value::as_u64).unwrap_or(0);
+    let cache = tokens.get("cache");
+    let c = |key: &str| {
+        cache
+            .and_then(|c| c.get(key))
+            .and_then(Value::as_u64)
+            .unwrap_or(0)
+    };
+    Some(TokenUsage {
+        input_tokens: n("input"),
+        output_tokens: n("output") + n("reasoning"),
+        cache_read_tokens: c("read"),
+        cache_write_tokens: c("write"),
+        reported_cost: part.get("cost").and_then(Value::as_f64),
+    })
+}
@@ -82,9 +82,9 @@ fn opencode_harness_runs_opencode_binary_and_returns_outputs() {
         })
         .unwrap();
 
-    // opencode harness always passes: opencode run [-m model] <prompt>
+    // opencode harness always passes: opencode run --format json [-m model] <prompt>
     assert!(
-        r.stdout.contains("STDOUT:run -m m1 hello"),
+        r.stdout.contains("STDOUT:run --format json -m m1 hello"),
         "unexpected stdout/stderr/exit_code: stdout={:?} stderr={:?} exit_code={}",
         r.stdout,
         r.stderr,
@@ -94,6 +94,46 @@ fn opencode_harness_runs_opencode_binary_and_returns_outputs() {
     assert_eq!(r.exit_code, 42);
 }
 
+#[test]
+fn opencode_harness_renders_json_events_and_reports_token_usage() {
+    let dir = tempfile::tempdir().unwrap();
+    let bin = dir.path().join("opencode");
+    write_executable(
+        &bin,
+        r#"#!/bin/sh
+echo '{"type":"step_start","part":{"type":"step-start"}}'
+printf '%s\n' '{"type":"text","part":{"type":"text","text":"Working on it\n<promise>COMPLETE</promise>\n"}}'
+echo '{"type":"tool_use","part":{"tool":"bash","state":{"status":"completed","title":"cargo test"}}}'
+echo '{"type":"step_finish","part":{"tokens":{"input":120,"output":30,"reasoning":0,"cache":{"read":500,"write":0}},"cost":0.02}}'
+echo 'plain line'
+"#,
+    );
+
+    let _path_guard = PathGuard::prepend(dir.path());
+
+    let mut h = OpencodeHarness;
+    let r = h
+        .run(&HarnessRunConfig {
+            prompt: "hello".to_string(),
+            model: None,
+            cwd: dir.path().to_path_buf(),
+            env: BTreeMap::new(),
+            interactive: false,
+            inactivity_timeout: None,
+        })
+        .unwrap();
+
+    assert_eq!(
+        r.stdout,
+        "Working on it\n<promise>COMPLETE</promise>\n| bash cargo test\nplain line\n"
+    );
+    let usage = r.usage.expect("usage from step_finish");
+    assert_eq!(usage.input_tokens, 120);
+    assert_eq!(usage.output_tokens, 30);
+    assert_eq!(usage.cache_read_tokens, 500);
+    assert_eq!(usage.reported_cost, Some(0.02));
+}
+
 #[test]
 fn opencode_harness_errors_when_opencode_missing() {
     let dir = tempfile::tempdir().unwrap();
@@ -0,0 +1,52 @@
+use spool_harness::{TokenUsage, parse_token_usage};
+
+#[test]
+fn parse_token_usage_sums_opencode_step_events() {
+    let stdout = r#"{"type":"text","part":{"text":"hello"}}
+{"type":"step_finish","part":{"tokens":{"input":100,"output":20,"reasoning":5,"cache":{"read":300,"write":10}},"cost":0.01}}
+{"type":"step_finish","part":{"tokens":{"input":50,"output":10,"reasoning":0,"cache":{"read":0,"write":0}},"cost":0.02}}
+"#;
+    let usage = parse_token_usage(stdout).unwrap();
+    assert_eq!(usage.input_tokens, 150);
+    assert_eq!(usage.output_tokens, 35);
+    assert_eq!(usage.cache_read_tokens, 300);
+    assert_eq!(usage.cache_write_tokens, 10);
+    assert!((usage.reported_cost.unwrap() - 0.03).abs() < 1e-9);
+}
+
+#[test]
+fn parse_token_usage_prefers_claude_result_totals() {
+    let stdout = r#"{"type":"assistant","message":{"content":[]},"usage":{"input_tokens":1,"output_tokens":1}}
+{"type":"result","usage":{"input_tokens":40,"output_tokens":7,"cache_read_input_tokens":1000,"cache_creation_input_tokens":200},"total_cost_usd":0.12}
+"#;
+    let usage = parse_token_usage(stdout).unwrap();
+    assert_eq!(
+        usage,
+        TokenUsage {
+            input_tokens: 40,
+            output_tokens: 7,
+            cache_read_tokens: 1000,
+            cache_write_tokens: 200,
+            reported_cost: Some(0.12),
+        }
+    );
+}
+
+#[test]
+fn parse_token_usage_reads_codex_turns_without_double_counting_cache() {
+    let stdout = r#"{"type":"turn.completed","usage":{"input_tokens":500,"cached_input_tokens":400,"output_tokens":30}}
+"#;
+    let usage = parse_token_usage(stdout).unwrap();
+    assert_eq!(usage.input_tokens, 100);
+    assert_eq!(usage.cache_read_tokens, 400);
+    assert_eq!(usage.output_tokens, 30);
+    assert_eq!(usage.reported_cost, None);
+}
+
+#[test]
+fn parse_token_usage_is_none_for_plain_text() {
+    assert_eq!(
+        parse_token_usage("working...\n<promise>COMPLETE</promise>\n"),
+        None
+    );
+}
@@ -90,6 +90,17 @@ impl ModelRegistry {
         })
     }
 
+    /// Load models from the local cache only, never touching the network.
+    pub fn load_cached(&self) -> Option<RegistryLoad> {
+        let cached = self.cache.load()?;
+        Some(RegistryLoad {
+            updated_at: cached.updated_at,
+            source: ModelSource::Cache,
+            stale: self.cache.is_stale(24),
+            models: cached.models,
+        })
+    }
+
     /// Find a model by ID
     pub fn find<'a>(&self, load: &'a RegistryLoad, id: &str) -> Option<&'a Model> {
         load.models.iter().find(|m| m.id == id)
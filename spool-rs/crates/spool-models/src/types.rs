@@ -46,6 +46,31 @@ impl Model {
     pub fn total_cost_per_million(&self) -> f64 {
         self.input_cost_per_million.unwrap_or(0.0) + self.output_cost_per_million.unwrap_or(0.0)
     }
+
+    /// Price a run's token usage in USD, or `None` when the model has no pricing.
+    ///
+    /// Cache reads and writes fall back to the input price when the model has no
+    /// dedicated cache pricing.
+    pub fn cost(
+        &self,
+        input_tokens: u64,
+        output_tokens: u64,
+        cache_read_tokens: u64,
+        cache_write_tokens: u64,
+    ) -> Option<f64> {
+        if self.input_cost_per_million.is_none() && self.output_cost_per_million.is_none() {
+            return None;
+        }
+        let input = self.input_cost_per_million.unwrap_or(0.0);
+        let output = self.output_cost_per_million.unwrap_or(0.0);
+        let cache_read = self.cache_read_cost_per_million.unwrap_or(input);
+        let cache_write = self.cache_write_cost_per_million.unwrap_or(input);
+        let per_million = input_tokens as f64 * input
+            + output_tokens as f64 * output
+            + cache_read_tokens as f64 * cache_read
+            + cache_write_tokens as f64 * cache_write;
+        Some(per_million / 1_000_000.0)
+    }
 }
 
 impl fmt::Display for Model {
@@ -161,3 +186,41 @@ impl fmt::Display for ModelSource {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn model(input: Option<f64>, output: Option<f64>, cache_read: Option<f64>) -> Model {
+        Model {
+            id: "test/model".to_string(),
+            provider: Provider {
+                id: "test".to_string(),
+                name: "Test".to_string(),
+            },
+            name: "Test Model".to_string(),
+            family: None,
+            input_cost_per_million: input,
+            output_cost_per_million: output,
+            cache_read_cost_per_million: cache_read,
+            cache_write_cost_per_million: None,
+            max_input_tokens: None,
+            max_output_tokens: None,
+            modalities: Default::default(),
+            capabilities: vec![],
+        }
+    }
+
+    #[test]
+    fn cost_prices_each_token_kind() {
+        let m = model(Some(3.0), Some(15.0), Some(0.3));
+        let cost = m.cost(1_000_000, 100_000, 2_000_000, 1_000_000).unwrap();
+        // 3.0 + 1.5 + 0.6 + 3.0 (cache writes fall back to the input price)
+        assert!((cost - 8.1).abs() < 1e-9);
+    }
+
+    #[test]
+    fn cost_is_none_without_pricing() {
+        assert_eq!(model(None, None, None).cost(10, 10, 0, 0), None);
+    }
+}
@@ -121,11 +121,10 @@ async fn handle_socket(socket: WebSocket, state: Arc<TerminalState>) {
                     break;
                 }
             }
-            Message::Binary(data) => {
-                if writer.write_all(&data).is_err() {
-                    break;
-                }
-            }
+            Message::Binary(data) => match writer.write_all(&data) {
+                Ok(()) => {}
+                Err(_) => break,
+            },
             Message::Close(_) => break,
             _ => {}
         }